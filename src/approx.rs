@@ -0,0 +1,61 @@
+//! Approximate equality, for comparing the results of floating point computations.
+
+use crate::{Matrix, Real};
+
+impl<T: Real> Matrix<T> {
+    /// Whether both matrices have the same shape and every pair of corresponding elements is close.
+    ///
+    /// Two elements `a` and `b` count as close if `|a - b| <= abs_tol` or
+    /// `|a - b| <= rel_tol * max(|a|, |b|)`. The absolute tolerance matters for values near zero,
+    /// where any relative tolerance is useless. NaNs are never close to anything.
+    pub fn approx_eq(&self, o: &Matrix<T>, abs_tol: T, rel_tol: T) -> bool {
+        self.rows == o.rows && self.columns == o.columns
+            && self.contents.iter().zip(&o.contents).all(|(&a, &b)| close(a, b, abs_tol, rel_tol))
+    }
+}
+
+fn close<T: Real>(a: T, b: T, abs_tol: T, rel_tol: T) -> bool {
+    if a == b {
+        // also covers equal infinities, whose difference would be NaN
+        return true;
+    }
+
+    let diff = (a - b).abs();
+    let largest = if a.abs() > b.abs() { a.abs() } else { b.abs() };
+    diff <= abs_tol || diff <= rel_tol * largest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rounding_errors() {
+        let a: Matrix<f64> = matrix![0.1 + 0.2, 1.0];
+        let b: Matrix<f64> = matrix![0.3, 1.0];
+
+        assert_ne!(a, b);
+        assert!(a.approx_eq(&b, 1e-12, 0.0));
+        assert!(a.approx_eq(&b, 0.0, 1e-12));
+    }
+
+    #[test]
+    fn tolerances() {
+        let big: Matrix<f64> = matrix![1e9, 1e-9];
+        let off: Matrix<f64> = matrix![1e9 + 1.0, 2e-9];
+
+        assert!(!big.approx_eq(&off, 1e-6, 0.0));
+        assert!(!big.approx_eq(&off, 0.0, 1e-6));
+        assert!(big.approx_eq(&off, 1e-6, 1e-6));
+    }
+
+    #[test]
+    fn special_values() {
+        let a: Matrix<f64> = matrix![f64::INFINITY, f64::NAN];
+        let b: Matrix<f64> = matrix![f64::INFINITY, 0.0];
+
+        assert!(!a.approx_eq(&a, 1.0, 1.0));
+        assert!(a.submatrix(0..1, 0..1).approx_eq(&b.submatrix(0..1, 0..1), 0.0, 0.0));
+        assert!(!b.approx_eq(&Matrix::zeroes(2, 1), 1.0, 1.0));
+    }
+}
@@ -0,0 +1,354 @@
+//! Compound assignment operators, which update a matrix in place instead of allocating a new one,
+//! arithmetic between a matrix and a single scalar, the matrix operators for borrowed operands, and powers.
+
+use std::ops;
+
+use crate::{view, Matrix, MatrixError, One, Zero};
+
+impl<T: Default + Copy + ops::Add<Output = T>> ops::AddAssign<&Matrix<T>> for Matrix<T> {
+    fn add_assign(&mut self, o: &Matrix<T>) {
+        assert!(self.columns == o.columns && self.rows == o.rows, "Can only add matrices of the same dimension.");
+
+        for (a, &b) in self.contents.iter_mut().zip(&o.contents) {
+            *a = *a + b;
+        }
+    }
+}
+
+impl<T: Default + Copy + ops::Add<Output = T>> ops::AddAssign<Matrix<T>> for Matrix<T> {
+    fn add_assign(&mut self, o: Matrix<T>) {
+        *self += &o;
+    }
+}
+
+impl<T: Default + Copy + ops::Sub<Output = T>> ops::SubAssign<&Matrix<T>> for Matrix<T> {
+    fn sub_assign(&mut self, o: &Matrix<T>) {
+        assert!(self.columns == o.columns && self.rows == o.rows, "Can only subtract matrices of the same dimension.");
+
+        for (a, &b) in self.contents.iter_mut().zip(&o.contents) {
+            *a = *a - b;
+        }
+    }
+}
+
+impl<T: Default + Copy + ops::Sub<Output = T>> ops::SubAssign<Matrix<T>> for Matrix<T> {
+    fn sub_assign(&mut self, o: Matrix<T>) {
+        *self -= &o;
+    }
+}
+
+/// Scales every element by `k`.
+impl<T: Default + Copy + ops::Mul<Output = T>> ops::MulAssign<T> for Matrix<T> {
+    fn mul_assign(&mut self, k: T) {
+        for e in &mut self.contents {
+            *e = *e * k;
+        }
+    }
+}
+
+/// Divides every element by `k`.
+impl<T: Default + Copy + ops::Div<Output = T>> ops::DivAssign<T> for Matrix<T> {
+    fn div_assign(&mut self, k: T) {
+        for e in &mut self.contents {
+            *e = *e / k;
+        }
+    }
+}
+
+/// Adds `k` to every element.
+impl<T: Default + Copy + ops::Add<Output = T>> ops::Add<T> for Matrix<T> {
+    type Output = Matrix<T>;
+    fn add(mut self, k: T) -> Matrix<T> {
+        self.map_mut(|e| *e = *e + k);
+        self
+    }
+}
+
+/// Subtracts `k` from every element.
+impl<T: Default + Copy + ops::Sub<Output = T>> ops::Sub<T> for Matrix<T> {
+    type Output = Matrix<T>;
+    fn sub(mut self, k: T) -> Matrix<T> {
+        self.map_mut(|e| *e = *e - k);
+        self
+    }
+}
+
+/// Scales every element by `k`.
+impl<T: Default + Copy + ops::Mul<Output = T>> ops::Mul<T> for Matrix<T> {
+    type Output = Matrix<T>;
+    fn mul(mut self, k: T) -> Matrix<T> {
+        self *= k;
+        self
+    }
+}
+
+/// Divides every element by `k`.
+impl<T: Default + Copy + ops::Div<Output = T>> ops::Div<T> for Matrix<T> {
+    type Output = Matrix<T>;
+    fn div(mut self, k: T) -> Matrix<T> {
+        self /= k;
+        self
+    }
+}
+
+impl<T: Default + Copy + ops::Neg<Output = T>> ops::Neg for Matrix<T> {
+    type Output = Matrix<T>;
+    fn neg(mut self) -> Matrix<T> {
+        self.map_mut(|e| *e = -*e);
+        self
+    }
+}
+
+// the scalar has to come first here, which the orphan rule only allows for concrete types
+macro_rules! impl_scalar_lhs {
+    ($($t:ty)*) => {
+        $(
+            impl ops::Mul<Matrix<$t>> for $t {
+                type Output = Matrix<$t>;
+                fn mul(self, m: Matrix<$t>) -> Matrix<$t> {
+                    m * self
+                }
+            }
+
+            impl ops::Add<Matrix<$t>> for $t {
+                type Output = Matrix<$t>;
+                fn add(self, m: Matrix<$t>) -> Matrix<$t> {
+                    m + self
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar_lhs!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize f32 f64);
+
+// `a + b` and friends consume both matrices, so these let expressions reuse their operands instead.
+// Where the left operand is owned its storage is reused for the result.
+
+impl<T: Default + Clone + Copy + ops::Add<Output = T>> ops::Add<&Matrix<T>> for &Matrix<T> {
+    type Output = Matrix<T>;
+    fn add(self, o: &Matrix<T>) -> Matrix<T> {
+        view::add(self, o)
+    }
+}
+
+impl<T: Default + Copy + ops::Add<Output = T>> ops::Add<&Matrix<T>> for Matrix<T> {
+    type Output = Matrix<T>;
+    fn add(mut self, o: &Matrix<T>) -> Matrix<T> {
+        self += o;
+        self
+    }
+}
+
+impl<T: Default + Clone + Copy + ops::Add<Output = T>> ops::Add<Matrix<T>> for &Matrix<T> {
+    type Output = Matrix<T>;
+    fn add(self, o: Matrix<T>) -> Matrix<T> {
+        view::add(self, &o)
+    }
+}
+
+impl<T: Default + Clone + Copy + ops::Sub<Output = T>> ops::Sub<&Matrix<T>> for &Matrix<T> {
+    type Output = Matrix<T>;
+    fn sub(self, o: &Matrix<T>) -> Matrix<T> {
+        self.clone() - o
+    }
+}
+
+impl<T: Default + Copy + ops::Sub<Output = T>> ops::Sub<&Matrix<T>> for Matrix<T> {
+    type Output = Matrix<T>;
+    fn sub(mut self, o: &Matrix<T>) -> Matrix<T> {
+        self -= o;
+        self
+    }
+}
+
+impl<T: Default + Clone + Copy + ops::Sub<Output = T>> ops::Sub<Matrix<T>> for &Matrix<T> {
+    type Output = Matrix<T>;
+    fn sub(self, o: Matrix<T>) -> Matrix<T> {
+        self - &o
+    }
+}
+
+impl<T: Default + Clone + Copy + ops::Add<Output = T> + ops::Mul<Output = T>> ops::Mul<&Matrix<T>> for &Matrix<T> {
+    type Output = Matrix<T>;
+    fn mul(self, o: &Matrix<T>) -> Matrix<T> {
+        view::mul(self, o)
+    }
+}
+
+impl<T: Default + Clone + Copy + ops::Add<Output = T> + ops::Mul<Output = T>> ops::Mul<&Matrix<T>> for Matrix<T> {
+    type Output = Matrix<T>;
+    fn mul(self, o: &Matrix<T>) -> Matrix<T> {
+        view::mul(&self, o)
+    }
+}
+
+impl<T: Default + Clone + Copy + ops::Add<Output = T> + ops::Mul<Output = T>> ops::Mul<Matrix<T>> for &Matrix<T> {
+    type Output = Matrix<T>;
+    fn mul(self, o: Matrix<T>) -> Matrix<T> {
+        view::mul(self, &o)
+    }
+}
+
+/// Scales every element by `k`, leaving the original alone.
+impl<T: Default + Clone + Copy + ops::Mul<Output = T>> ops::Mul<T> for &Matrix<T> {
+    type Output = Matrix<T>;
+    fn mul(self, k: T) -> Matrix<T> {
+        self.map(|&e| e * k)
+    }
+}
+
+impl<T: Default + Copy + Zero + One> Matrix<T> {
+    /// Raises a square matrix to the `n`-th power by repeated squaring, which takes
+    /// O(log n) multiplications. `pow(0)` is the identity matrix.
+    ///
+    /// This panics for non-square matrices; see `try_pow`.
+    pub fn pow(&self, n: u32) -> Matrix<T> {
+        self.try_pow(n).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// The non-panicking version of `pow`.
+    pub fn try_pow(&self, mut n: u32) -> Result<Matrix<T>, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare { rows: self.rows, columns: self.columns });
+        }
+
+        let mut result = Matrix::identity(self.rows);
+        let mut square = self.clone();
+        while n > 0 {
+            if n & 1 == 1 {
+                result = &result * &square;
+            }
+            n >>= 1;
+            if n > 0 {
+                square = &square * &square;
+            }
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accumulating() {
+        let mut acc: Matrix<i32> = Matrix::zeroes(2, 2);
+        let step: Matrix<i32> = matrix![1, 2; 3, 4];
+
+        for _ in 0..3 {
+            acc += &step;
+        }
+        assert_eq!(acc, matrix![3, 6; 9, 12]);
+
+        acc -= step;
+        assert_eq!(acc, matrix![2, 4; 6, 8]);
+    }
+
+    #[test]
+    fn scalar_assignment() {
+        let mut m: Matrix<f64> = matrix![1.0, -2.0; 0.5, 4.0];
+
+        m *= 2.0;
+        assert_eq!(m, matrix![2.0, -4.0; 1.0, 8.0]);
+        m /= 4.0;
+        assert_eq!(m, matrix![0.5, -1.0; 0.25, 2.0]);
+    }
+
+    #[test]
+    fn subtraction() {
+        let a: Matrix<i32> = matrix![5, 5; 5, 5];
+        let b: Matrix<i32> = matrix![1, 2; 3, 4];
+
+        assert_eq!(a.clone() - b.clone(), matrix![4, 3; 2, 1]);
+        assert!(b.try_sub(Matrix::zeroes(1, 2)).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "Can only add matrices of the same dimension.")]
+    fn mismatched_add_assign() {
+        let mut a: Matrix<i32> = Matrix::zeroes(2, 2);
+        a += Matrix::zeroes(2, 3);
+    }
+
+    #[test]
+    fn scalar_operators() {
+        let m: Matrix<f64> = matrix![1.0, 2.0; 3.0, 4.0];
+
+        assert_eq!(m.clone() * 2.0, matrix![2.0, 4.0; 6.0, 8.0]);
+        assert_eq!(2.0 * m.clone(), m.clone() * 2.0);
+        assert_eq!(m.clone() / 2.0, matrix![0.5, 1.0; 1.5, 2.0]);
+        assert_eq!(m.clone() + 1.0, matrix![2.0, 3.0; 4.0, 5.0]);
+        assert_eq!(1.0 + m.clone(), m.clone() + 1.0);
+        assert_eq!(m.clone() - 1.0, matrix![0.0, 1.0; 2.0, 3.0]);
+        assert_eq!(-m, matrix![-1.0, -2.0; -3.0, -4.0]);
+    }
+
+    #[test]
+    fn scalar_and_matrix_products_coexist() {
+        let m: Matrix<i32> = matrix![1, 2; 3, 4];
+
+        assert_eq!(m.clone() * 3, matrix![3, 6; 9, 12]);
+        assert_eq!(m.clone() * Matrix::identity(2), m);
+    }
+
+    #[test]
+    fn borrowed_operands() {
+        let a: Matrix<i32> = matrix![1, 2; 3, 4];
+        let b: Matrix<i32> = matrix![0, 1; 1, 0];
+
+        assert_eq!(&a + &b, matrix![1, 3; 4, 4]);
+        assert_eq!(&a - &b, matrix![1, 1; 2, 4]);
+        assert_eq!(&a * &b, matrix![2, 1; 4, 3]);
+        assert_eq!(&a * 2, matrix![2, 4; 6, 8]);
+
+        // both operands are still around
+        assert_eq!(a, matrix![1, 2; 3, 4]);
+        assert_eq!(b, matrix![0, 1; 1, 0]);
+    }
+
+    #[test]
+    fn mixed_operands() {
+        let a: Matrix<i32> = matrix![1, 2; 3, 4];
+        let i: Matrix<i32> = Matrix::identity(2);
+
+        assert_eq!(a.clone() + &i, &a + i.clone());
+        assert_eq!(a.clone() - &i, &a - i.clone());
+        assert_eq!(a.clone() * &i, &a * i.clone());
+        assert_eq!(&(&a * &a) * &a, a.clone() * a.clone() * a.clone());
+    }
+
+    #[test]
+    #[should_panic(expected = "aren't multiplicable")]
+    fn borrowed_mismatch() {
+        let a: Matrix<i32> = Matrix::zeroes(2, 3);
+        let _ = &a * &a;
+    }
+
+    #[test]
+    fn fibonacci_power() {
+        let f: Matrix<u64> = matrix![1, 1;
+                                     1, 0];
+
+        assert_eq!(f.pow(0), Matrix::identity(2));
+        assert_eq!(f.pow(1), f);
+        assert_eq!(f.pow(10)[(0, 1)], 55);
+        assert_eq!(f.pow(90)[(0, 1)], 2_880_067_194_370_816_120);
+    }
+
+    #[test]
+    fn markov_steps() {
+        let p: Matrix<f64> = matrix![0.5, 0.5;
+                                     0.0, 1.0];
+
+        assert_eq!(p.pow(3), &(&p * &p) * &p);
+        assert_eq!(p.pow(2).row_sums(), vec![1.0, 1.0]);
+    }
+
+    #[test]
+    fn non_square_power() {
+        let m: Matrix<i32> = Matrix::zeroes(2, 3);
+        assert_eq!(m.try_pow(2), Err(MatrixError::NotSquare { rows: 2, columns: 3 }));
+    }
+}
@@ -0,0 +1,274 @@
+//! Banded matrices, stored as just the diagonals that can be nonzero.
+
+use crate::{Matrix, MatrixError, Real, Vector, Zero};
+
+/// A square matrix whose nonzero elements all lie within `lower` diagonals below and `upper`
+/// diagonals above the main one, as in finite element and spline systems.
+///
+/// Only those `lower + upper + 1` diagonals are stored, row by row, and the LU decomposition
+/// and `solve` take O(n (lower + upper)²) time rather than O(n³).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BandedMatrix<T> {
+    size: usize,
+    lower: usize,
+    upper: usize,
+    // element (i, j) is at i * (lower + upper + 1) + (j + lower - i); positions outside the
+    // matrix, at the start of the first rows and the end of the last ones, stay zero
+    bands: Vec<T>
+}
+
+impl<T: Clone + Zero> BandedMatrix<T> {
+    /// An n×n matrix of zeroes with room for `lower` subdiagonals and `upper` superdiagonals.
+    pub fn zeroes(size: usize, lower: usize, upper: usize) -> BandedMatrix<T> {
+        BandedMatrix { size, lower, upper, bands: vec![T::zero(); size * (lower + upper + 1)] }
+    }
+
+    /// The number of rows, which is also the number of columns.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// The number of subdiagonals and superdiagonals that are stored.
+    pub fn bandwidths(&self) -> (usize, usize) {
+        (self.lower, self.upper)
+    }
+
+    /// Whether element `(i, j)` lies within the band, i.e. may be nonzero.
+    pub fn in_band(&self, i: usize, j: usize) -> bool {
+        i < self.size && j < self.size && j + self.lower >= i && j <= i + self.upper
+    }
+
+    /// Element `(i, j)`, which is zero outside of the band. This panics outside of the matrix.
+    pub fn get(&self, i: usize, j: usize) -> T {
+        assert!(i < self.size && j < self.size,
+            "Index ({}, {}) is out of bounds for a matrix of dimensions ({}, {}).", i, j, self.size, self.size);
+
+        if self.in_band(i, j) { self.bands[self.position(i, j)].clone() } else { T::zero() }
+    }
+
+    /// Sets element `(i, j)`. This panics if it lies outside of the band.
+    pub fn set(&mut self, i: usize, j: usize, value: T) {
+        assert!(self.in_band(i, j),
+            "Element ({}, {}) lies outside of a band with {} subdiagonals and {} superdiagonals.",
+            i, j, self.lower, self.upper);
+
+        let p = self.position(i, j);
+        self.bands[p] = value;
+    }
+
+    /// The band of a square matrix; everything outside of it is ignored. Fails with `NotSquare`.
+    pub fn from_dense(m: &Matrix<T>, lower: usize, upper: usize) -> Result<BandedMatrix<T>, MatrixError> where T: Default {
+        if !m.is_square() {
+            return Err(MatrixError::NotSquare { rows: m.rows, columns: m.columns });
+        }
+
+        let mut banded = BandedMatrix::zeroes(m.rows, lower, upper);
+        for i in 0..m.rows {
+            for j in i.saturating_sub(lower)..(i + upper + 1).min(m.rows) {
+                banded.set(i, j, m.contents[i * m.rows + j].clone());
+            }
+        }
+        Ok(banded)
+    }
+
+    /// The dense n×n matrix.
+    pub fn to_dense(&self) -> Matrix<T> where T: Default {
+        Matrix::from_fn(self.size, self.size, |i, j| self.get(i, j))
+    }
+
+    fn position(&self, i: usize, j: usize) -> usize {
+        i * (self.lower + self.upper + 1) + (j + self.lower - i)
+    }
+}
+
+impl<T: Default + Clone + Zero> From<BandedMatrix<T>> for Matrix<T> {
+    fn from(b: BandedMatrix<T>) -> Matrix<T> {
+        b.to_dense()
+    }
+}
+
+/// The LU decomposition `P A = L U` of a banded matrix, with partial pivoting.
+///
+/// Row swaps let `U` grow `lower` extra superdiagonals, but the factors stay banded, so
+/// solving with them is still cheap.
+#[derive(Clone, Debug)]
+pub struct BandedLu<T> {
+    size: usize,
+    lower: usize,
+    // U, with `lower + upper + 1` elements per row starting at the diagonal
+    upper_factor: Vec<T>,
+    width: usize,
+    // the multipliers that eliminated the `lower` elements below each pivot
+    multipliers: Vec<T>,
+    // at step k, rows k and pivots[k] were swapped
+    pivots: Vec<usize>,
+    singular: bool
+}
+
+impl<T: Real> BandedMatrix<T> {
+    /// Computes the banded LU decomposition with partial pivoting.
+    pub fn lu(&self) -> BandedLu<T> {
+        let (n, kl, ku) = (self.size, self.lower, self.upper);
+        let width = kl + ku + 1;
+        let largest = self.bands.iter().fold(T::zero(), |acc, e| if e.abs() > acc { e.abs() } else { acc });
+        let tolerance = largest * T::epsilon() * T::from_f64(n as f64);
+
+        // row i holds columns i - kl ..= i + ku + kl, the extra ones for the fill-in
+        let stride = kl + width;
+        let mut work = vec![T::zero(); n * stride];
+        for i in 0..n {
+            for j in i.saturating_sub(kl)..(i + ku + 1).min(n) {
+                work[i * stride + j + kl - i] = self.bands[self.position(i, j)];
+            }
+        }
+        let at = |i: usize, j: usize| i * stride + j + kl - i;
+
+        let mut multipliers = vec![T::zero(); n * kl];
+        let mut pivots = Vec::with_capacity(n);
+        let mut singular = false;
+        for k in 0..n {
+            let last_row = (k + kl).min(n - 1);
+            let last_column = (k + ku + kl).min(n - 1);
+
+            let mut p = k;
+            for r in k + 1..=last_row {
+                if work[at(r, k)].abs() > work[at(p, k)].abs() {
+                    p = r;
+                }
+            }
+            pivots.push(p);
+            if p != k {
+                for j in k..=last_column {
+                    work.swap(at(k, j), at(p, j));
+                }
+            }
+
+            let pivot = work[at(k, k)];
+            if pivot.abs() <= tolerance || !pivot.is_finite() {
+                singular = true;
+                continue;
+            }
+            for r in k + 1..=last_row {
+                let factor = work[at(r, k)] / pivot;
+                multipliers[k * kl + (r - k - 1)] = factor;
+                work[at(r, k)] = T::zero();
+                for j in k + 1..=last_column {
+                    work[at(r, j)] = work[at(r, j)] - factor * work[at(k, j)];
+                }
+            }
+        }
+
+        // keep just U, from the diagonal onwards
+        let upper_factor = (0..n).flat_map(|i| work[i * stride + kl..(i + 1) * stride].to_vec()).collect();
+        BandedLu { size: n, lower: kl, upper_factor, width, multipliers, pivots, singular }
+    }
+
+    /// Solves `A x = b` through the banded LU decomposition. Fails with `Singular`, or with
+    /// `DimensionMismatch` if `b` has the wrong length.
+    pub fn solve(&self, b: &Vector<T>) -> Result<Vector<T>, MatrixError> {
+        self.lu().solve(b)
+    }
+}
+
+impl<T: Real> BandedLu<T> {
+    /// Whether the decomposed matrix is (numerically) singular.
+    pub fn is_singular(&self) -> bool {
+        self.singular
+    }
+
+    /// The determinant of the decomposed matrix.
+    pub fn det(&self) -> T {
+        let swaps = self.pivots.iter().enumerate().filter(|&(k, &p)| k != p).count();
+        let product = (0..self.size).fold(T::one(), |acc, i| acc * self.upper_factor[i * self.width]);
+        if swaps % 2 == 1 { -product } else { product }
+    }
+
+    /// Solves `A x = b` for `x`.
+    pub fn solve(&self, b: &Vector<T>) -> Result<Vector<T>, MatrixError> {
+        let n = self.size;
+        if b.len() != n {
+            return Err(MatrixError::DimensionMismatch { operation: "solving", left: (n, n), right: (b.len(), 1) });
+        }
+        if self.singular {
+            return Err(MatrixError::Singular);
+        }
+
+        let mut x = b.clone().into_vec();
+        for k in 0..n {
+            x.swap(k, self.pivots[k]);
+            for r in k + 1..(k + self.lower + 1).min(n) {
+                x[r] = x[r] - self.multipliers[k * self.lower + (r - k - 1)] * x[k];
+            }
+        }
+        for i in (0..n).rev() {
+            let row = &self.upper_factor[i * self.width..(i + 1) * self.width];
+            let sum = (i + 1..(i + self.width).min(n)).fold(x[i], |acc, j| acc - row[j - i] * x[j]);
+            x[i] = sum / row[0];
+        }
+        Ok(Vector::new(x))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn storage() {
+        let mut b: BandedMatrix<i32> = BandedMatrix::zeroes(4, 1, 2);
+        b.set(0, 0, 1);
+        b.set(0, 2, 2);
+        b.set(3, 2, 3);
+
+        assert_eq!(b.to_dense(), matrix![1, 0, 2, 0;
+                                         0, 0, 0, 0;
+                                         0, 0, 0, 0;
+                                         0, 0, 3, 0]);
+        assert_eq!(b.get(3, 0), 0);
+        assert!(!b.in_band(0, 3) && b.in_band(1, 3));
+        assert_eq!(BandedMatrix::from_dense(&b.to_dense(), 1, 2), Ok(b.clone()));
+        assert_eq!(Matrix::from(b.clone()), b.to_dense());
+    }
+
+    #[test]
+    fn solves_like_dense() {
+        let n = 8;
+        let dense: Matrix<f64> = Matrix::from_fn(n, n, |i, j| {
+            if j + 2 < i || j > i + 1 { 0.0 } else { ((i * 5 + j * 3) % 7) as f64 - 3.0 }
+        });
+        let banded = BandedMatrix::from_dense(&dense, 2, 1).unwrap();
+        let b = Vector::new((0..n).map(|i| i as f64).collect());
+
+        let x = banded.solve(&b).unwrap();
+        let expected = dense.solve(&Matrix::from(b)).unwrap();
+        assert!(Matrix::from(x).approx_eq(&expected, 1e-12, 1e-12));
+        assert!((banded.lu().det() - dense.det()).abs() < 1e-10 * dense.det().abs());
+    }
+
+    #[test]
+    fn needs_pivoting() {
+        let dense: Matrix<f64> = matrix![0.0, 1.0, 0.0;
+                                         1.0, 0.0, 1.0;
+                                         0.0, 1.0, 1.0];
+        let banded = BandedMatrix::from_dense(&dense, 1, 1).unwrap();
+
+        let x = banded.solve(&Vector::new(vec![1.0, 2.0, 3.0])).unwrap();
+        assert!(Matrix::from(x).approx_eq(&matrix![0.0; 1.0; 2.0], 1e-14, 0.0));
+        assert_eq!(banded.lu().det(), -1.0);
+    }
+
+    #[test]
+    fn failures() {
+        let singular: BandedMatrix<f64> = BandedMatrix::zeroes(2, 0, 0);
+        assert_eq!(singular.solve(&Vector::new(vec![1.0, 1.0])), Err(MatrixError::Singular));
+        assert_eq!(BandedMatrix::from_dense(&Matrix::<f64>::identity(3), 0, 0).unwrap().solve(&Vector::new(vec![1.0])),
+            Err(MatrixError::DimensionMismatch { operation: "solving", left: (3, 3), right: (1, 1) }));
+    }
+
+    #[test]
+    #[should_panic(expected = "Element (0, 2) lies outside of a band with 1 subdiagonals and 1 superdiagonals.")]
+    fn outside_band() {
+        let mut b: BandedMatrix<i32> = BandedMatrix::zeroes(3, 1, 1);
+        b.set(0, 2, 1);
+    }
+}
@@ -0,0 +1,126 @@
+//! Exact determinants and ranks of integer matrices by fraction-free (Bareiss) elimination.
+//!
+//! Every intermediate value is itself the determinant of a submatrix, so each division is
+//! exact and nothing ever leaves the integers. Intermediates grow no larger than the
+//! determinant bound of the matrix, but can still overflow a fixed-width type; like the rest
+//! of the `checked_` family, these return `None` when that happens.
+
+use crate::{Integer, Matrix, MatrixError};
+
+impl<T: Integer> Matrix<T> {
+    /// The exact determinant, or `None` if an intermediate value overflows `T`. For unsigned
+    /// `T`, that includes every negative intermediate, so prefer a signed type.
+    /// Like `det`, this panics for non-square matrices.
+    pub fn checked_det(&self) -> Option<T> {
+        if !self.is_square() {
+            panic!("{}", MatrixError::NotSquare { rows: self.rows, columns: self.columns });
+        }
+
+        let (reduced, rank, odd) = self.bareiss()?;
+        let n = self.rows;
+        if rank < n {
+            return Some(T::zero());
+        }
+        if n == 0 {
+            return Some(T::one());
+        }
+        let det = reduced.contents[n * n - 1];
+        if odd { T::zero().checked_sub(det) } else { Some(det) }
+    }
+
+    /// The exact rank, or `None` if an intermediate value overflows `T` (see `checked_det`).
+    pub fn checked_rank(&self) -> Option<usize> {
+        self.bareiss().map(|(_, rank, _)| rank)
+    }
+
+    // reduces to row echelon form, returning it along with the rank and whether an odd number
+    // of rows were swapped. The last pivot of a square nonsingular matrix is its determinant,
+    // up to that sign
+    fn bareiss(&self) -> Option<(Matrix<T>, usize, bool)> {
+        let (m, n) = (self.rows, self.columns);
+        let mut a = self.clone();
+        let mut previous = T::one();
+        let mut rank = 0;
+        let mut odd = false;
+
+        for k in 0..n {
+            if rank == m {
+                break;
+            }
+            let pivot_row = match (rank..m).find(|&r| !a.contents[r * n + k].is_zero()) {
+                Some(r) => r,
+                None => continue
+            };
+            if pivot_row != rank {
+                for c in 0..n {
+                    a.contents.swap(pivot_row * n + c, rank * n + c);
+                }
+                odd = !odd;
+            }
+
+            let pivot = a.contents[rank * n + k];
+            for r in rank + 1..m {
+                let factor = a.contents[r * n + k];
+                for c in k + 1..n {
+                    let minor = pivot.checked_mul(a.contents[r * n + c])?
+                        .checked_sub(factor.checked_mul(a.contents[rank * n + c])?)?;
+                    a.contents[r * n + c] = minor.checked_div(previous)?;
+                }
+                a.contents[r * n + k] = T::zero();
+            }
+            previous = pivot;
+            rank += 1;
+        }
+
+        Some((a, rank, odd))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn determinants() {
+        let a: Matrix<i64> = matrix![2, -1, 0;
+                                     -1, 2, -1;
+                                     0, -1, 2];
+        assert_eq!(a.checked_det(), Some(4));
+
+        // needs a row swap
+        let b: Matrix<i64> = matrix![0, 1;
+                                     1, 0];
+        assert_eq!(b.checked_det(), Some(-1));
+
+        let c: Matrix<i64> = matrix![1, 2, 3;
+                                     4, 5, 6;
+                                     7, 8, 9];
+        assert_eq!(c.checked_det(), Some(0));
+
+        // the Hilbert matrix scaled to integers, ill-conditioned enough to trouble floating point
+        let h: Matrix<i128> = Matrix::from_fn(6, 6, |r, c| 27720 / (r + c + 1) as i128);
+        let det = h.checked_det().unwrap();
+        assert_ne!(det, 0);
+        assert_eq!(h.map(|&e| 2 * e).checked_det(), Some(64 * det));
+        assert_eq!(Matrix::<i64>::zeroes(0, 0).checked_det(), Some(1));
+    }
+
+    #[test]
+    fn ranks() {
+        let a: Matrix<i32> = matrix![1, 2, 3, 4;
+                                     2, 4, 6, 8;
+                                     0, 0, 1, 1];
+        assert_eq!(a.checked_rank(), Some(2));
+        assert_eq!(a.transpose().checked_rank(), Some(2));
+        assert_eq!(Matrix::<i32>::zeroes(3, 2).checked_rank(), Some(0));
+        assert_eq!(Matrix::<i32>::identity(4).checked_rank(), Some(4));
+    }
+
+    #[test]
+    fn overflow() {
+        let a: Matrix<i8> = matrix![100, 100;
+                                    -100, 100];
+        assert_eq!(a.checked_det(), None);
+        assert_eq!(Matrix::<u8>::identity(3).checked_det(), Some(1));
+    }
+}
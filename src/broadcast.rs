@@ -0,0 +1,117 @@
+//! Combining a matrix with a single row or column, which is repeated to cover the whole matrix.
+
+use std::ops;
+
+use crate::Matrix;
+
+impl<T: Default + Copy> Matrix<T> {
+    // applies `f` to every element and the element of `row` in the same column
+    fn with_row<F: Fn(T, T) -> T>(&self, row: &Matrix<T>, f: F) -> Matrix<T> {
+        assert!(row.rows == 1 && row.columns == self.columns,
+            "A row vector of dimensions ({}, {}) can't be broadcast across a matrix of dimensions ({}, {}).",
+            row.rows, row.columns, self.rows, self.columns);
+
+        Matrix::from_fn(self.rows, self.columns, |r, c| f(self.contents[r * self.columns + c], row.contents[c]))
+    }
+
+    // applies `f` to every element and the element of `col` in the same row
+    fn with_col<F: Fn(T, T) -> T>(&self, col: &Matrix<T>, f: F) -> Matrix<T> {
+        assert!(col.columns == 1 && col.rows == self.rows,
+            "A column vector of dimensions ({}, {}) can't be broadcast across a matrix of dimensions ({}, {}).",
+            col.rows, col.columns, self.rows, self.columns);
+
+        Matrix::from_fn(self.rows, self.columns, |r, c| f(self.contents[r * self.columns + c], col.contents[r]))
+    }
+}
+
+impl<T: Default + Copy + ops::Add<Output = T>> Matrix<T> {
+    /// Adds the 1×n matrix `row` to every row.
+    pub fn add_row_vector(&self, row: &Matrix<T>) -> Matrix<T> {
+        self.with_row(row, |a, b| a + b)
+    }
+
+    /// Adds the m×1 matrix `col` to every column.
+    pub fn add_col_vector(&self, col: &Matrix<T>) -> Matrix<T> {
+        self.with_col(col, |a, b| a + b)
+    }
+}
+
+impl<T: Default + Copy + ops::Sub<Output = T>> Matrix<T> {
+    /// Subtracts the 1×n matrix `row` from every row.
+    pub fn sub_row_vector(&self, row: &Matrix<T>) -> Matrix<T> {
+        self.with_row(row, |a, b| a - b)
+    }
+
+    /// Subtracts the m×1 matrix `col` from every column.
+    pub fn sub_col_vector(&self, col: &Matrix<T>) -> Matrix<T> {
+        self.with_col(col, |a, b| a - b)
+    }
+}
+
+impl<T: Default + Copy + ops::Mul<Output = T>> Matrix<T> {
+    /// Multiplies every row element-wise by the 1×n matrix `row`.
+    pub fn mul_row_vector(&self, row: &Matrix<T>) -> Matrix<T> {
+        self.with_row(row, |a, b| a * b)
+    }
+
+    /// Multiplies every column element-wise by the m×1 matrix `col`.
+    pub fn mul_col_vector(&self, col: &Matrix<T>) -> Matrix<T> {
+        self.with_col(col, |a, b| a * b)
+    }
+}
+
+impl<T: Default + Copy + ops::Div<Output = T>> Matrix<T> {
+    /// Divides every row element-wise by the 1×n matrix `row`.
+    pub fn div_row_vector(&self, row: &Matrix<T>) -> Matrix<T> {
+        self.with_row(row, |a, b| a / b)
+    }
+
+    /// Divides every column element-wise by the m×1 matrix `col`.
+    pub fn div_col_vector(&self, col: &Matrix<T>) -> Matrix<T> {
+        self.with_col(col, |a, b| a / b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn centering_columns() {
+        let data: Matrix<f64> = matrix![1.0, 10.0;
+                                        3.0, 30.0];
+        let means = Matrix::new(1, 2, data.col_sums()) / 2.0;
+
+        let centered = data.sub_row_vector(&means);
+        assert_eq!(centered, matrix![-1.0, -10.0; 1.0, 10.0]);
+        assert_eq!(centered.add_row_vector(&means), data);
+    }
+
+    #[test]
+    fn column_broadcasting() {
+        let m: Matrix<i32> = matrix![1, 2, 3;
+                                     4, 5, 6];
+        let col: Matrix<i32> = matrix![10; 100];
+
+        assert_eq!(m.add_col_vector(&col), matrix![11, 12, 13; 104, 105, 106]);
+        assert_eq!(m.sub_col_vector(&col), matrix![-9, -8, -7; -96, -95, -94]);
+        assert_eq!(m.mul_col_vector(&col), matrix![10, 20, 30; 400, 500, 600]);
+        assert_eq!(m.mul_col_vector(&col).div_col_vector(&col), m);
+    }
+
+    #[test]
+    fn row_scaling() {
+        let m: Matrix<i32> = matrix![1, 2;
+                                     3, 4];
+
+        assert_eq!(m.mul_row_vector(&matrix![2, 0]), matrix![2, 0; 6, 0]);
+        assert_eq!(m.div_row_vector(&matrix![1, 2]), matrix![1, 1; 3, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "A row vector of dimensions (2, 1) can't be broadcast")]
+    fn wrong_orientation() {
+        let m: Matrix<i32> = Matrix::zeroes(2, 2);
+        m.add_row_vector(&matrix![1; 2]);
+    }
+}
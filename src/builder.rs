@@ -0,0 +1,103 @@
+use crate::{Matrix, MatrixError};
+
+/// Builds a matrix row by row, for when the number of rows isn't known up front
+/// (e.g. when parsing a file).
+///
+/// ```
+/// use matricks::MatrixBuilder;
+///
+/// let mut builder = MatrixBuilder::new();
+/// for line in "1 2\n3 4\n5 6".lines() {
+///     builder.push_row(line.split(' ').map(|x| x.parse::<i32>().unwrap())).unwrap();
+/// }
+/// let m = builder.build();
+/// assert_eq!(m.shape(), (3, 2).into());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct MatrixBuilder<T> {
+    columns: Option<usize>,
+    rows: usize,
+    contents: Vec<T>
+}
+
+impl<T: Default> MatrixBuilder<T> {
+    /// Creates a builder that takes its width from the first row pushed into it.
+    pub fn new() -> MatrixBuilder<T> {
+        MatrixBuilder { columns: None, rows: 0, contents: Vec::new() }
+    }
+
+    /// Creates a builder that only accepts rows of the given width.
+    pub fn with_columns(columns: usize) -> MatrixBuilder<T> {
+        MatrixBuilder { columns: Some(columns), rows: 0, contents: Vec::new() }
+    }
+
+    /// Appends a row. If it doesn't have the same length as the rows before it,
+    /// it's rejected and the builder is left as it was.
+    pub fn push_row<I: IntoIterator<Item = T>>(&mut self, row: I) -> Result<(), MatrixError> {
+        let start = self.contents.len();
+        self.contents.extend(row);
+        let length = self.contents.len() - start;
+
+        match self.columns {
+            Some(columns) if columns != length => {
+                self.contents.truncate(start);
+                Err(MatrixError::ElementCount { expected: columns, found: length })
+            },
+            _ => {
+                self.columns = Some(length);
+                self.rows += 1;
+                Ok(())
+            }
+        }
+    }
+
+    /// The number of rows pushed so far.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Finishes the matrix. Without any rows, this gives a 0 by n matrix if the
+    /// width was given up front, or a 0 by 0 one otherwise.
+    pub fn build(self) -> Matrix<T> {
+        Matrix {
+            rows: self.rows,
+            columns: self.columns.unwrap_or(0),
+            contents: self.contents
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_row_by_row() {
+        let mut b = MatrixBuilder::new();
+        b.push_row(vec![1, 2, 3]).unwrap();
+        b.push_row(4..7).unwrap();
+
+        assert_eq!(b.rows(), 2);
+        let m = b.build();
+        assert_eq!(m.shape(), (2, 3).into());
+        assert_eq!(m.contents, vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn rejects_wrong_width() {
+        let mut b = MatrixBuilder::with_columns(2);
+
+        assert_eq!(b.push_row(vec![1, 2, 3]), Err(MatrixError::ElementCount { expected: 2, found: 3 }));
+        b.push_row(vec![1, 2]).unwrap();
+        assert!(b.push_row(vec![1]).is_err());
+
+        let m = b.build();
+        assert_eq!(m.contents, vec![1, 2]);
+    }
+
+    #[test]
+    fn empty_builds() {
+        assert_eq!(MatrixBuilder::<i32>::new().build().shape(), (0, 0).into());
+        assert_eq!(MatrixBuilder::<i32>::with_columns(4).build().shape(), (0, 4).into());
+    }
+}
@@ -0,0 +1,224 @@
+//! Complex numbers, and the matrix operations that only make sense for them.
+//!
+//! `Complex` has the same public fields and basic methods as `num_complex::Complex`, so code
+//! written against one carries over to the other.
+
+use std::fmt;
+use std::ops;
+
+use crate::{Matrix, One, Real, Zero};
+
+/// A complex number `re + im·i`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Complex<T> {
+    pub re: T,
+    pub im: T
+}
+
+impl<T: Real> Complex<T> {
+    pub fn new(re: T, im: T) -> Complex<T> {
+        Complex { re, im }
+    }
+
+    /// The imaginary unit.
+    pub fn i() -> Complex<T> {
+        Complex { re: T::zero(), im: T::one() }
+    }
+
+    /// The complex conjugate `re - im·i`.
+    pub fn conj(&self) -> Complex<T> {
+        Complex { re: self.re, im: -self.im }
+    }
+
+    /// The absolute value, or modulus.
+    pub fn norm(&self) -> T {
+        self.re.hypot(self.im)
+    }
+
+    /// The squared absolute value, which doesn't need a square root.
+    pub fn norm_sqr(&self) -> T {
+        self.re * self.re + self.im * self.im
+    }
+}
+
+impl<T: Real> From<T> for Complex<T> {
+    fn from(re: T) -> Complex<T> {
+        Complex { re, im: T::zero() }
+    }
+}
+
+impl<T: Real> ops::Add for Complex<T> {
+    type Output = Complex<T>;
+    fn add(self, o: Complex<T>) -> Complex<T> {
+        Complex { re: self.re + o.re, im: self.im + o.im }
+    }
+}
+
+impl<T: Real> ops::Sub for Complex<T> {
+    type Output = Complex<T>;
+    fn sub(self, o: Complex<T>) -> Complex<T> {
+        Complex { re: self.re - o.re, im: self.im - o.im }
+    }
+}
+
+impl<T: Real> ops::Mul for Complex<T> {
+    type Output = Complex<T>;
+    fn mul(self, o: Complex<T>) -> Complex<T> {
+        Complex {
+            re: self.re * o.re - self.im * o.im,
+            im: self.re * o.im + self.im * o.re
+        }
+    }
+}
+
+impl<T: Real> ops::Div for Complex<T> {
+    type Output = Complex<T>;
+    fn div(self, o: Complex<T>) -> Complex<T> {
+        let d = o.norm_sqr();
+        Complex {
+            re: (self.re * o.re + self.im * o.im) / d,
+            im: (self.im * o.re - self.re * o.im) / d
+        }
+    }
+}
+
+impl<T: Real> ops::Neg for Complex<T> {
+    type Output = Complex<T>;
+    fn neg(self) -> Complex<T> {
+        Complex { re: -self.re, im: -self.im }
+    }
+}
+
+impl<T: Real> Zero for Complex<T> {
+    fn zero() -> Complex<T> {
+        Complex { re: T::zero(), im: T::zero() }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.re.is_zero() && self.im.is_zero()
+    }
+}
+
+impl<T: Real> One for Complex<T> {
+    fn one() -> Complex<T> {
+        Complex { re: T::one(), im: T::zero() }
+    }
+}
+
+/// Renders as `1+2i` or `1-2i`. Width and alignment apply to the whole thing, so complex
+/// matrices line up like any other when displayed.
+impl<T: Real> fmt::Display for Complex<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = if self.im < T::zero() {
+            format!("{}-{}i", self.re, -self.im)
+        } else {
+            format!("{}+{}i", self.re, self.im)
+        };
+        f.pad(&s)
+    }
+}
+
+/// Types with a complex conjugate. For real numbers that's the number itself.
+pub trait Conjugate {
+    fn conjugate(&self) -> Self;
+}
+
+macro_rules! impl_real_conjugate {
+    ($($t:ty),*) => {
+        $(
+            impl Conjugate for $t {
+                fn conjugate(&self) -> $t { *self }
+            }
+        )*
+    };
+}
+
+impl_real_conjugate!(i8, i16, i32, i64, i128, isize, f32, f64);
+
+impl<T: Real> Conjugate for Complex<T> {
+    fn conjugate(&self) -> Complex<T> {
+        self.conj()
+    }
+}
+
+impl<T: Default + Clone + Conjugate> Matrix<T> {
+    /// Conjugates every element.
+    pub fn conjugate(&self) -> Matrix<T> {
+        self.map(Conjugate::conjugate)
+    }
+
+    /// The conjugate transpose, or Hermitian adjoint, `Aᴴ`. For real matrices this is just the transpose.
+    pub fn conjugate_transpose(&self) -> Matrix<T> {
+        Matrix::from_fn(self.columns, self.rows, |r, c| self.contents[c * self.columns + r].conjugate())
+    }
+}
+
+impl<T: Default + Clone + Conjugate + PartialEq> Matrix<T> {
+    /// Whether the matrix equals its own conjugate transpose. For real matrices this is the same as `is_symmetric`.
+    pub fn is_hermitian(&self) -> bool {
+        if !self.is_square() {
+            return false;
+        }
+
+        for r in 0..self.rows {
+            for c in r..self.columns {
+                if self.contents[r * self.columns + c] != self.contents[c * self.columns + r].conjugate() {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn c(re: f64, im: f64) -> Complex<f64> {
+        Complex::new(re, im)
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = c(1.0, 2.0);
+        let b = c(3.0, -1.0);
+
+        assert_eq!(a + b, c(4.0, 1.0));
+        assert_eq!(a * b, c(5.0, 5.0));
+        assert_eq!((a * b) / b, a);
+        assert_eq!(Complex::<f64>::i() * Complex::i(), -Complex::one());
+        assert_eq!(c(3.0, 4.0).norm(), 5.0);
+    }
+
+    #[test]
+    fn adjoint() {
+        let m: Matrix<Complex<f64>> = matrix![c(1.0, 1.0), c(2.0, 0.0);
+                                              c(0.0, -3.0), c(4.0, 5.0)];
+
+        assert_eq!(m.conjugate_transpose(), matrix![c(1.0, -1.0), c(0.0, 3.0);
+                                                    c(2.0, 0.0), c(4.0, -5.0)]);
+        assert_eq!(m.conjugate()[(1, 1)], c(4.0, -5.0));
+        assert_eq!(m.conjugate_transpose().conjugate_transpose(), m);
+    }
+
+    #[test]
+    fn hermitian() {
+        let h: Matrix<Complex<f64>> = matrix![c(2.0, 0.0), c(1.0, -1.0);
+                                              c(1.0, 1.0), c(3.0, 0.0)];
+        let not_real_diagonal: Matrix<Complex<f64>> = matrix![c(2.0, 1.0)];
+
+        assert!(h.is_hermitian());
+        assert!(!h.is_symmetric());
+        assert!(!not_real_diagonal.is_hermitian());
+        assert!(Matrix::<f64>::identity(3).is_hermitian());
+    }
+
+    #[test]
+    fn display() {
+        let m: Matrix<Complex<f64>> = matrix![c(1.0, -2.0), c(10.0, 0.5)];
+
+        assert_eq!(c(1.0, -2.0).to_string(), "1-2i");
+        assert_eq!(m.to_string(), "┌                 ┐\n│    1-2i 10+0.5i │\n└                 ┘");
+    }
+}
@@ -0,0 +1,205 @@
+use crate::{Matrix, MatrixError, Real, Vector};
+
+/// The Cholesky decomposition `A = L Lᵀ` of a symmetric positive definite matrix, where `L` is
+/// lower triangular with a positive diagonal.
+///
+/// It takes about half the work of an LU decomposition, and doesn't need any pivoting.
+#[derive(Clone, Debug)]
+pub struct Cholesky<T: Default> {
+    l: Matrix<T>
+}
+
+impl<T: Real> Matrix<T> {
+    /// Computes the Cholesky decomposition.
+    ///
+    /// Only the lower triangle of the matrix is read; the upper one is assumed to mirror it.
+    /// Fails with `NotPositiveDefinite` if the matrix turns out not to be positive definite.
+    pub fn cholesky(&self) -> Result<Cholesky<T>, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare { rows: self.rows, columns: self.columns });
+        }
+
+        let n = self.rows;
+        let mut l = Matrix::zeroes(n, n);
+        for c in 0..n {
+            let mut d = self.contents[c * n + c];
+            for k in 0..c {
+                d = d - l.contents[c * n + k] * l.contents[c * n + k];
+            }
+            if d <= T::zero() || !d.is_finite() {
+                return Err(MatrixError::NotPositiveDefinite);
+            }
+            let d = d.sqrt();
+            l.contents[c * n + c] = d;
+
+            for r in c + 1..n {
+                let mut s = self.contents[r * n + c];
+                for k in 0..c {
+                    s = s - l.contents[r * n + k] * l.contents[c * n + k];
+                }
+                l.contents[r * n + c] = s / d;
+            }
+        }
+
+        Ok(Cholesky { l })
+    }
+}
+
+impl<T: Real> Cholesky<T> {
+    /// The lower triangular factor `L`.
+    pub fn l(&self) -> &Matrix<T> {
+        &self.l
+    }
+
+    /// Consumes the decomposition, returning `L`.
+    pub fn into_l(self) -> Matrix<T> {
+        self.l
+    }
+
+    /// The determinant of the decomposed matrix, which is the squared product of the diagonal of `L`.
+    pub fn det(&self) -> T {
+        let product = self.l.diagonal().into_iter().fold(T::one(), |acc, d| acc * d);
+        product * product
+    }
+
+    /// Turns this into the decomposition of `A + x xᵀ`, in O(n²) rather than the O(n³) of
+    /// decomposing that from scratch. The result is always positive definite, so this only fails
+    /// with `DimensionMismatch` if `x` has the wrong length.
+    pub fn cholesky_update(&mut self, x: &Vector<T>) -> Result<(), MatrixError> {
+        self.rank_one(x, false)
+    }
+
+    /// Turns this into the decomposition of `A - x xᵀ`, the counterpart of `cholesky_update`,
+    /// e.g. for removing an observation from a covariance matrix. Fails with `NotPositiveDefinite`
+    /// if `A - x xᵀ` isn't positive definite, in which case the decomposition is left as it was.
+    pub fn cholesky_downdate(&mut self, x: &Vector<T>) -> Result<(), MatrixError> {
+        self.rank_one(x, true)
+    }
+
+    // a sequence of rotations (hyperbolic ones for a downdate) that fold x into L column by column
+    fn rank_one(&mut self, x: &Vector<T>, downdate: bool) -> Result<(), MatrixError> {
+        let n = self.l.rows;
+        if x.len() != n {
+            return Err(MatrixError::DimensionMismatch { operation: "a rank one update", left: (n, n), right: (x.len(), 1) });
+        }
+
+        let mut l = self.l.clone();
+        let mut x: Vec<T> = x.iter().copied().collect();
+        for k in 0..n {
+            let d = l.contents[k * n + k];
+            let r = if downdate {
+                let square = d * d - x[k] * x[k];
+                if square <= T::zero() || !square.is_finite() {
+                    return Err(MatrixError::NotPositiveDefinite);
+                }
+                square.sqrt()
+            } else {
+                d.hypot(x[k])
+            };
+            let (c, s) = (r / d, x[k] / d);
+            l.contents[k * n + k] = r;
+
+            for (i, xi) in x.iter_mut().enumerate().skip(k + 1) {
+                let e = l.contents[i * n + k];
+                let updated = if downdate { (e - s * *xi) / c } else { (e + s * *xi) / c };
+                l.contents[i * n + k] = updated;
+                *xi = c * *xi - s * updated;
+            }
+        }
+
+        self.l = l;
+        Ok(())
+    }
+
+    /// Solves `A x = b` by a forward substitution with `L` and a back substitution with `Lᵀ`.
+    pub fn solve(&self, b: &Vector<T>) -> Result<Vector<T>, MatrixError> {
+        let n = self.l.rows;
+        if b.len() != n {
+            return Err(MatrixError::DimensionMismatch { operation: "solving", left: (n, n), right: (b.len(), 1) });
+        }
+
+        let l = &self.l.contents;
+        let mut x: Vec<T> = b.iter().copied().collect();
+        for r in 0..n {
+            for c in 0..r {
+                x[r] = x[r] - l[r * n + c] * x[c];
+            }
+            x[r] = x[r] / l[r * n + r];
+        }
+        for r in (0..n).rev() {
+            for c in r + 1..n {
+                x[r] = x[r] - l[c * n + r] * x[c];
+            }
+            x[r] = x[r] / l[r * n + r];
+        }
+        Ok(Vector::new(x))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spd() -> Matrix<f64> {
+        matrix![4.0, 12.0, -16.0;
+                12.0, 37.0, -43.0;
+                -16.0, -43.0, 98.0]
+    }
+
+    #[test]
+    fn factor() {
+        let chol = spd().cholesky().unwrap();
+
+        assert_eq!(chol.l(), &matrix![2.0, 0.0, 0.0;
+                                      6.0, 1.0, 0.0;
+                                      -8.0, 5.0, 3.0]);
+        assert_eq!(&chol.l().clone() * &chol.l().transpose(), spd());
+        assert_eq!(chol.det(), 36.0);
+    }
+
+    #[test]
+    fn solving() {
+        let a = spd();
+        let b = Vector::new(vec![1.0, 2.0, 3.0]);
+
+        let x = a.cholesky().unwrap().solve(&b).unwrap();
+        let expected = a.lu().unwrap().solve(&b).unwrap();
+        assert!(x.iter().zip(&expected).all(|(p, q)| (p - q).abs() < 1e-9));
+    }
+
+    #[test]
+    fn rank_one_changes() {
+        let a = spd();
+        let x = Vector::new(vec![1.0, -2.0, 0.5]);
+        let xxt = Matrix::outer(&x, &x);
+
+        let mut chol = a.cholesky().unwrap();
+        chol.cholesky_update(&x).unwrap();
+        assert!((chol.l() * &chol.l().transpose()).approx_eq(&(&a + &xxt), 1e-12, 0.0));
+        assert!(chol.l().approx_eq((&a + &xxt).cholesky().unwrap().l(), 1e-12, 0.0));
+
+        chol.cholesky_downdate(&x).unwrap();
+        assert!(chol.l().approx_eq(a.cholesky().unwrap().l(), 1e-12, 0.0));
+    }
+
+    #[test]
+    fn failed_downdate() {
+        let mut chol = spd().cholesky().unwrap();
+        let before = chol.l().clone();
+
+        assert_eq!(chol.cholesky_downdate(&Vector::new(vec![3.0, 0.0, 0.0])), Err(MatrixError::NotPositiveDefinite));
+        assert_eq!(chol.l(), &before);
+        assert_eq!(chol.cholesky_update(&Vector::new(vec![1.0])),
+            Err(MatrixError::DimensionMismatch { operation: "a rank one update", left: (3, 3), right: (1, 1) }));
+    }
+
+    #[test]
+    fn not_positive_definite() {
+        let indefinite: Matrix<f64> = matrix![1.0, 2.0;
+                                              2.0, 1.0];
+
+        assert_eq!(indefinite.cholesky().unwrap_err(), MatrixError::NotPositiveDefinite);
+        assert_eq!(Matrix::<f64>::zeroes(2, 2).cholesky().unwrap_err(), MatrixError::NotPositiveDefinite);
+        assert_eq!(Matrix::<f64>::zeroes(2, 3).cholesky().unwrap_err(), MatrixError::NotSquare { rows: 2, columns: 3 });
+    }
+}
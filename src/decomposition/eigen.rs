@@ -0,0 +1,202 @@
+use crate::{Matrix, MatrixError, Real};
+
+/// The eigendecomposition `A = V Λ Vᵀ` of a symmetric matrix.
+///
+/// The eigenvalues are real and sorted in increasing order, and the columns of the orthogonal
+/// matrix `V` are the corresponding eigenvectors.
+#[derive(Clone, Debug)]
+pub struct SymmetricEigen<T: Default> {
+    eigenvalues: Vec<T>,
+    eigenvectors: Matrix<T>
+}
+
+impl<T: Real> Matrix<T> {
+    /// Computes the eigenvalues and eigenvectors of a symmetric matrix with the cyclic Jacobi
+    /// method, to full machine precision.
+    ///
+    /// A rotation is skipped once `|a_pq| <= ε ‖A‖` (Frobenius norm), which is below the
+    /// rounding error the rotations themselves make, and the iteration stops after a sweep
+    /// without any rotations. So the eigenvalues are accurate to about `n ε ‖A‖`.
+    ///
+    /// Only the lower triangle of the matrix is read; the upper one is assumed to mirror it.
+    pub fn symmetric_eigen(&self) -> Result<SymmetricEigen<T>, MatrixError> {
+        self.jacobi_eigen(None, 100)
+    }
+
+    /// Like `symmetric_eigen`, but stops as soon as the off-diagonal part has shrunk to
+    /// `tolerance` times the size of the whole matrix (both measured by the Frobenius norm),
+    /// or fails with `NoConvergence` after `max_sweeps` passes over all pairs of rows.
+    ///
+    /// Rounding keeps the off-diagonal part from shrinking much below `n ε` times the whole,
+    /// so smaller tolerances may never be reached.
+    pub fn symmetric_eigen_with(&self, tolerance: T, max_sweeps: usize) -> Result<SymmetricEigen<T>, MatrixError> {
+        self.jacobi_eigen(Some(tolerance), max_sweeps)
+    }
+
+    // stops at the relative tolerance if there is one, and otherwise once a sweep finds
+    // nothing left worth rotating
+    fn jacobi_eigen(&self, tolerance: Option<T>, max_sweeps: usize) -> Result<SymmetricEigen<T>, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare { rows: self.rows, columns: self.columns });
+        }
+
+        let n = self.rows;
+        let mut a = Matrix::from_fn(n, n, |r, c| {
+            if r >= c { self.contents[r * n + c] } else { self.contents[c * n + r] }
+        });
+        let mut v: Matrix<T> = Matrix::identity(n);
+        let total = a.contents.iter().fold(T::zero(), |acc, &e| acc + e * e).sqrt();
+
+        let mut sweeps = 0;
+        loop {
+            if let Some(tolerance) = tolerance {
+                let off = (0..n).flat_map(|r| (0..n).filter(move |&c| c != r).map(move |c| (r, c)))
+                    .fold(T::zero(), |acc, (r, c)| acc + a.contents[r * n + c] * a.contents[r * n + c])
+                    .sqrt();
+                if off <= tolerance * total {
+                    break;
+                }
+            }
+            if sweeps == max_sweeps {
+                return Err(MatrixError::NoConvergence { iterations: max_sweeps });
+            }
+            sweeps += 1;
+
+            let mut rotated = false;
+            for p in 0..n {
+                for q in p + 1..n {
+                    let apq = a.contents[p * n + q];
+                    let negligible = tolerance.is_none() && apq.abs() <= T::epsilon() * total;
+                    if apq.is_zero() || negligible {
+                        continue;
+                    }
+                    rotated = true;
+
+                    let theta = (a.contents[q * n + q] - a.contents[p * n + p]) / (apq + apq);
+                    let sign = if theta < T::zero() { -T::one() } else { T::one() };
+                    let t = sign / (theta.abs() + (theta * theta + T::one()).sqrt());
+                    let c = T::one() / (t * t + T::one()).sqrt();
+                    let s = t * c;
+
+                    rotate_columns(&mut a, p, q, c, s);
+                    rotate_rows(&mut a, p, q, c, s);
+                    rotate_columns(&mut v, p, q, c, s);
+                }
+            }
+            if !rotated {
+                break;
+            }
+        }
+
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&x, &y| a.contents[x * n + x].partial_cmp(&a.contents[y * n + y]).unwrap_or(std::cmp::Ordering::Equal));
+
+        Ok(SymmetricEigen {
+            eigenvalues: order.iter().map(|&i| a.contents[i * n + i]).collect(),
+            eigenvectors: Matrix::from_fn(n, n, |r, c| v.contents[r * n + order[c]])
+        })
+    }
+}
+
+impl<T: Real> SymmetricEigen<T> {
+    /// The eigenvalues, in increasing order.
+    pub fn eigenvalues(&self) -> &[T] {
+        &self.eigenvalues
+    }
+
+    /// The eigenvectors, as the columns of an orthogonal matrix, in the same order as the eigenvalues.
+    pub fn eigenvectors(&self) -> &Matrix<T> {
+        &self.eigenvectors
+    }
+}
+
+// replaces columns p and q by c p - s q and s p + c q
+fn rotate_columns<T: Real>(m: &mut Matrix<T>, p: usize, q: usize, c: T, s: T) {
+    let n = m.columns;
+    for k in 0..m.rows {
+        let (x, y) = (m.contents[k * n + p], m.contents[k * n + q]);
+        m.contents[k * n + p] = c * x - s * y;
+        m.contents[k * n + q] = s * x + c * y;
+    }
+}
+
+fn rotate_rows<T: Real>(m: &mut Matrix<T>, p: usize, q: usize, c: T, s: T) {
+    let n = m.columns;
+    for k in 0..n {
+        let (x, y) = (m.contents[p * n + k], m.contents[q * n + k]);
+        m.contents[p * n + k] = c * x - s * y;
+        m.contents[q * n + k] = s * x + c * y;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn small_example() {
+        let a: Matrix<f64> = matrix![2.0, 1.0;
+                                     1.0, 2.0];
+
+        let eigen = a.symmetric_eigen().unwrap();
+        assert!((eigen.eigenvalues()[0] - 1.0).abs() < 1e-14);
+        assert!((eigen.eigenvalues()[1] - 3.0).abs() < 1e-14);
+
+        let v = eigen.eigenvectors();
+        assert!((v[(0, 1)].abs() - 0.5f64.sqrt()).abs() < 1e-14);
+        assert!((v[(0, 1)] - v[(1, 1)]).abs() < 1e-14);
+    }
+
+    #[test]
+    fn reconstructs() {
+        let a: Matrix<f64> = matrix![4.0, -2.0, 1.0, 0.5;
+                                     -2.0, 5.0, 0.0, 1.0;
+                                     1.0, 0.0, 3.0, -1.0;
+                                     0.5, 1.0, -1.0, 6.0];
+
+        let eigen = a.symmetric_eigen().unwrap();
+        let v = eigen.eigenvectors();
+        let mut lambda = Matrix::zeroes(4, 4);
+        lambda.set_diagonal(eigen.eigenvalues());
+
+        assert!((&v.transpose() * v).approx_eq(&Matrix::identity(4), 1e-12, 0.0));
+        assert!((&(v * &lambda) * &v.transpose()).approx_eq(&a, 1e-12, 0.0));
+        assert!(eigen.eigenvalues().windows(2).all(|w| w[0] <= w[1]));
+        assert!((eigen.eigenvalues().iter().sum::<f64>() - a.trace()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn tolerance_and_limits() {
+        let a: Matrix<f64> = Matrix::hilbert(5);
+
+        let rough = a.symmetric_eigen_with(1e-3, 100).unwrap();
+        assert!((rough.eigenvalues()[4] - 1.5670506910982311).abs() < 1e-3);
+        assert_eq!(a.symmetric_eigen_with(0.0, 1).unwrap_err(), MatrixError::NoConvergence { iterations: 1 });
+        assert_eq!(Matrix::<f64>::zeroes(2, 3).symmetric_eigen().unwrap_err(), MatrixError::NotSquare { rows: 2, columns: 3 });
+    }
+
+    #[test]
+    fn dense_and_larger() {
+        // a dense symmetric matrix with no structure to speak of
+        let n = 40;
+        let a: Matrix<f64> = Matrix::from_fn(n, n, |r, c| {
+            let (i, j) = (r.min(c) as f64, r.max(c) as f64);
+            (i * 12.9898 + j * 78.233).sin() * 43758.5453 % 1.0
+        });
+
+        let eigen = a.symmetric_eigen().unwrap();
+        let v = eigen.eigenvectors();
+        let mut lambda = Matrix::zeroes(n, n);
+        lambda.set_diagonal(eigen.eigenvalues());
+
+        assert!((&v.transpose() * v).approx_eq(&Matrix::identity(n), 1e-12, 0.0));
+        assert!((&(v * &lambda) * &v.transpose()).approx_eq(&a, 1e-12, 0.0));
+        assert!(a.symmetric_eigen_with(1e-14, 100).is_ok());
+
+        // rank deficient, so a whole cluster of eigenvalues is zero up to rounding
+        let b: Matrix<f64> = Matrix::from_fn(20, 30, |r, c| (r as f64 * 12.9898 + c as f64 * 78.233).sin() * 43758.5453 % 1.0);
+        let eigen = b.gram().symmetric_eigen().unwrap();
+        assert!(eigen.eigenvalues()[..10].iter().all(|e| e.abs() < 1e-12));
+        assert!(eigen.eigenvalues()[10] > 1e-3);
+    }
+}
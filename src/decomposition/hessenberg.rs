@@ -0,0 +1,418 @@
+use crate::{Complex, Matrix, MatrixError, Real};
+
+impl<T: Real> Matrix<T> {
+    /// The eigenvalues of a general real square matrix, which may be complex.
+    ///
+    /// The matrix is first reduced to upper Hessenberg form by Householder reflections, which
+    /// the Francis double-shift QR iteration then drives to quasi-triangular form. Complex
+    /// eigenvalues come in conjugate pairs, and the result is sorted by decreasing real part
+    /// (and decreasing imaginary part among equal real parts). Fails with `NoConvergence` in
+    /// the rare case that an eigenvalue takes more than 30 iterations to split off.
+    pub fn eigenvalues(&self) -> Result<Vec<Complex<T>>, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare { rows: self.rows, columns: self.columns });
+        }
+
+        let (mut h, _) = self.hessenberg(false);
+        let mut values = hessenberg_qr(&mut h, None)?;
+        values.sort_by(|a, b| {
+            b.re.partial_cmp(&a.re).unwrap_or(std::cmp::Ordering::Equal)
+                .then(b.im.partial_cmp(&a.im).unwrap_or(std::cmp::Ordering::Equal))
+        });
+        Ok(values)
+    }
+
+    /// The coefficients of the characteristic polynomial `det(λI - A)`, from the highest power
+    /// of λ down, so the first is always 1, the second minus the trace and the last `(-1)ⁿ`
+    /// times the determinant.
+    ///
+    /// The matrix is reduced to Hessenberg form first, which has the same characteristic
+    /// polynomial, and the polynomials of its leading principal submatrices are then built up
+    /// one row at a time (La Budde's method). Fails with `NotSquare`.
+    pub fn char_poly(&self) -> Result<Vec<T>, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare { rows: self.rows, columns: self.columns });
+        }
+
+        let n = self.rows;
+        let (h, _) = self.hessenberg(false);
+        // polys[i] holds the characteristic polynomial of the leading i×i submatrix, lowest
+        // power first
+        let mut polys: Vec<Vec<T>> = vec![vec![T::one()]];
+        for i in 1..=n {
+            let previous = &polys[i - 1];
+            let mut p = vec![T::zero(); i + 1];
+            for (power, &c) in previous.iter().enumerate() {
+                p[power + 1] = p[power + 1] + c;
+                p[power] = p[power] - h[(i - 1, i - 1)] * c;
+            }
+
+            let mut subdiagonal = T::one();
+            for m in 1..i {
+                subdiagonal = subdiagonal * h[(i - m, i - m - 1)];
+                let factor = h[(i - m - 1, i - 1)] * subdiagonal;
+                for (power, &c) in polys[i - m - 1].iter().enumerate() {
+                    p[power] = p[power] - factor * c;
+                }
+            }
+            polys.push(p);
+        }
+
+        let mut coefficients = polys.pop().unwrap();
+        coefficients.reverse();
+        Ok(coefficients)
+    }
+
+    /// Reduces a square matrix to upper Hessenberg form `H = Qᵀ A Q`, i.e. zero below the first
+    /// subdiagonal, with an orthogonal `Q` which is only accumulated if asked for.
+    pub(crate) fn hessenberg(&self, want_q: bool) -> (Matrix<T>, Option<Matrix<T>>) {
+        let n = self.rows;
+        let mut h = self.clone();
+        let mut q = if want_q { Some(Matrix::identity(n)) } else { None };
+
+        for k in 0..n.saturating_sub(2) {
+            let alpha = h[(k + 1, k)];
+            let tail = (k + 2..n).fold(T::zero(), |acc, r| acc.hypot(h[(r, k)]));
+            if tail.is_zero() {
+                continue;
+            }
+
+            // the reflector I - tau v vᵀ maps column k below the diagonal onto a multiple of e_1
+            let norm = alpha.hypot(tail);
+            let beta = if alpha > T::zero() { -norm } else { norm };
+            let tau = (beta - alpha) / beta;
+            let mut v = vec![T::zero(); n];
+            v[k + 1] = T::one();
+            for r in k + 2..n {
+                v[r] = h[(r, k)] / (alpha - beta);
+            }
+
+            // from the left, on rows k + 1..
+            for c in 0..n {
+                let w = (k + 1..n).fold(T::zero(), |acc, r| acc + v[r] * h[(r, c)]) * tau;
+                for r in k + 1..n {
+                    h[(r, c)] = h[(r, c)] - w * v[r];
+                }
+            }
+            // and from the right, on columns k + 1..
+            for m in std::iter::once(&mut h).chain(q.as_mut()) {
+                for r in 0..n {
+                    let w = (k + 1..n).fold(T::zero(), |acc, c| acc + m[(r, c)] * v[c]) * tau;
+                    for c in k + 1..n {
+                        m[(r, c)] = m[(r, c)] - w * v[c];
+                    }
+                }
+            }
+
+            h[(k + 1, k)] = beta;
+            for r in k + 2..n {
+                h[(r, k)] = T::zero();
+            }
+        }
+
+        (h, q)
+    }
+}
+
+// magnitude of a with the sign of b
+fn sign<T: Real>(a: T, b: T) -> T {
+    if b >= T::zero() { a.abs() } else { -a.abs() }
+}
+
+// the eigenvalues of an upper Hessenberg matrix by the Francis double-shift QR iteration,
+// following the classic EISPACK `hqr`. Without `vectors`, h is destroyed in the process. With it, this is
+// `hqr2`: the transformations are applied to all of h, turning it into the quasi-triangular
+// Schur form, and accumulated into `vectors`, so that the Schur vectors of the original matrix come out
+// if it starts out as the Q of its Hessenberg reduction
+pub(super) fn hessenberg_qr<T: Real>(h: &mut Matrix<T>, mut vectors: Option<&mut Matrix<T>>) -> Result<Vec<Complex<T>>, MatrixError> {
+    const MAX_ITERATIONS: usize = 30;
+    let n = h.rows;
+    let schur = vectors.is_some();
+    let mut values = vec![Complex::new(T::zero(), T::zero()); n];
+    let norm = (0..n).flat_map(|r| (r.saturating_sub(1)..n).map(move |c| (r, c)))
+        .fold(T::zero(), |acc, (r, c)| acc + h[(r, c)].abs());
+    let (half, three_quarters, exceptional) = (T::from_f64(0.5), T::from_f64(0.75), T::from_f64(-0.4375));
+
+    // accumulated exceptional shifts
+    let mut t = T::zero();
+    let mut nn = n as isize - 1;
+    while nn >= 0 {
+        let top = nn as usize;
+        let mut iterations = 0;
+        loop {
+            // look for a single small subdiagonal element to split the matrix at
+            let mut l = top;
+            while l > 0 {
+                let mut s = h[(l - 1, l - 1)].abs() + h[(l, l)].abs();
+                if s.is_zero() {
+                    s = norm;
+                }
+                if h[(l, l - 1)].abs() + s == s {
+                    h[(l, l - 1)] = T::zero();
+                    break;
+                }
+                l -= 1;
+            }
+
+            let mut x = h[(top, top)];
+            if l == top {
+                // a single real root
+                values[top] = Complex::new(x + t, T::zero());
+                h[(top, top)] = x + t;
+                nn -= 1;
+                break;
+            }
+
+            let mut y = h[(top - 1, top - 1)];
+            let mut w = h[(top, top - 1)] * h[(top - 1, top)];
+            if l == top - 1 {
+                // a 2×2 block, with either two real or a pair of complex roots
+                let p = half * (y - x);
+                let q = p * p + w;
+                let z = q.abs().sqrt();
+                x = x + t;
+                h[(top, top)] = x;
+                h[(top - 1, top - 1)] = y + t;
+                if q >= T::zero() {
+                    let z = p + sign(z, p);
+                    let low = if z.is_zero() { x + z } else { x - w / z };
+                    values[top - 1] = Complex::new(x + z, T::zero());
+                    values[top] = Complex::new(low, T::zero());
+
+                    if schur {
+                        // two real roots, so a rotation makes the block upper triangular
+                        let sub = h[(top, top - 1)];
+                        let scale = sub.abs() + z.abs();
+                        let (p, q) = (sub / scale, z / scale);
+                        let r = p.hypot(q);
+                        let (p, q) = (p / r, q / r);
+                        let rotate = |m: &mut Matrix<T>, a: (usize, usize), b: (usize, usize)| {
+                            let e = m[a];
+                            m[a] = q * e + p * m[b];
+                            m[b] = q * m[b] - p * e;
+                        };
+                        for j in top - 1..n {
+                            rotate(h, (top - 1, j), (top, j));
+                        }
+                        for i in 0..=top {
+                            rotate(h, (i, top - 1), (i, top));
+                        }
+                        if let Some(v) = vectors.as_mut() {
+                            for i in 0..n {
+                                rotate(v, (i, top - 1), (i, top));
+                            }
+                        }
+                        h[(top, top - 1)] = T::zero();
+                    }
+                } else {
+                    values[top - 1] = Complex::new(x + p, -z);
+                    values[top] = Complex::new(x + p, z);
+                }
+                nn -= 2;
+                break;
+            }
+
+            if iterations == MAX_ITERATIONS {
+                return Err(MatrixError::NoConvergence { iterations });
+            }
+            if iterations == 10 || iterations == 20 {
+                // an exceptional shift, to break out of cycles
+                t = t + x;
+                for i in 0..=top {
+                    h[(i, i)] = h[(i, i)] - x;
+                }
+                let s = h[(top, top - 1)].abs() + h[(top - 1, top - 2)].abs();
+                x = three_quarters * s;
+                y = x;
+                w = exceptional * s * s;
+            }
+            iterations += 1;
+
+            // form the shift and look for two consecutive small subdiagonal elements
+            let mut m = top - 2;
+            let (mut p, mut q, mut r);
+            loop {
+                let z = h[(m, m)];
+                let rr = x - z;
+                let ss = y - z;
+                p = (rr * ss - w) / h[(m + 1, m)] + h[(m, m + 1)];
+                q = h[(m + 1, m + 1)] - z - rr - ss;
+                r = h[(m + 2, m + 1)];
+                let s = p.abs() + q.abs() + r.abs();
+                p = p / s;
+                q = q / s;
+                r = r / s;
+                if m == l {
+                    break;
+                }
+                let u = h[(m, m - 1)].abs() * (q.abs() + r.abs());
+                let v = p.abs() * (h[(m - 1, m - 1)].abs() + z.abs() + h[(m + 1, m + 1)].abs());
+                if u + v == v {
+                    break;
+                }
+                m -= 1;
+            }
+
+            for i in m + 2..=top {
+                h[(i, i - 2)] = T::zero();
+                if i != m + 2 {
+                    h[(i, i - 3)] = T::zero();
+                }
+            }
+
+            // the double QR step on rows l..=top and columns m..=top
+            for k in m..top {
+                if k != m {
+                    p = h[(k, k - 1)];
+                    q = h[(k + 1, k - 1)];
+                    r = if k != top - 1 { h[(k + 2, k - 1)] } else { T::zero() };
+                    x = p.abs() + q.abs() + r.abs();
+                    if !x.is_zero() {
+                        p = p / x;
+                        q = q / x;
+                        r = r / x;
+                    }
+                }
+
+                let s = sign((p * p + q * q + r * r).sqrt(), p);
+                if s.is_zero() {
+                    continue;
+                }
+                if k == m {
+                    if l != m {
+                        h[(k, k - 1)] = -h[(k, k - 1)];
+                    }
+                } else {
+                    h[(k, k - 1)] = -s * x;
+                }
+                p = p + s;
+                x = p / s;
+                y = q / s;
+                let z = r / s;
+                q = q / p;
+                r = r / p;
+
+                // for the Schur form, the reflector has to act on the whole of h, not just the
+                // active block
+                let (last, first) = if schur { (n - 1, 0) } else { (top, l) };
+                for j in k..=last {
+                    let mut p = h[(k, j)] + q * h[(k + 1, j)];
+                    if k != top - 1 {
+                        p = p + r * h[(k + 2, j)];
+                        h[(k + 2, j)] = h[(k + 2, j)] - p * z;
+                    }
+                    h[(k + 1, j)] = h[(k + 1, j)] - p * y;
+                    h[(k, j)] = h[(k, j)] - p * x;
+                }
+
+                for i in first..=top.min(k + 3) {
+                    let mut p = x * h[(i, k)] + y * h[(i, k + 1)];
+                    if k != top - 1 {
+                        p = p + z * h[(i, k + 2)];
+                        h[(i, k + 2)] = h[(i, k + 2)] - p * r;
+                    }
+                    h[(i, k + 1)] = h[(i, k + 1)] - p * q;
+                    h[(i, k)] = h[(i, k)] - p;
+                }
+
+                if let Some(v) = vectors.as_mut() {
+                    for i in 0..n {
+                        let mut p = x * v[(i, k)] + y * v[(i, k + 1)];
+                        if k != top - 1 {
+                            p = p + z * v[(i, k + 2)];
+                            v[(i, k + 2)] = v[(i, k + 2)] - p * r;
+                        }
+                        v[(i, k + 1)] = v[(i, k + 1)] - p * q;
+                        v[(i, k)] = v[(i, k)] - p;
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: Complex<f64>, re: f64, im: f64) -> bool {
+        (a.re - re).abs() < 1e-10 && (a.im - im).abs() < 1e-10
+    }
+
+    #[test]
+    fn hessenberg_form() {
+        let a: Matrix<f64> = Matrix::from_fn(5, 5, |r, c| ((r * 7 + c * 3) % 5) as f64 - 1.5);
+
+        let (h, q) = a.hessenberg(true);
+        let q = q.unwrap();
+        assert!(h.tril(-2).approx_eq(&Matrix::zeroes(5, 5), 0.0, 0.0));
+        assert!((&q.transpose() * &q).approx_eq(&Matrix::identity(5), 1e-12, 0.0));
+        assert!((&(&q * &h) * &q.transpose()).approx_eq(&a, 1e-12, 0.0));
+    }
+
+    #[test]
+    fn real_eigenvalues() {
+        let a: Matrix<f64> = matrix![2.0, 0.0, 0.0;
+                                     1.0, 3.0, 0.0;
+                                     4.0, 5.0, -1.0];
+
+        let values = a.eigenvalues().unwrap();
+        assert!(close(values[0], 3.0, 0.0) && close(values[1], 2.0, 0.0) && close(values[2], -1.0, 0.0));
+    }
+
+    #[test]
+    fn complex_eigenvalues() {
+        // a rotation by 90 degrees, scaled by 2, next to a plain 5
+        let a: Matrix<f64> = matrix![0.0, -2.0, 0.0;
+                                     2.0, 0.0, 0.0;
+                                     0.0, 0.0, 5.0];
+
+        let values = a.eigenvalues().unwrap();
+        assert!(close(values[0], 5.0, 0.0));
+        assert!(close(values[1], 0.0, 2.0));
+        assert!(close(values[2], 0.0, -2.0));
+    }
+
+    #[test]
+    fn larger_matrix() {
+        let a: Matrix<f64> = Matrix::from_fn(6, 6, |r, c| ((r * 5 + c * 11) % 7) as f64 - 3.0);
+        let values = a.eigenvalues().unwrap();
+
+        // the eigenvalues sum to the trace, and multiply to the determinant
+        let sum = values.iter().fold(Complex::new(0.0, 0.0), |acc, &v| acc + v);
+        let product = values.iter().fold(Complex::new(1.0, 0.0), |acc, &v| acc * v);
+        assert!(close(sum, a.trace(), 0.0));
+        assert!((product.re - a.det()).abs() < 1e-8 * a.det().abs().max(1.0) && product.im.abs() < 1e-8);
+    }
+
+    #[test]
+    fn characteristic_polynomial() {
+        let a: Matrix<f64> = matrix![1.0, 2.0;
+                                     3.0, 4.0];
+        let p = a.char_poly().unwrap();
+        assert_eq!(p.len(), 3);
+        assert!((p[0] - 1.0).abs() < 1e-14 && (p[1] + 5.0).abs() < 1e-14 && (p[2] + 2.0).abs() < 1e-14);
+
+        // diag(1, 2, 3) in disguise has (λ - 1)(λ - 2)(λ - 3) = λ³ - 6λ² + 11λ - 6
+        let s: Matrix<f64> = matrix![1.0, 1.0, 0.0;
+                                     0.0, 1.0, 1.0;
+                                     1.0, 0.0, 1.0];
+        let d: Matrix<f64> = matrix![1.0, 0.0, 0.0;
+                                     0.0, 2.0, 0.0;
+                                     0.0, 0.0, 3.0];
+        let a = &(&s * &d) * &s.inverse().unwrap();
+        let p = a.char_poly().unwrap();
+        for (c, expected) in p.iter().zip(&[1.0, -6.0, 11.0, -6.0]) {
+            assert!((c - expected).abs() < 1e-12);
+        }
+
+        let a: Matrix<f64> = Matrix::from_fn(5, 5, |r, c| ((r * 5 + c * 11) % 7) as f64 - 3.0);
+        let p = a.char_poly().unwrap();
+        assert!((p[1] + a.trace()).abs() < 1e-12);
+        assert!((p[5] + a.det()).abs() < 1e-9 * a.det().abs().max(1.0));
+        assert_eq!(Matrix::<f64>::zeroes(0, 0).char_poly().unwrap(), vec![1.0]);
+        assert_eq!(Matrix::<f64>::zeroes(2, 3).char_poly().unwrap_err(), MatrixError::NotSquare { rows: 2, columns: 3 });
+    }
+}
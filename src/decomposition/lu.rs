@@ -0,0 +1,227 @@
+use crate::{Matrix, MatrixError, PermutationMatrix, Real, Vector};
+
+use super::pivot_tolerance;
+
+/// The LU decomposition `P A = L U` of a square matrix, with partial pivoting.
+///
+/// `L` is unit lower triangular and `U` upper triangular. Both are stored packed into a single
+/// matrix, with the implicit ones on the diagonal of `L` left out. Singular matrices still have
+/// a decomposition, but `solve` and `inverse` fail on them.
+#[derive(Clone, Debug)]
+pub struct Lu<T: Default> {
+    packed: Matrix<T>,
+    // row i of P A is row perm[i] of A
+    perm: Vec<usize>,
+    // whether an odd number of row swaps was made
+    odd: bool,
+    singular: bool
+}
+
+impl<T: Real> Matrix<T> {
+    /// Computes the LU decomposition with partial pivoting. This fails only for non-square matrices.
+    pub fn lu(&self) -> Result<Lu<T>, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare { rows: self.rows, columns: self.columns });
+        }
+
+        let n = self.rows;
+        let tolerance = pivot_tolerance(self);
+        let mut a = self.clone();
+        let mut perm: Vec<usize> = (0..n).collect();
+        let mut odd = false;
+        let mut singular = false;
+
+        for k in 0..n {
+            let mut p = k;
+            for r in k + 1..n {
+                if a.contents[r * n + k].abs() > a.contents[p * n + k].abs() {
+                    p = r;
+                }
+            }
+            if p != k {
+                a.swap_rows(k, p);
+                perm.swap(k, p);
+                odd = !odd;
+            }
+
+            let pivot = a.contents[k * n + k];
+            if pivot.abs() <= tolerance || !pivot.is_finite() {
+                // nothing to eliminate with; the rest of the column stays as it is
+                singular = true;
+                continue;
+            }
+
+            for r in k + 1..n {
+                let factor = a.contents[r * n + k] / pivot;
+                a.contents[r * n + k] = factor;
+                for c in k + 1..n {
+                    a.contents[r * n + c] = a.contents[r * n + c] - factor * a.contents[k * n + c];
+                }
+            }
+        }
+
+        Ok(Lu { packed: a, perm, odd, singular })
+    }
+
+    /// The determinant, computed through the LU decomposition. This panics for non-square
+    /// matrices; see `try_det`.
+    pub fn det(&self) -> T {
+        self.try_det().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// The non-panicking version of `det`.
+    pub fn try_det(&self) -> Result<T, MatrixError> {
+        Ok(self.lu()?.det())
+    }
+}
+
+impl<T: Real> Lu<T> {
+    /// The unit lower triangular factor `L`.
+    pub fn l(&self) -> Matrix<T> {
+        let n = self.packed.rows;
+        Matrix::from_fn(n, n, |r, c| {
+            if r == c { T::one() } else if r > c { self.packed.contents[r * n + c] } else { T::zero() }
+        })
+    }
+
+    /// The upper triangular factor `U`.
+    pub fn u(&self) -> Matrix<T> {
+        self.packed.triu(0)
+    }
+
+    /// The permutation matrix `P`.
+    pub fn p(&self) -> PermutationMatrix {
+        PermutationMatrix::new(self.perm.clone())
+    }
+
+    /// The row permutation: row `i` of `P A` is row `permutation()[i]` of `A`.
+    pub fn permutation(&self) -> &[usize] {
+        &self.perm
+    }
+
+    /// Whether the decomposed matrix is (numerically) singular.
+    pub fn is_singular(&self) -> bool {
+        self.singular
+    }
+
+    /// The determinant of the decomposed matrix.
+    pub fn det(&self) -> T {
+        let product = self.packed.diagonal().into_iter().fold(T::one(), |acc, d| acc * d);
+        if self.odd { -product } else { product }
+    }
+
+    /// Solves `A x = b` for `x`.
+    pub fn solve(&self, b: &Vector<T>) -> Result<Vector<T>, MatrixError> {
+        let n = self.perm.len();
+        if b.len() != n {
+            return Err(MatrixError::DimensionMismatch { operation: "solving", left: (n, n), right: (b.len(), 1) });
+        }
+        if self.singular {
+            return Err(MatrixError::Singular);
+        }
+
+        let mut x: Vec<T> = self.perm.iter().map(|&i| b[i]).collect();
+        self.substitute(&mut x);
+        Ok(Vector::new(x))
+    }
+
+    /// Solves `A X = B` for `X`, one column of `B` at a time.
+    pub fn solve_matrix(&self, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        let n = self.perm.len();
+        if b.rows != n {
+            return Err(MatrixError::DimensionMismatch { operation: "solving", left: (n, n), right: (b.rows, b.columns) });
+        }
+        if self.singular {
+            return Err(MatrixError::Singular);
+        }
+
+        let mut x = Matrix::zeroes(n, b.columns);
+        for c in 0..b.columns {
+            let mut column: Vec<T> = self.perm.iter().map(|&i| b.contents[i * b.columns + c]).collect();
+            self.substitute(&mut column);
+            for (r, v) in column.into_iter().enumerate() {
+                x.contents[r * b.columns + c] = v;
+            }
+        }
+        Ok(x)
+    }
+
+    /// The inverse of the decomposed matrix.
+    pub fn inverse(&self) -> Result<Matrix<T>, MatrixError> {
+        self.solve_matrix(&Matrix::identity(self.perm.len()))
+    }
+
+    // forward substitution with L, then back substitution with U, in place
+    fn substitute(&self, x: &mut [T]) {
+        let n = self.perm.len();
+        let a = &self.packed.contents;
+        for r in 0..n {
+            for c in 0..r {
+                x[r] = x[r] - a[r * n + c] * x[c];
+            }
+        }
+        for r in (0..n).rev() {
+            for c in r + 1..n {
+                x[r] = x[r] - a[r * n + c] * x[c];
+            }
+            x[r] = x[r] / a[r * n + r];
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Matrix<f64> {
+        matrix![2.0, 1.0, 1.0;
+                4.0, -6.0, 0.0;
+                -2.0, 7.0, 2.0]
+    }
+
+    #[test]
+    fn factors_multiply_back() {
+        let a = example();
+        let lu = a.lu().unwrap();
+
+        assert!(lu.l().is_lower_triangular());
+        assert!(lu.u().is_upper_triangular());
+        assert!((&lu.p() * &a).approx_eq(&(&lu.l() * &lu.u()), 1e-12, 0.0));
+        assert_eq!(lu.permutation(), &[1, 0, 2]);
+    }
+
+    #[test]
+    fn determinants() {
+        assert!((example().det() - -16.0).abs() < 1e-12);
+        assert_eq!(Matrix::<f64>::identity(4).det(), 1.0);
+        assert_eq!(matrix![0.0, 1.0; 1.0, 0.0].det(), -1.0);
+        assert_eq!(matrix![1.0, 2.0; 2.0, 4.0].det(), 0.0);
+        assert!(Matrix::<f64>::zeroes(2, 3).try_det().is_err());
+    }
+
+    #[test]
+    fn reused_for_many_right_hand_sides() {
+        let a = example();
+        let lu = a.lu().unwrap();
+
+        for b in &[vec![5.0, -2.0, 9.0], vec![1.0, 0.0, 0.0]] {
+            let b = Vector::new(b.clone());
+            let x = lu.solve(&b).unwrap();
+            let ax = &a * &x;
+            assert!(ax.iter().zip(&b).all(|(p, q)| (p - q).abs() < 1e-12));
+        }
+
+        let inverse = lu.inverse().unwrap();
+        assert!((&a * &inverse).approx_eq(&Matrix::identity(3), 1e-12, 0.0));
+        assert!(inverse.approx_eq(&a.inverse().unwrap(), 1e-12, 0.0));
+    }
+
+    #[test]
+    fn singular_systems() {
+        let lu = matrix![1.0, 2.0; 2.0, 4.0].lu().unwrap();
+
+        assert!(lu.is_singular());
+        assert_eq!(lu.solve(&Vector::new(vec![1.0, 2.0])), Err(MatrixError::Singular));
+        assert_eq!(lu.inverse(), Err(MatrixError::Singular));
+    }
+}
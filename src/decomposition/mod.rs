@@ -0,0 +1,29 @@
+//! Matrix factorizations.
+//!
+//! Each factorization is computed once by a method on `Matrix` (`lu`, `qr`, `svd`, ...) and kept in its
+//! own struct, so that the expensive part can be reused, e.g. to solve many systems that
+//! share the same left hand side.
+
+mod cholesky;
+mod eigen;
+mod hessenberg;
+mod lu;
+mod qr;
+mod schur;
+mod svd;
+
+pub use cholesky::Cholesky;
+pub use eigen::SymmetricEigen;
+pub use lu::Lu;
+pub use qr::{PivotedQr, Qr};
+pub use schur::Schur;
+pub use svd::Svd;
+
+use crate::{Matrix, Real};
+
+/// Pivots at or below this size are treated as zero. It scales with the largest element, so
+/// that multiplying a matrix by a constant doesn't change whether it counts as singular.
+pub(crate) fn pivot_tolerance<T: Real>(m: &Matrix<T>) -> T {
+    let largest = m.contents.iter().fold(T::zero(), |acc, e| if e.abs() > acc { e.abs() } else { acc });
+    largest * T::epsilon() * T::from_f64(m.rows.max(m.columns) as f64)
+}
@@ -0,0 +1,369 @@
+use crate::{Matrix, MatrixError, PermutationMatrix, Real, Vector};
+
+use super::pivot_tolerance;
+
+/// The QR decomposition `A = Q R` of an m×n matrix, computed with Householder reflections.
+///
+/// `Q` is orthogonal and `R` upper triangular. `Q` is kept implicitly, as the Householder vectors
+/// stored below the diagonal of `R`, which takes no more memory than `A` itself. `apply_q` and
+/// `apply_qt` use it in that form; `q` and `thin_q` build it explicitly when it's needed.
+///
+/// With k = min(m, n), the full variant has an m×m `Q` and an m×n `R`, while the thin variant
+/// has an m×k `Q` and a k×n `R`.
+#[derive(Clone, Debug)]
+pub struct Qr<T: Default> {
+    // R on and above the diagonal, the Householder vectors (without their leading one) below it
+    packed: Matrix<T>,
+    // reflector k is I - tau[k] v vᵀ
+    tau: Vec<T>
+}
+
+/// The QR decomposition with column pivoting `A P = Q R`, which reveals the numerical rank.
+///
+/// At every step, the remaining column with the largest norm is moved to the front, which
+/// makes the diagonal of `R` decrease in magnitude. The rank is the number of diagonal elements
+/// that aren't negligible next to the first one, and the first `rank` columns of the
+/// permutation are a well-conditioned choice of independent columns of `A`.
+#[derive(Clone, Debug)]
+pub struct PivotedQr<T: Default> {
+    qr: Qr<T>,
+    // column j of A P is column perm[j] of A
+    perm: Vec<usize>,
+    rank: usize
+}
+
+impl<T: Real> Matrix<T> {
+    /// Computes the QR decomposition. This works for matrices of any shape.
+    pub fn qr(&self) -> Qr<T> {
+        self.householder_qr(false).0
+    }
+
+    /// Computes the QR decomposition with column pivoting. This works for matrices of any shape.
+    pub fn qr_pivoted(&self) -> PivotedQr<T> {
+        let (qr, perm) = self.householder_qr(true);
+        let n = self.columns;
+        let diagonal: Vec<T> = (0..qr.tau.len()).map(|i| qr.packed.contents[i * n + i].abs()).collect();
+        let tolerance = diagonal.first().map_or(T::zero(), |&d| d * T::epsilon() * T::from_f64(self.rows.max(n) as f64));
+        let rank = diagonal.iter().take_while(|&&d| d > tolerance).count();
+        PivotedQr { qr, perm, rank }
+    }
+
+    fn householder_qr(&self, pivoting: bool) -> (Qr<T>, Vec<usize>) {
+        let (m, n) = (self.rows, self.columns);
+        let mut a = self.clone();
+        let mut tau = Vec::with_capacity(m.min(n));
+        let mut perm: Vec<usize> = (0..n).collect();
+
+        for k in 0..m.min(n) {
+            if pivoting {
+                // the norms of what's left of every column; recomputed rather than downdated,
+                // which is slower but can't lose accuracy
+                let norm = |c: usize| (k..m).fold(T::zero(), |acc, r| acc.hypot(a.contents[r * n + c]));
+                let mut p = k;
+                let mut largest = norm(k);
+                for c in k + 1..n {
+                    let candidate = norm(c);
+                    if candidate > largest {
+                        p = c;
+                        largest = candidate;
+                    }
+                }
+                if p != k {
+                    a.swap_cols(k, p);
+                    perm.swap(k, p);
+                }
+            }
+
+            let alpha = a.contents[k * n + k];
+            let tail = (k + 1..m).fold(T::zero(), |acc, r| acc.hypot(a.contents[r * n + k]));
+            if tail.is_zero() {
+                // already zero below the diagonal, so the reflector is the identity
+                tau.push(T::zero());
+                continue;
+            }
+
+            let norm = alpha.hypot(tail);
+            let beta = if alpha > T::zero() { -norm } else { norm };
+            tau.push((beta - alpha) / beta);
+            let scale = alpha - beta;
+            for r in k + 1..m {
+                a.contents[r * n + k] = a.contents[r * n + k] / scale;
+            }
+            a.contents[k * n + k] = beta;
+
+            // apply the reflector to the remaining columns
+            for c in k + 1..n {
+                let mut w = a.contents[k * n + c];
+                for r in k + 1..m {
+                    w = w + a.contents[r * n + k] * a.contents[r * n + c];
+                }
+                w = w * tau[k];
+                a.contents[k * n + c] = a.contents[k * n + c] - w;
+                for r in k + 1..m {
+                    a.contents[r * n + c] = a.contents[r * n + c] - w * a.contents[r * n + k];
+                }
+            }
+        }
+
+        (Qr { packed: a, tau }, perm)
+    }
+}
+
+impl<T: Real> PivotedQr<T> {
+    /// The decomposition `A P = Q R` of the permuted matrix, with all of the usual factors.
+    pub fn qr(&self) -> &Qr<T> {
+        &self.qr
+    }
+
+    /// The column permutation: column `j` of `A P` is column `permutation()[j]` of `A`.
+    pub fn permutation(&self) -> &[usize] {
+        &self.perm
+    }
+
+    /// The n×n permutation matrix `P`.
+    pub fn p(&self) -> PermutationMatrix {
+        PermutationMatrix::new(self.perm.clone()).inverse()
+    }
+
+    /// The numerical rank of `A`.
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+
+    /// A least squares solution of `A x = b` that also works for rank-deficient `A` of any shape.
+    ///
+    /// This is the basic solution, which only uses the first `rank` columns of the permutation
+    /// and sets the unknowns of the others to zero. It minimises `|A x - b|` like any other
+    /// least squares solution, but it is generally not the one of smallest norm; see `lstsq`
+    /// for that.
+    pub fn solve(&self, b: &Vector<T>) -> Result<Vector<T>, MatrixError> {
+        let (m, n) = (self.qr.packed.rows, self.qr.packed.columns);
+        if b.len() != m {
+            return Err(MatrixError::DimensionMismatch { operation: "solving", left: (m, n), right: (b.len(), 1) });
+        }
+
+        let qtb = self.qr.apply_qt(&Matrix::from(b.clone()));
+        let r = |i: usize, j: usize| self.qr.packed.contents[i * n + j];
+        let mut y: Vec<T> = qtb.contents[..self.rank].to_vec();
+        for i in (0..self.rank).rev() {
+            for j in i + 1..self.rank {
+                y[i] = y[i] - r(i, j) * y[j];
+            }
+            y[i] = y[i] / r(i, i);
+        }
+
+        let mut x = Vector::zeroes(n);
+        for (i, yi) in y.into_iter().enumerate() {
+            x[self.perm[i]] = yi;
+        }
+        Ok(x)
+    }
+}
+
+impl<T: Real> Qr<T> {
+    /// The full m×m orthogonal factor.
+    pub fn q(&self) -> Matrix<T> {
+        let m = self.packed.rows;
+        self.apply_q(&Matrix::identity(m))
+    }
+
+    /// The thin m×k factor, whose columns are an orthonormal basis of the first k columns of `A`.
+    pub fn thin_q(&self) -> Matrix<T> {
+        let (m, k) = (self.packed.rows, self.tau.len());
+        self.apply_q(&Matrix::from_fn(m, k, |r, c| if r == c { T::one() } else { T::zero() }))
+    }
+
+    /// The full m×n upper triangular factor.
+    pub fn r(&self) -> Matrix<T> {
+        self.packed.triu(0)
+    }
+
+    /// The thin k×n upper triangular factor.
+    pub fn thin_r(&self) -> Matrix<T> {
+        let (n, k) = (self.packed.columns, self.tau.len());
+        Matrix::from_fn(k, n, |r, c| if c >= r { self.packed.contents[r * n + c] } else { T::zero() })
+    }
+
+    /// The Householder reflectors making up `Q = H_0 H_1 ... H_(k-1)`, each as a pair `(v, tau)`
+    /// with `H = I - tau v vᵀ`. Every `v` has length m and is zero above its own index.
+    pub fn householder_vectors(&self) -> Vec<(Vector<T>, T)> {
+        let (m, n) = (self.packed.rows, self.packed.columns);
+        self.tau.iter().enumerate().map(|(k, &tau)| {
+            let v = (0..m).map(|r| {
+                if r < k { T::zero() } else if r == k { T::one() } else { self.packed.contents[r * n + k] }
+            }).collect();
+            (v, tau)
+        }).collect()
+    }
+
+    /// Computes `Q B` without forming `Q`. `B` needs m rows.
+    pub fn apply_q(&self, b: &Matrix<T>) -> Matrix<T> {
+        let mut b = b.clone();
+        for k in (0..self.tau.len()).rev() {
+            self.reflect(k, &mut b);
+        }
+        b
+    }
+
+    /// Computes `Qᵀ B` without forming `Q`. `B` needs m rows.
+    pub fn apply_qt(&self, b: &Matrix<T>) -> Matrix<T> {
+        let mut b = b.clone();
+        for k in 0..self.tau.len() {
+            self.reflect(k, &mut b);
+        }
+        b
+    }
+
+    /// Solves `A x = b` in the least squares sense, i.e. finds the `x` minimising `|A x - b|`.
+    ///
+    /// This needs at least as many rows as columns and fails with `Singular` if the columns
+    /// of `A` are linearly dependent.
+    pub fn solve(&self, b: &Vector<T>) -> Result<Vector<T>, MatrixError> {
+        let (m, n) = (self.packed.rows, self.packed.columns);
+        if b.len() != m {
+            return Err(MatrixError::DimensionMismatch { operation: "solving", left: (m, n), right: (b.len(), 1) });
+        }
+        if m < n {
+            return Err(MatrixError::Underdetermined { rows: m, columns: n });
+        }
+
+        let tolerance = pivot_tolerance(&self.packed);
+        if (0..n).any(|i| self.packed.contents[i * n + i].abs() <= tolerance) {
+            return Err(MatrixError::Singular);
+        }
+
+        let qtb = self.apply_qt(&Matrix::from(b.clone()));
+        let mut x: Vec<T> = qtb.contents[..n].to_vec();
+        for r in (0..n).rev() {
+            for c in r + 1..n {
+                x[r] = x[r] - self.packed.contents[r * n + c] * x[c];
+            }
+            x[r] = x[r] / self.packed.contents[r * n + r];
+        }
+        Ok(Vector::new(x))
+    }
+
+    // applies reflector k to every column of b
+    fn reflect(&self, k: usize, b: &mut Matrix<T>) {
+        let (m, n) = (self.packed.rows, self.packed.columns);
+        assert!(b.rows == m, "Q has {} rows, so it can't be applied to a matrix with {} rows.", m, b.rows);
+        let tau = self.tau[k];
+        if tau.is_zero() {
+            return;
+        }
+
+        let v = |r: usize| if r == k { T::one() } else { self.packed.contents[r * n + k] };
+        for c in 0..b.columns {
+            let w = (k..m).fold(T::zero(), |acc, r| acc + v(r) * b.contents[r * b.columns + c]) * tau;
+            for r in k..m {
+                b.contents[r * b.columns + c] = b.contents[r * b.columns + c] - w * v(r);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tall() -> Matrix<f64> {
+        matrix![12.0, -51.0, 4.0;
+                6.0, 167.0, -68.0;
+                -4.0, 24.0, -41.0;
+                1.0, 2.0, 3.0]
+    }
+
+    #[test]
+    fn full_factors() {
+        let a = tall();
+        let qr = a.qr();
+        let (q, r) = (qr.q(), qr.r());
+
+        assert_eq!(q.shape(), (4, 4).into());
+        assert_eq!(r.shape(), (4, 3).into());
+        assert!(r.is_upper_triangular());
+        assert!((&q.transpose() * &q).approx_eq(&Matrix::identity(4), 1e-12, 0.0));
+        assert!((&q * &r).approx_eq(&a, 1e-10, 0.0));
+    }
+
+    #[test]
+    fn thin_factors() {
+        let a = tall();
+        let qr = a.qr();
+        let (q, r) = (qr.thin_q(), qr.thin_r());
+
+        assert_eq!(q.shape(), (4, 3).into());
+        assert_eq!(r.shape(), (3, 3).into());
+        assert!((&q.transpose() * &q).approx_eq(&Matrix::identity(3), 1e-12, 0.0));
+        assert!((&q * &r).approx_eq(&a, 1e-10, 0.0));
+
+        let wide = a.transpose();
+        let qr = wide.qr();
+        assert!((&qr.thin_q() * &qr.thin_r()).approx_eq(&wide, 1e-10, 0.0));
+    }
+
+    #[test]
+    fn implicit_q() {
+        let a = tall();
+        let qr = a.qr();
+        let b: Matrix<f64> = Matrix::from_fn(4, 2, |r, c| (r + c) as f64);
+
+        assert!(qr.apply_q(&b).approx_eq(&(&qr.q() * &b), 1e-12, 0.0));
+        assert!(qr.apply_qt(&b).approx_eq(&(&qr.q().transpose() * &b), 1e-12, 0.0));
+
+        let product = qr.householder_vectors().into_iter().fold(Matrix::identity(4), |q, (v, tau)| {
+            &q * &(Matrix::identity(4) - Matrix::outer(&v, &v) * tau)
+        });
+        assert!(product.approx_eq(&qr.q(), 1e-12, 0.0));
+    }
+
+    #[test]
+    fn least_squares() {
+        // fit y = c0 + c1 x through points that lie exactly on y = 1 + 2x
+        let a: Matrix<f64> = matrix![1.0, 0.0;
+                                     1.0, 1.0;
+                                     1.0, 2.0];
+        let b = Vector::new(vec![1.0, 3.0, 5.0]);
+
+        let x = a.qr().solve(&b).unwrap();
+        assert!((x[0] - 1.0).abs() < 1e-12 && (x[1] - 2.0).abs() < 1e-12);
+
+        let dependent: Matrix<f64> = matrix![1.0, 2.0; 2.0, 4.0; 3.0, 6.0];
+        assert_eq!(dependent.qr().solve(&b), Err(MatrixError::Singular));
+        assert_eq!(a.transpose().qr().solve(&Vector::new(vec![1.0, 2.0])), Err(MatrixError::Underdetermined { rows: 2, columns: 3 }));
+    }
+
+    #[test]
+    fn column_pivoting() {
+        let a = tall();
+        let pivoted = a.qr_pivoted();
+        let (q, r) = (pivoted.qr().q(), pivoted.qr().r());
+
+        assert_eq!(pivoted.rank(), 3);
+        // the middle column is by far the largest
+        assert_eq!(pivoted.permutation()[0], 1);
+        assert!((&q * &r).approx_eq(&(&a * &pivoted.p()), 1e-10, 0.0));
+        let diagonal: Vec<f64> = r.diagonal().iter().map(|d| d.abs()).collect();
+        assert!(diagonal.windows(2).all(|w| w[0] >= w[1]));
+    }
+
+    #[test]
+    fn rank_deficient_least_squares() {
+        // the third column is the sum of the first two
+        let a: Matrix<f64> = matrix![1.0, 0.0, 1.0;
+                                     0.0, 1.0, 1.0;
+                                     1.0, 1.0, 2.0;
+                                     2.0, 0.0, 2.0];
+        let b = Vector::new(vec![1.0, 2.0, 3.0, 2.0]);
+
+        let pivoted = a.qr_pivoted();
+        assert_eq!(pivoted.rank(), 2);
+        let x = pivoted.solve(&b).unwrap();
+        assert_eq!(x.iter().filter(|&&e| e == 0.0).count(), 1);
+        assert!(Matrix::from(a.apply(x.as_slice())).approx_eq(&Matrix::from(b), 1e-12, 0.0));
+
+        let wide = a.transpose();
+        assert_eq!(wide.qr_pivoted().rank(), 2);
+        assert_eq!(Matrix::<f64>::zeroes(3, 2).qr_pivoted().rank(), 0);
+    }
+}
@@ -0,0 +1,121 @@
+use super::hessenberg::hessenberg_qr;
+use crate::{Complex, Matrix, MatrixError, Real};
+
+/// The real Schur decomposition `A = Q T Qᵀ` of a square matrix.
+///
+/// `Q` is orthogonal, and `T` is quasi-upper-triangular: upper triangular except for 2×2 blocks
+/// on the diagonal, one for every pair of complex conjugate eigenvalues. The real eigenvalues
+/// sit on the diagonal of `T` itself.
+#[derive(Clone, Debug)]
+pub struct Schur<T: Default> {
+    q: Matrix<T>,
+    t: Matrix<T>,
+    eigenvalues: Vec<Complex<T>>
+}
+
+impl<T: Real> Matrix<T> {
+    /// Computes the real Schur decomposition by reducing to Hessenberg form and running the
+    /// Francis double-shift QR iteration on it, accumulating all transformations into `Q`.
+    /// Fails with `NoConvergence` in the rare case that an eigenvalue takes more than 30
+    /// iterations to split off.
+    pub fn schur(&self) -> Result<Schur<T>, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare { rows: self.rows, columns: self.columns });
+        }
+
+        let (mut t, q) = self.hessenberg(true);
+        let mut q = q.unwrap();
+        let eigenvalues = hessenberg_qr(&mut t, Some(&mut q))?;
+
+        // the iteration leaves rounding noise below the subdiagonal
+        for r in 2..t.rows {
+            for c in 0..r - 1 {
+                t[(r, c)] = T::zero();
+            }
+        }
+
+        Ok(Schur { q, t, eigenvalues })
+    }
+}
+
+impl<T: Real> Schur<T> {
+    /// The orthogonal matrix of Schur vectors.
+    pub fn q(&self) -> &Matrix<T> {
+        &self.q
+    }
+
+    /// The quasi-upper-triangular factor.
+    pub fn t(&self) -> &Matrix<T> {
+        &self.t
+    }
+
+    /// The eigenvalues, in the order in which they appear along the diagonal of `T`.
+    pub fn eigenvalues(&self) -> &[Complex<T>] {
+        &self.eigenvalues
+    }
+
+    /// Consumes the decomposition, returning `(Q, T)`.
+    pub fn into_parts(self) -> (Matrix<T>, Matrix<T>) {
+        (self.q, self.t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(a: &Matrix<f64>) -> Schur<f64> {
+        let n = a.rows;
+        let schur = a.schur().unwrap();
+        let (q, t) = (schur.q(), schur.t());
+
+        assert!((&q.transpose() * q).approx_eq(&Matrix::identity(n), 1e-12, 0.0));
+        assert!((&(q * t) * &q.transpose()).approx_eq(a, 1e-10, 0.0));
+        assert!(t.tril(-2).approx_eq(&Matrix::zeroes(n, n), 0.0, 0.0));
+        // no two consecutive nonzero subdiagonal entries, so the blocks are at most 2×2
+        for r in 2..n {
+            assert!(t[(r, r - 1)] == 0.0 || t[(r - 1, r - 2)] == 0.0);
+        }
+        schur
+    }
+
+    #[test]
+    fn real_eigenvalues() {
+        let a: Matrix<f64> = matrix![4.0, 1.0, -2.0, 2.0;
+                                     1.0, 2.0, 0.0, 1.0;
+                                     -2.0, 0.0, 3.0, -2.0;
+                                     2.0, 1.0, -2.0, -1.0];
+
+        let schur = check(&a);
+        let t = schur.t();
+        for r in 1..4 {
+            assert_eq!(t[(r, r - 1)], 0.0);
+        }
+        for (r, value) in schur.eigenvalues().iter().enumerate() {
+            assert!((t[(r, r)] - value.re).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn complex_pairs() {
+        let a: Matrix<f64> = matrix![0.0, -1.0, 0.0, 2.0, 1.0;
+                                     1.0, 0.0, 3.0, 0.0, -1.0;
+                                     0.0, 0.0, 2.0, -4.0, 0.5;
+                                     0.0, 1.0, 4.0, 2.0, 0.0;
+                                     1.0, 0.0, 0.0, 0.0, 3.0];
+
+        let schur = check(&a);
+        let t = schur.t();
+        assert!((1..5).any(|r| t[(r, r - 1)] != 0.0));
+        let trace: f64 = schur.eigenvalues().iter().map(|v| v.re).sum();
+        assert!((trace - a.trace()).abs() < 1e-10);
+
+        let b: Matrix<f64> = Matrix::from_fn(6, 6, |r, c| ((r * 5 + c * 3) % 7) as f64 - 3.0);
+        check(&b);
+    }
+
+    #[test]
+    fn not_square() {
+        assert_eq!(Matrix::<f64>::zeroes(2, 3).schur().unwrap_err(), MatrixError::NotSquare { rows: 2, columns: 3 });
+    }
+}
@@ -0,0 +1,304 @@
+use crate::{Matrix, MatrixError, Real};
+
+/// The singular value decomposition `A = U Σ Vᵀ` of an m×n matrix.
+///
+/// `U` and `V` have orthonormal columns and `Σ` is diagonal, holding the non-negative singular
+/// values in decreasing order. With k = min(m, n), the thin variant (from `svd`) has an m×k `U`
+/// and a k×n `Vᵀ`; the full variant (from `svd_full`) has a square m×m `U` and n×n `Vᵀ`, and an
+/// m×n `Σ` that is zero outside of its first k diagonal elements.
+///
+/// This uses one-sided Jacobi rotations, which are slower than the Golub–Kahan approach but
+/// simple and very accurate, even for tiny singular values.
+#[derive(Clone, Debug)]
+pub struct Svd<T: Default> {
+    u: Matrix<T>,
+    singular_values: Vec<T>,
+    vt: Matrix<T>
+}
+
+impl<T: Real> Matrix<T> {
+    /// Computes the thin singular value decomposition.
+    pub fn svd(&self) -> Svd<T> {
+        self.compute_svd(false)
+    }
+
+    /// Computes the full singular value decomposition, with square `U` and `Vᵀ`.
+    pub fn svd_full(&self) -> Svd<T> {
+        self.compute_svd(true)
+    }
+
+    /// Computes just the singular values, in decreasing order, which skips building `U` and `V`.
+    pub fn singular_values(&self) -> Vec<T> {
+        let columns = if self.rows >= self.columns { column_vectors(self) } else { column_vectors(&self.transpose()) };
+        let (_, mut sigma) = one_sided_jacobi(columns, None);
+        sigma.sort_by(|a, b| b.partial_cmp(a).unwrap_or(std::cmp::Ordering::Equal));
+        sigma
+    }
+
+    fn compute_svd(&self, full: bool) -> Svd<T> {
+        if self.rows < self.columns {
+            // A = (Aᵀ)ᵀ = (U Σ Vᵀ)ᵀ = V Σ Uᵀ
+            let Svd { u, singular_values, vt } = self.transpose().compute_svd(full);
+            return Svd { u: vt.transpose(), singular_values, vt: u.transpose() };
+        }
+
+        let (m, n) = (self.rows, self.columns);
+        let mut v: Vec<Vec<T>> = (0..n).map(|j| (0..n).map(|i| if i == j { T::one() } else { T::zero() }).collect()).collect();
+        let (columns, sigma) = one_sided_jacobi(column_vectors(self), Some(&mut v));
+
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&a, &b| sigma[b].partial_cmp(&sigma[a]).unwrap_or(std::cmp::Ordering::Equal));
+
+        let largest = order.first().map_or(T::zero(), |&j| sigma[j]);
+        let tolerance = largest * T::epsilon() * T::from_f64(m as f64);
+
+        // columns of U for the nonzero singular values; the others are filled in below
+        let mut u: Vec<Option<Vec<T>>> = order.iter().map(|&j| {
+            if sigma[j] > tolerance {
+                Some(columns[j].iter().map(|&x| x / sigma[j]).collect())
+            } else {
+                None
+            }
+        }).collect();
+        if full {
+            u.resize(m, None);
+        }
+        let u = complete_orthonormal(u, m);
+
+        let singular_values: Vec<T> = order.iter().map(|&j| sigma[j]).collect();
+        let vt = Matrix::from_fn(n, n, |r, c| v[order[r]][c]);
+        Svd { u: Matrix::from_fn(m, u.len(), |r, c| u[c][r]), singular_values, vt }
+    }
+}
+
+impl<T: Real> Svd<T> {
+    /// The left singular vectors, as columns.
+    pub fn u(&self) -> &Matrix<T> {
+        &self.u
+    }
+
+    /// The singular values, in decreasing order.
+    pub fn singular_values(&self) -> &[T] {
+        &self.singular_values
+    }
+
+    /// The right singular vectors, as rows.
+    pub fn vt(&self) -> &Matrix<T> {
+        &self.vt
+    }
+
+    /// `Σ` as a matrix, shaped so that `u() * sigma() * vt()` gives back `A`.
+    pub fn sigma(&self) -> Matrix<T> {
+        let mut sigma = Matrix::zeroes(self.u.columns, self.vt.rows);
+        for (i, &s) in self.singular_values.iter().enumerate() {
+            sigma.contents[i * sigma.columns + i] = s;
+        }
+        sigma
+    }
+
+    /// Solves `A X = B` in the least squares sense, giving the `X` of smallest norm among
+    /// those that minimise `|A X - B|`. Singular values at or below `tolerance` are treated as
+    /// zero, which is what keeps rank-deficient problems from blowing up.
+    pub fn solve(&self, b: &Matrix<T>, tolerance: T) -> Result<Matrix<T>, MatrixError> {
+        let (m, n) = (self.u.rows, self.vt.columns);
+        if b.rows != m {
+            return Err(MatrixError::DimensionMismatch { operation: "solving", left: (m, n), right: (b.rows, b.columns) });
+        }
+
+        // X = V Σ⁺ Uᵀ B, one singular triplet at a time
+        let mut x = Matrix::zeroes(n, b.columns);
+        for (i, &s) in self.singular_values.iter().enumerate() {
+            if s <= tolerance {
+                continue;
+            }
+            for c in 0..b.columns {
+                let coefficient = (0..m).fold(T::zero(), |acc, r| {
+                    acc + self.u.contents[r * self.u.columns + i] * b.contents[r * b.columns + c]
+                }) / s;
+                for r in 0..n {
+                    x.contents[r * b.columns + c] = x.contents[r * b.columns + c] + coefficient * self.vt.contents[i * n + r];
+                }
+            }
+        }
+        Ok(x)
+    }
+
+    /// The numerical rank: the number of singular values above the default tolerance.
+    pub fn rank(&self) -> usize {
+        let tolerance = self.default_tolerance();
+        self.singular_values.iter().filter(|&&s| s > tolerance).count()
+    }
+
+    // the usual cutoff below which singular values count as zero: the largest one, times the
+    // machine epsilon, times the larger dimension
+    pub(crate) fn default_tolerance(&self) -> T {
+        let largest = self.singular_values.first().copied().unwrap_or_else(T::zero);
+        largest * T::epsilon() * T::from_f64(self.u.rows.max(self.vt.columns) as f64)
+    }
+
+    /// The ratio of the largest to the smallest singular value, which is infinite for
+    /// rank-deficient matrices.
+    pub fn condition_number(&self) -> T {
+        match (self.singular_values.first(), self.singular_values.last()) {
+            (Some(&largest), Some(&smallest)) => largest / smallest,
+            _ => T::zero()
+        }
+    }
+}
+
+fn column_vectors<T: Real>(a: &Matrix<T>) -> Vec<Vec<T>> {
+    (0..a.columns).map(|c| (0..a.rows).map(|r| a.contents[r * a.columns + c]).collect()).collect()
+}
+
+fn dot<T: Real>(a: &[T], b: &[T]) -> T {
+    a.iter().zip(b).fold(T::zero(), |acc, (&x, &y)| acc + x * y)
+}
+
+// rotates pairs of columns until they are all orthogonal to each other, applying the same
+// rotations to the columns of `v` if given. Returns the rotated columns and their norms.
+fn one_sided_jacobi<T: Real>(mut columns: Vec<Vec<T>>, mut v: Option<&mut Vec<Vec<T>>>) -> (Vec<Vec<T>>, Vec<T>) {
+    const MAX_SWEEPS: usize = 60;
+    let n = columns.len();
+
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+        for p in 0..n {
+            for q in p + 1..n {
+                let alpha = dot(&columns[p], &columns[p]);
+                let beta = dot(&columns[q], &columns[q]);
+                let gamma = dot(&columns[p], &columns[q]);
+                if gamma.is_zero() || gamma.abs() <= T::epsilon() * (alpha * beta).sqrt() {
+                    continue;
+                }
+                rotated = true;
+
+                let zeta = (beta - alpha) / (gamma + gamma);
+                let sign = if zeta < T::zero() { -T::one() } else { T::one() };
+                let t = sign / (zeta.abs() + (T::one() + zeta * zeta).sqrt());
+                let c = T::one() / (T::one() + t * t).sqrt();
+                let s = c * t;
+
+                rotate(&mut columns, p, q, c, s);
+                if let Some(v) = v.as_mut() {
+                    rotate(v, p, q, c, s);
+                }
+            }
+        }
+        if !rotated {
+            break;
+        }
+    }
+
+    let norms = columns.iter().map(|col| dot(col, col).sqrt()).collect();
+    (columns, norms)
+}
+
+fn rotate<T: Real>(columns: &mut [Vec<T>], p: usize, q: usize, c: T, s: T) {
+    for i in 0..columns[p].len() {
+        let (x, y) = (columns[p][i], columns[q][i]);
+        columns[p][i] = c * x - s * y;
+        columns[q][i] = s * x + c * y;
+    }
+}
+
+// fills every missing column with a unit vector orthogonal to all the others, by
+// orthogonalising standard basis vectors against them
+pub(crate) fn complete_orthonormal<T: Real>(columns: Vec<Option<Vec<T>>>, m: usize) -> Vec<Vec<T>> {
+    let mut done: Vec<Vec<T>> = columns.iter().flatten().cloned().collect();
+    let mut candidates = 0..m;
+    let mut result = Vec::with_capacity(columns.len());
+
+    for column in columns {
+        match column {
+            Some(c) => result.push(c),
+            None => loop {
+                let i = candidates.next().expect("there are never more than m orthonormal columns of length m");
+                let mut x: Vec<T> = (0..m).map(|r| if r == i { T::one() } else { T::zero() }).collect();
+                // twice is enough for orthogonality to hold up to rounding
+                for _ in 0..2 {
+                    for d in &done {
+                        let k = dot(&x, d);
+                        x.iter_mut().zip(d).for_each(|(a, &b)| *a = *a - k * b);
+                    }
+                }
+                let norm = dot(&x, &x).sqrt();
+                if norm > T::from_f64(0.5) {
+                    let x: Vec<T> = x.into_iter().map(|a| a / norm).collect();
+                    done.push(x.clone());
+                    result.push(x);
+                    break;
+                }
+            }
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reconstruct(svd: &Svd<f64>) -> Matrix<f64> {
+        &(svd.u() * &svd.sigma()) * svd.vt()
+    }
+
+    fn is_orthonormal(columns: &Matrix<f64>) -> bool {
+        (&columns.transpose() * columns).approx_eq(&Matrix::identity(columns.shape().cols), 1e-12, 0.0)
+    }
+
+    #[test]
+    fn thin() {
+        let a: Matrix<f64> = matrix![3.0, 2.0, 2.0;
+                                     2.0, 3.0, -2.0];
+
+        let svd = a.svd();
+        assert_eq!(svd.u().shape(), (2, 2).into());
+        assert_eq!(svd.vt().shape(), (2, 3).into());
+        assert!((svd.singular_values()[0] - 5.0).abs() < 1e-12);
+        assert!((svd.singular_values()[1] - 3.0).abs() < 1e-12);
+        assert!(reconstruct(&svd).approx_eq(&a, 1e-12, 0.0));
+        assert!(is_orthonormal(svd.u()) && is_orthonormal(&svd.vt().transpose()));
+    }
+
+    #[test]
+    fn full() {
+        let a: Matrix<f64> = matrix![1.0, 2.0;
+                                     3.0, 4.0;
+                                     5.0, 6.0;
+                                     7.0, 8.0];
+
+        let svd = a.svd_full();
+        assert_eq!(svd.u().shape(), (4, 4).into());
+        assert_eq!(svd.sigma().shape(), (4, 2).into());
+        assert_eq!(svd.vt().shape(), (2, 2).into());
+        assert!(reconstruct(&svd).approx_eq(&a, 1e-12, 0.0));
+        assert!(is_orthonormal(svd.u()));
+
+        let wide = a.transpose().svd_full();
+        assert_eq!(wide.vt().shape(), (4, 4).into());
+        assert!(reconstruct(&wide).approx_eq(&a.transpose(), 1e-12, 0.0));
+    }
+
+    #[test]
+    fn rank_deficient() {
+        let a: Matrix<f64> = matrix![1.0, 2.0, 3.0;
+                                     2.0, 4.0, 6.0;
+                                     1.0, 1.0, 1.0];
+
+        let svd = a.svd();
+        assert!(svd.singular_values()[2] < 1e-12);
+        assert!(svd.condition_number() > 1e12);
+        assert!(is_orthonormal(svd.u()));
+        assert!(reconstruct(&svd).approx_eq(&a, 1e-12, 0.0));
+    }
+
+    #[test]
+    fn values_only() {
+        let a: Matrix<f64> = Matrix::hilbert(4);
+
+        let values = a.singular_values();
+        let full = a.svd();
+        assert!(values.iter().zip(full.singular_values()).all(|(x, y)| (x - y).abs() < 1e-14));
+        assert!(values.windows(2).all(|w| w[0] >= w[1]));
+    }
+}
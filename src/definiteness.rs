@@ -0,0 +1,158 @@
+//! Positive (and negative) definiteness of the quadratic form `xᵀ A x`.
+//!
+//! The form only depends on the symmetric part `(A + Aᵀ) / 2`, so that is what gets
+//! classified; for a symmetric matrix it's the matrix itself. Everything here is decided by
+//! the same pivoted Cholesky decomposition, so the checks can't contradict each other.
+
+use crate::{Matrix, MatrixError, Real};
+
+/// The sign behaviour of `xᵀ A x` over all nonzero `x`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Definiteness {
+    /// Always positive; all eigenvalues are positive.
+    PositiveDefinite,
+    /// Never negative, but zero for some `x`; the zero matrix is counted here.
+    PositiveSemidefinite,
+    /// Always negative.
+    NegativeDefinite,
+    /// Never positive, but zero for some `x`.
+    NegativeSemidefinite,
+    /// Positive for some `x` and negative for others.
+    Indefinite
+}
+
+impl<T: Real> Matrix<T> {
+    /// Whether `xᵀ A x > 0` for every nonzero `x`. Non-square matrices are never positive
+    /// definite.
+    ///
+    /// Use this to validate e.g. a covariance matrix before sampling from or inverting it.
+    /// Like the other checks here, it's decided by a Cholesky decomposition with diagonal
+    /// pivoting, in which a pivot within `n ε` times the largest element in magnitude counts
+    /// as zero. So a matrix that is singular up to rounding is semidefinite, not definite.
+    pub fn is_positive_definite(&self) -> bool {
+        self.definiteness() == Ok(Definiteness::PositiveDefinite)
+    }
+
+    /// Whether `xᵀ A x >= 0` for every `x`, up to the tolerance of `is_positive_definite`.
+    /// Non-square matrices are never positive semidefinite.
+    pub fn is_positive_semidefinite(&self) -> bool {
+        match self.definiteness() {
+            Ok(d) => d == Definiteness::PositiveDefinite || d == Definiteness::PositiveSemidefinite,
+            Err(_) => false
+        }
+    }
+
+    /// Classifies the matrix (its symmetric part, that is) by attempting a pivoted Cholesky
+    /// decomposition of it and, if that fails, of its negation, with the tolerance described at
+    /// `is_positive_definite`. Fails with `NotSquare`, and only then.
+    pub fn definiteness(&self) -> Result<Definiteness, MatrixError> {
+        let s = self.try_symmetrize()?;
+        let n = s.rows;
+
+        Ok(match semidefinite_rank(s.clone()) {
+            Some(rank) if rank == n => Definiteness::PositiveDefinite,
+            Some(_) => Definiteness::PositiveSemidefinite,
+            None => match semidefinite_rank(s.map(|&e| -e)) {
+                Some(rank) if rank == n => Definiteness::NegativeDefinite,
+                Some(_) => Definiteness::NegativeSemidefinite,
+                None => Definiteness::Indefinite
+            }
+        })
+    }
+}
+
+// The rank of a symmetric positive semidefinite matrix, found by Cholesky with diagonal
+// pivoting, or None if the matrix isn't positive semidefinite. Once the largest remaining
+// pivot is negligible, everything left of the Schur complement has to be negligible too.
+fn semidefinite_rank<T: Real>(mut s: Matrix<T>) -> Option<usize> {
+    let n = s.rows;
+    let largest = s.contents.iter().fold(T::zero(), |acc, &e| if e.abs() > acc { e.abs() } else { acc });
+    let tolerance = T::from_f64(n as f64) * T::epsilon() * largest;
+
+    for k in 0..n {
+        let p = (k..n).fold(k, |p, i| if s.contents[i * n + i] > s.contents[p * n + p] { i } else { p });
+        if s.contents[p * n + p] <= tolerance {
+            let rest_negligible = (k..n).all(|i| (k..n).all(|j| s.contents[i * n + j].abs() <= tolerance));
+            return if rest_negligible { Some(k) } else { None };
+        }
+        s.swap_rows(k, p);
+        s.swap_cols(k, p);
+
+        let d = s.contents[k * n + k].sqrt();
+        for i in k + 1..n {
+            s.contents[i * n + k] = s.contents[i * n + k] / d;
+        }
+        for i in k + 1..n {
+            for j in k + 1..n {
+                s.contents[i * n + j] = s.contents[i * n + j] - s.contents[i * n + k] * s.contents[j * n + k];
+            }
+        }
+    }
+    Some(n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classification() {
+        let covariance: Matrix<f64> = matrix![4.0, 2.0, 0.6;
+                                              2.0, 2.0, 0.4;
+                                              0.6, 0.4, 1.0];
+        assert!(covariance.is_positive_definite());
+        assert_eq!(covariance.definiteness(), Ok(Definiteness::PositiveDefinite));
+        assert_eq!(covariance.map(|&e| -e).definiteness(), Ok(Definiteness::NegativeDefinite));
+
+        // the Gram matrix of dependent vectors is singular
+        let gram: Matrix<f64> = matrix![1.0, 2.0; 2.0, 4.0; 3.0, 6.0].gram();
+        assert!(!gram.is_positive_definite());
+        assert!(gram.is_positive_semidefinite());
+        assert_eq!(gram.map(|&e| -e).definiteness(), Ok(Definiteness::NegativeSemidefinite));
+
+        let saddle: Matrix<f64> = matrix![1.0, 0.0; 0.0, -1.0];
+        assert_eq!(saddle.definiteness(), Ok(Definiteness::Indefinite));
+        assert!(!saddle.is_positive_semidefinite());
+        assert_eq!(Matrix::<f64>::zeroes(2, 2).definiteness(), Ok(Definiteness::PositiveSemidefinite));
+    }
+
+    #[test]
+    fn non_symmetric() {
+        // xᵀ A x = x₀² + x₁², the skew-symmetric part doesn't contribute
+        let a: Matrix<f64> = matrix![1.0, 5.0;
+                                     -5.0, 1.0];
+        assert!(a.is_positive_definite());
+        assert_eq!(a.definiteness(), Ok(Definiteness::PositiveDefinite));
+
+        let wide: Matrix<f64> = Matrix::ones(2, 3);
+        assert!(!wide.is_positive_definite() && !wide.is_positive_semidefinite());
+        assert_eq!(wide.definiteness(), Err(MatrixError::NotSquare { rows: 2, columns: 3 }));
+    }
+
+    #[test]
+    fn larger_matrices() {
+        let n = 30;
+        let b: Matrix<f64> = Matrix::from_fn(20, n, |r, c| (r as f64 * 12.9898 + c as f64 * 78.233).sin() * 43758.5453 % 1.0);
+
+        // a dense covariance-like matrix, and a singular one of rank 20
+        let mut spd = b.gram();
+        for i in 0..n {
+            spd[(i, i)] += 1.0;
+        }
+        assert!(spd.is_positive_definite() && spd.is_positive_semidefinite());
+        assert_eq!(spd.definiteness(), Ok(Definiteness::PositiveDefinite));
+
+        let singular = b.gram();
+        assert!(!singular.is_positive_definite() && singular.is_positive_semidefinite());
+        assert_eq!(singular.definiteness(), Ok(Definiteness::PositiveSemidefinite));
+        assert_eq!(singular.map(|&e| -e).definiteness(), Ok(Definiteness::NegativeSemidefinite));
+
+        // the eigenvalues agree
+        let smallest = singular.symmetric_eigen().unwrap().eigenvalues()[0];
+        assert!(smallest.abs() < 1e-10);
+
+        let mut indefinite = spd.clone();
+        indefinite[(n - 1, n - 1)] = -1.0;
+        assert_eq!(indefinite.definiteness(), Ok(Definiteness::Indefinite));
+    }
+}
@@ -0,0 +1,114 @@
+//! Diagonals and triangles.
+
+use crate::{Matrix, MatrixError, Zero};
+
+impl<T: Default + Clone> Matrix<T> {
+    /// Returns the main diagonal, from the top left corner down. For non-square matrices this
+    /// stops at whichever edge it hits first.
+    pub fn diagonal(&self) -> Vec<T> {
+        (0..self.rows.min(self.columns)).map(|i| self.contents[i * self.columns + i].clone()).collect()
+    }
+
+    /// Returns the anti-diagonal, from the top right corner down and to the left.
+    pub fn anti_diagonal(&self) -> Vec<T> {
+        (0..self.rows.min(self.columns)).map(|i| self.contents[i * self.columns + self.columns - 1 - i].clone()).collect()
+    }
+
+    /// Overwrites the main diagonal. `values` needs to be exactly as long as the diagonal.
+    pub fn set_diagonal(&mut self, values: &[T]) {
+        let length = self.rows.min(self.columns);
+        assert!(values.len() == length,
+            "Got {} values for a diagonal of length {}.", values.len(), length);
+
+        for (i, v) in values.iter().enumerate() {
+            self.contents[i * self.columns + i] = v.clone();
+        }
+    }
+}
+
+impl<T: Default + Clone + Zero> Matrix<T> {
+    /// Returns a copy with everything below the `k`-th diagonal set to zero.
+    ///
+    /// `k = 0` is the main diagonal, positive `k` are above it and negative `k` below it,
+    /// so `triu(1)` keeps only what is strictly above the main diagonal.
+    pub fn triu(&self, k: isize) -> Matrix<T> {
+        self.keep_where(|r, c| c as isize - r as isize >= k)
+    }
+
+    /// Returns a copy with everything above the `k`-th diagonal set to zero. See `triu`.
+    pub fn tril(&self, k: isize) -> Matrix<T> {
+        self.keep_where(|r, c| c as isize - r as isize <= k)
+    }
+
+    /// The sum of the main diagonal. This panics for non-square matrices; see `try_trace`.
+    pub fn trace(&self) -> T {
+        self.try_trace().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// The non-panicking version of `trace`.
+    pub fn try_trace(&self) -> Result<T, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare { rows: self.rows, columns: self.columns });
+        }
+
+        Ok(self.diagonal().into_iter().fold(T::zero(), |acc, e| acc + e))
+    }
+
+    fn keep_where<F: Fn(usize, usize) -> bool>(&self, keep: F) -> Matrix<T> {
+        Matrix::from_fn(self.rows, self.columns, |r, c| {
+            if keep(r, c) { self.contents[r * self.columns + c].clone() } else { T::zero() }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counting(rows: usize, columns: usize) -> Matrix<i32> {
+        Matrix::from_fn(rows, columns, |r, c| (r * columns + c + 1) as i32)
+    }
+
+    #[test]
+    fn diagonals() {
+        let m = counting(3, 4);
+
+        assert_eq!(m.diagonal(), vec![1, 6, 11]);
+        assert_eq!(m.anti_diagonal(), vec![4, 7, 10]);
+        assert_eq!(counting(4, 2).diagonal(), vec![1, 4]);
+    }
+
+    #[test]
+    fn setting_the_diagonal() {
+        let mut m: Matrix<i32> = Matrix::zeroes(2, 3);
+        m.set_diagonal(&[7, 8]);
+
+        assert_eq!(m.contents, vec![7, 0, 0, 0, 8, 0]);
+    }
+
+    #[test]
+    fn triangles() {
+        let m = counting(3, 3);
+
+        assert_eq!(m.triu(0).contents, vec![1, 2, 3, 0, 5, 6, 0, 0, 9]);
+        assert_eq!(m.triu(1).contents, vec![0, 2, 3, 0, 0, 6, 0, 0, 0]);
+        assert_eq!(m.tril(0).contents, vec![1, 0, 0, 4, 5, 0, 7, 8, 9]);
+        assert_eq!(m.tril(-1).contents, vec![0, 0, 0, 4, 0, 0, 7, 8, 0]);
+        assert!(m.triu(0).is_upper_triangular());
+    }
+
+    #[test]
+    fn traces() {
+        assert_eq!(counting(3, 3).trace(), 15);
+        assert_eq!(Matrix::<f64>::identity(4).trace(), 4.0);
+        assert_eq!(Matrix::<i32>::zeroes(0, 0).trace(), 0);
+        assert_eq!(counting(2, 3).try_trace(), Err(MatrixError::NotSquare { rows: 2, columns: 3 }));
+    }
+
+    #[test]
+    #[should_panic(expected = "Got 3 values for a diagonal of length 2")]
+    fn wrong_diagonal_length() {
+        let mut m: Matrix<i32> = Matrix::zeroes(2, 2);
+        m.set_diagonal(&[1, 2, 3]);
+    }
+}
@@ -0,0 +1,208 @@
+//! Gaussian elimination down to (reduced) row echelon form.
+
+use crate::decomposition::pivot_tolerance;
+use crate::{Field, Matrix, MatrixError, Real};
+
+impl<T: Real> Matrix<T> {
+    /// Brings the matrix into row echelon form by Gaussian elimination with partial pivoting,
+    /// and returns it together with the pivot columns.
+    ///
+    /// Every row starts with more zeroes than the one above it, and the rank is the number of
+    /// pivots. Elements that are negligible next to the largest one are taken to be zero, so
+    /// that rounding errors don't turn up as spurious pivots.
+    pub fn row_echelon(&self) -> (Matrix<T>, Vec<usize>) {
+        self.eliminate(false)
+    }
+
+    /// Brings the matrix into reduced row echelon form, where additionally every pivot is one
+    /// and the only nonzero element in its column. Returns it together with the pivot columns.
+    ///
+    /// The reduced form is unique, and the non-pivot columns are the free variables of the
+    /// homogeneous system `A x = 0`.
+    pub fn rref(&self) -> (Matrix<T>, Vec<usize>) {
+        self.eliminate(true)
+    }
+
+    fn eliminate(&self, reduced: bool) -> (Matrix<T>, Vec<usize>) {
+        let (rows, columns) = (self.rows, self.columns);
+        let tolerance = pivot_tolerance(self);
+        let mut a = self.clone();
+        let mut pivots = Vec::new();
+
+        for c in 0..columns {
+            let r = pivots.len();
+            if r == rows {
+                break;
+            }
+
+            let mut p = r;
+            for i in r + 1..rows {
+                if a.contents[i * columns + c].abs() > a.contents[p * columns + c].abs() {
+                    p = i;
+                }
+            }
+            if a.contents[p * columns + c].abs() <= tolerance {
+                for i in r..rows {
+                    a.contents[i * columns + c] = T::zero();
+                }
+                continue;
+            }
+            a.swap_rows(r, p);
+
+            if reduced {
+                let pivot = a.contents[r * columns + c];
+                for j in c..columns {
+                    a.contents[r * columns + j] = a.contents[r * columns + j] / pivot;
+                }
+            }
+
+            let pivot = a.contents[r * columns + c];
+            let start = if reduced { 0 } else { r + 1 };
+            for i in (start..rows).filter(|&i| i != r) {
+                let factor = a.contents[i * columns + c] / pivot;
+                for j in c + 1..columns {
+                    a.contents[i * columns + j] = a.contents[i * columns + j] - factor * a.contents[r * columns + j];
+                }
+                a.contents[i * columns + c] = T::zero();
+            }
+            pivots.push(c);
+        }
+
+        (a, pivots)
+    }
+}
+
+impl<T: Field> Matrix<T> {
+    /// Brings the matrix into reduced row echelon form by exact Gauss–Jordan elimination, and
+    /// returns it together with the pivot columns. Unlike `rref`, there is no tolerance: the
+    /// first nonzero element of a column is taken as its pivot.
+    pub fn rref_exact(&self) -> (Matrix<T>, Vec<usize>) {
+        let (rows, columns) = (self.rows, self.columns);
+        let mut a = self.clone();
+        let mut pivots = Vec::new();
+
+        for c in 0..columns {
+            let r = pivots.len();
+            if r == rows {
+                break;
+            }
+
+            let p = match (r..rows).find(|&i| !a.contents[i * columns + c].is_zero()) {
+                Some(p) => p,
+                None => continue
+            };
+            a.swap_rows(r, p);
+
+            let pivot = a.contents[r * columns + c];
+            for j in c..columns {
+                a.contents[r * columns + j] = a.contents[r * columns + j] / pivot;
+            }
+
+            for i in (0..rows).filter(|&i| i != r) {
+                let factor = a.contents[i * columns + c];
+                if factor.is_zero() {
+                    continue;
+                }
+                for j in c..columns {
+                    a.contents[i * columns + j] = a.contents[i * columns + j] - factor * a.contents[r * columns + j];
+                }
+            }
+            pivots.push(c);
+        }
+
+        (a, pivots)
+    }
+
+    /// The exact determinant, by Gaussian elimination without rounding. This panics for
+    /// non-square matrices.
+    pub fn det_exact(&self) -> T {
+        if !self.is_square() {
+            panic!("{}", MatrixError::NotSquare { rows: self.rows, columns: self.columns });
+        }
+
+        let n = self.rows;
+        let mut a = self.clone();
+        let mut det = T::one();
+        for c in 0..n {
+            let p = match (c..n).find(|&i| !a.contents[i * n + c].is_zero()) {
+                Some(p) => p,
+                None => return T::zero()
+            };
+            if p != c {
+                a.swap_rows(c, p);
+                det = -det;
+            }
+
+            let pivot = a.contents[c * n + c];
+            det = det * pivot;
+            for i in c + 1..n {
+                let factor = a.contents[i * n + c] / pivot;
+                for j in c + 1..n {
+                    a.contents[i * n + j] = a.contents[i * n + j] - factor * a.contents[c * n + j];
+                }
+            }
+        }
+        det
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Rational64, Ratio, Zero};
+
+    #[test]
+    fn echelon_form() {
+        let a: Matrix<f64> = matrix![1.0, 2.0, 1.0;
+                                     3.0, 8.0, 1.0;
+                                     0.0, 4.0, 1.0];
+
+        let (e, pivots) = a.row_echelon();
+        assert_eq!(pivots, vec![0, 1, 2]);
+        assert!(e.tril(-1).approx_eq(&Matrix::zeroes(3, 3), 0.0, 0.0));
+        // partial pivoting brings the 3 up first
+        assert_eq!(e.row(0), matrix![3.0, 8.0, 1.0]);
+        assert!((e.diagonal().into_iter().product::<f64>().abs() - a.det().abs()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn reduced_form() {
+        let a: Matrix<f64> = matrix![1.0, 2.0, -1.0, -4.0;
+                                     2.0, 3.0, -1.0, -11.0;
+                                     -2.0, 0.0, -3.0, 22.0];
+
+        let (r, pivots) = a.rref();
+        assert_eq!(pivots, vec![0, 1, 2]);
+        assert!(r.approx_eq(&matrix![1.0, 0.0, 0.0, -8.0;
+                                     0.0, 1.0, 0.0, 1.0;
+                                     0.0, 0.0, 1.0, -2.0], 1e-12, 0.0));
+    }
+
+    #[test]
+    fn rank_deficient() {
+        let a: Matrix<f64> = matrix![1.0, 2.0, 3.0;
+                                     2.0, 4.0, 6.0;
+                                     1.0, 1.0, 1.0];
+
+        let (r, pivots) = a.rref();
+        assert_eq!(pivots, vec![0, 1]);
+        assert!(r.approx_eq(&matrix![1.0, 0.0, -1.0;
+                                     0.0, 1.0, 2.0;
+                                     0.0, 0.0, 0.0], 1e-12, 0.0));
+        assert_eq!(a.row_echelon().1, vec![0, 1]);
+        assert_eq!(Matrix::<f64>::zeroes(2, 3).rref(), (Matrix::zeroes(2, 3), vec![]));
+    }
+
+    #[test]
+    fn exact_reduced_form() {
+        let a: Matrix<Rational64> = matrix![1, 2, 3, 1;
+                                            2, 4, 7, 0;
+                                            3, 6, 10, 2].map(|&e| Ratio::from(e));
+
+        let (r, pivots) = a.rref_exact();
+        assert_eq!(pivots, vec![0, 2, 3]);
+        assert_eq!(r.row(0), matrix![1, 2, 0, 0].map(|&e| Ratio::from(e)));
+        assert_eq!(r[(1, 3)], Ratio::zero());
+        assert_eq!(Matrix::<Rational64>::zeroes(2, 3).rref_exact(), (Matrix::zeroes(2, 3), vec![]));
+    }
+}
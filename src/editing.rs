@@ -0,0 +1,153 @@
+//! Inserting and removing whole rows and columns.
+
+use crate::Matrix;
+
+impl<T: Default> Matrix<T> {
+    /// Inserts `row` so that it becomes row `i`, shifting everything below it down.
+    ///
+    /// The row has to be exactly as long as the matrix is wide. The one exception is a
+    /// 0 by 0 matrix, which takes on the width of the first row inserted into it.
+    pub fn insert_row(&mut self, i: usize, row: Vec<T>) {
+        assert!(i <= self.rows, "Can't insert a row at {} in a matrix with {} rows.", i, self.rows);
+        if self.rows == 0 && self.columns == 0 {
+            self.columns = row.len();
+        }
+        assert!(row.len() == self.columns,
+            "Can't insert a row of length {} into a matrix with {} columns.", row.len(), self.columns);
+
+        let at = i * self.columns;
+        self.contents.splice(at..at, row);
+        self.rows += 1;
+    }
+
+    /// Inserts `column` so that it becomes column `j`, shifting everything right of it over.
+    ///
+    /// The column has to be exactly as long as the matrix is tall. The one exception is a
+    /// 0 by 0 matrix, which takes on the height of the first column inserted into it.
+    pub fn insert_column(&mut self, j: usize, column: Vec<T>) {
+        assert!(j <= self.columns, "Can't insert a column at {} in a matrix with {} columns.", j, self.columns);
+        if self.rows == 0 && self.columns == 0 {
+            self.rows = column.len();
+        }
+        assert!(column.len() == self.rows,
+            "Can't insert a column of length {} into a matrix with {} rows.", column.len(), self.rows);
+
+        let old = std::mem::take(&mut self.contents);
+        let mut old = old.into_iter();
+        let mut contents: Vec<T> = Vec::with_capacity((self.columns + 1) * self.rows);
+        for e in column {
+            contents.extend(old.by_ref().take(j));
+            contents.push(e);
+            contents.extend(old.by_ref().take(self.columns - j));
+        }
+
+        self.columns += 1;
+        self.contents = contents;
+    }
+
+    /// Appends `row` below the last row. See `insert_row`.
+    pub fn push_row(&mut self, row: Vec<T>) {
+        self.insert_row(self.rows, row);
+    }
+
+    /// Appends `column` right of the last column. See `insert_column`.
+    pub fn push_col(&mut self, column: Vec<T>) {
+        self.insert_column(self.columns, column);
+    }
+
+    /// Removes row `i` and returns its elements, shifting everything below it up.
+    pub fn remove_row(&mut self, i: usize) -> Vec<T> {
+        assert!(i < self.rows, "Row {} doesn't exist in a matrix with {} rows.", i, self.rows);
+
+        self.rows -= 1;
+        self.contents.drain(i * self.columns..(i + 1) * self.columns).collect()
+    }
+
+    /// Removes column `j` and returns its elements, shifting everything right of it over.
+    pub fn remove_column(&mut self, j: usize) -> Vec<T> {
+        assert!(j < self.columns, "Column {} doesn't exist in a matrix with {} columns.", j, self.columns);
+
+        let columns = self.columns;
+        let mut removed: Vec<T> = Vec::with_capacity(self.rows);
+        let mut kept: Vec<T> = Vec::with_capacity(self.rows * (columns - 1));
+        for (i, e) in std::mem::take(&mut self.contents).into_iter().enumerate() {
+            if i % columns == j {
+                removed.push(e);
+            } else {
+                kept.push(e);
+            }
+        }
+
+        self.columns -= 1;
+        self.contents = kept;
+        removed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_rows() {
+        let mut m: Matrix<i32> = matrix![1, 2;
+                                         5, 6];
+
+        m.insert_row(1, vec![3, 4]);
+        m.insert_row(3, vec![7, 8]);
+        assert_eq!(m.shape(), (4, 2).into());
+        assert_eq!(m.contents, vec![1, 2, 3, 4, 5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn insert_columns() {
+        let mut m: Matrix<i32> = matrix![1, 3;
+                                         4, 6];
+
+        m.insert_column(1, vec![2, 5]);
+        m.insert_column(0, vec![0, 0]);
+        assert_eq!(m.shape(), (2, 4).into());
+        assert_eq!(m.contents, vec![0, 1, 2, 3, 0, 4, 5, 6]);
+    }
+
+    #[test]
+    fn remove_rows_and_columns() {
+        let mut m: Matrix<i32> = matrix![1, 2, 3;
+                                         4, 5, 6;
+                                         7, 8, 9];
+
+        assert_eq!(m.remove_row(1), vec![4, 5, 6]);
+        assert_eq!(m.remove_column(0), vec![1, 7]);
+        assert_eq!(m.shape(), (2, 2).into());
+        assert_eq!(m.contents, vec![2, 3, 8, 9]);
+    }
+
+    #[test]
+    fn grow_from_empty() {
+        let mut rows: Matrix<i32> = Matrix::new(0, 0, vec![]);
+        rows.insert_row(0, vec![1, 2, 3]);
+        assert_eq!(rows.shape(), (1, 3).into());
+
+        let mut columns: Matrix<i32> = Matrix::new(0, 0, vec![]);
+        columns.insert_column(0, vec![1, 2]);
+        assert_eq!(columns.shape(), (2, 1).into());
+    }
+
+    #[test]
+    fn push_rows_and_columns() {
+        let mut m: Matrix<i32> = Matrix::new(0, 0, vec![]);
+        m.push_row(vec![1, 2]);
+        m.push_row(vec![4, 5]);
+        m.push_col(vec![3, 6]);
+
+        assert_eq!(m.shape(), (2, 3).into());
+        assert_eq!(m.contents, vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Can't insert a row of length 3 into a matrix with 2 columns")]
+    fn insert_wrong_length() {
+        let mut m: Matrix<i32> = Matrix::zeroes(2, 2);
+        m.insert_row(0, vec![1, 2, 3]);
+    }
+}
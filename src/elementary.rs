@@ -0,0 +1,236 @@
+//! Householder reflections and Givens rotations, the orthogonal building blocks of QR,
+//! Hessenberg reduction, bidiagonalization and their structured variants.
+//!
+//! Both can be applied in place, from either side, to anything that is indexable like a
+//! matrix: an owned `Matrix` or a `MatrixViewMut` into part of one. Applying to a view is how
+//! a transformation is restricted to a trailing block, as the factorizations do at each step.
+
+use std::ops;
+
+use crate::{Matrix, MatrixLike, Real, Vector};
+
+/// The reflection `H = I - tau v vᵀ` that maps a vector `x` onto `beta e₁`, zeroing all but its
+/// first element. `H` is symmetric and orthogonal, so it is its own inverse.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Householder<T> {
+    // normalized so that v[0] = 1
+    v: Vec<T>,
+    tau: T,
+    beta: T
+}
+
+impl<T: Real> Householder<T> {
+    /// The reflector that maps `x` onto `beta e₁`, where `beta` is `±‖x‖`. The sign is chosen
+    /// opposite to that of `x[0]`, which avoids cancellation. If `x` is already a multiple of
+    /// `e₁`, this is the identity.
+    pub fn new(x: &Vector<T>) -> Householder<T> {
+        let n = x.len();
+        let mut v = vec![T::zero(); n];
+        if n == 0 {
+            return Householder { v, tau: T::zero(), beta: T::zero() };
+        }
+
+        let alpha = x[0];
+        v[0] = T::one();
+        let tail = x.iter().skip(1).fold(T::zero(), |acc, &e| acc.hypot(e));
+        if tail.is_zero() {
+            return Householder { v, tau: T::zero(), beta: alpha };
+        }
+
+        let norm = alpha.hypot(tail);
+        let beta = if alpha > T::zero() { -norm } else { norm };
+        for (vi, &xi) in v.iter_mut().zip(x.iter()).skip(1) {
+            *vi = xi / (alpha - beta);
+        }
+        Householder { v, tau: (beta - alpha) / beta, beta }
+    }
+
+    /// The length of the vectors it acts on.
+    pub fn size(&self) -> usize {
+        self.v.len()
+    }
+
+    /// The Householder vector `v`, scaled so that its first element is one.
+    pub fn vector(&self) -> &[T] {
+        &self.v
+    }
+
+    /// The scalar `tau` in `I - tau v vᵀ`, which is zero for the identity.
+    pub fn tau(&self) -> T {
+        self.tau
+    }
+
+    /// The first element of `H x`; the others are zero.
+    pub fn beta(&self) -> T {
+        self.beta
+    }
+
+    /// Replaces `A` by `H A`. This panics unless `A` has `size()` rows.
+    pub fn apply_left<M>(&self, a: &mut M) where M: MatrixLike<T> + ops::IndexMut<(usize, usize), Output = T> + ?Sized {
+        let shape = a.shape();
+        assert!(shape.rows == self.size(),
+            "Can't reflect the {} rows of a matrix with a reflector of size {}.", shape.rows, self.size());
+        if self.tau.is_zero() {
+            return;
+        }
+
+        for c in 0..shape.cols {
+            let w = self.v.iter().enumerate().fold(T::zero(), |acc, (r, &vr)| acc + vr * a[(r, c)]) * self.tau;
+            for (r, &vr) in self.v.iter().enumerate() {
+                a[(r, c)] = a[(r, c)] - w * vr;
+            }
+        }
+    }
+
+    /// Replaces `A` by `A H`. This panics unless `A` has `size()` columns.
+    pub fn apply_right<M>(&self, a: &mut M) where M: MatrixLike<T> + ops::IndexMut<(usize, usize), Output = T> + ?Sized {
+        let shape = a.shape();
+        assert!(shape.cols == self.size(),
+            "Can't reflect the {} columns of a matrix with a reflector of size {}.", shape.cols, self.size());
+        if self.tau.is_zero() {
+            return;
+        }
+
+        for r in 0..shape.rows {
+            let w = self.v.iter().enumerate().fold(T::zero(), |acc, (c, &vc)| acc + a[(r, c)] * vc) * self.tau;
+            for (c, &vc) in self.v.iter().enumerate() {
+                a[(r, c)] = a[(r, c)] - w * vc;
+            }
+        }
+    }
+
+    /// The dense matrix `H`.
+    pub fn to_dense(&self) -> Matrix<T> {
+        let mut h = Matrix::identity(self.size());
+        self.apply_left(&mut h);
+        h
+    }
+}
+
+/// The plane rotation `G = [c s; -s c]` that maps `(a, b)` onto `(r, 0)`.
+///
+/// It acts on two rows (or columns) `i` and `k` of a matrix and leaves the others alone, which
+/// makes it the tool of choice for zeroing single elements, e.g. in Hessenberg or banded
+/// matrices, without disturbing the zeroes already there.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Givens<T> {
+    c: T,
+    s: T,
+    r: T
+}
+
+impl<T: Real> Givens<T> {
+    /// The rotation with `G (a, b)ᵀ = (r, 0)ᵀ` and `r = hypot(a, b)`, or the identity if both are zero.
+    pub fn new(a: T, b: T) -> Givens<T> {
+        let r = a.hypot(b);
+        if r.is_zero() {
+            return Givens { c: T::one(), s: T::zero(), r };
+        }
+        Givens { c: a / r, s: b / r, r }
+    }
+
+    /// The cosine and sine `(c, s)`.
+    pub fn cs(&self) -> (T, T) {
+        (self.c, self.s)
+    }
+
+    /// The length of `(a, b)`, which the rotation leaves in place of `a`.
+    pub fn r(&self) -> T {
+        self.r
+    }
+
+    /// Replaces rows `i` and `k` of `A` by `c row_i + s row_k` and `c row_k - s row_i`, i.e.
+    /// `A` by `G A` in the plane of those two rows. This panics if either is out of bounds.
+    pub fn apply_left<M>(&self, a: &mut M, i: usize, k: usize) where M: MatrixLike<T> + ops::IndexMut<(usize, usize), Output = T> + ?Sized {
+        let shape = a.shape();
+        assert!(i < shape.rows && k < shape.rows,
+            "Can't rotate rows {} and {} of a matrix with {} rows.", i, k, shape.rows);
+
+        for c in 0..shape.cols {
+            let (x, y) = (a[(i, c)], a[(k, c)]);
+            a[(i, c)] = self.c * x + self.s * y;
+            a[(k, c)] = self.c * y - self.s * x;
+        }
+    }
+
+    /// Replaces columns `i` and `k` of `A` in the same way, i.e. `A` by `A Gᵀ`, so that
+    /// `apply_left` followed by `apply_right` is the similarity transformation `G A Gᵀ`.
+    /// This panics if either is out of bounds.
+    pub fn apply_right<M>(&self, a: &mut M, i: usize, k: usize) where M: MatrixLike<T> + ops::IndexMut<(usize, usize), Output = T> + ?Sized {
+        let shape = a.shape();
+        assert!(i < shape.cols && k < shape.cols,
+            "Can't rotate columns {} and {} of a matrix with {} columns.", i, k, shape.cols);
+
+        for r in 0..shape.rows {
+            let (x, y) = (a[(r, i)], a[(r, k)]);
+            a[(r, i)] = self.c * x + self.s * y;
+            a[(r, k)] = self.c * y - self.s * x;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn householder_reflection() {
+        let x = Vector::new(vec![3.0, 1.0, 5.0, 1.0]);
+        let h = Householder::new(&x);
+        let dense = h.to_dense();
+
+        assert!((h.beta().abs() - 6.0).abs() < 1e-14 && h.beta() < 0.0);
+        assert!((&dense * &dense).approx_eq(&Matrix::identity(4), 1e-14, 0.0));
+        let mut column = Matrix::from(x);
+        h.apply_left(&mut column);
+        assert!(column.approx_eq(&matrix![h.beta(); 0.0; 0.0; 0.0], 1e-14, 0.0));
+
+        let a: Matrix<f64> = Matrix::from_fn(3, 4, |r, c| (r * 4 + c) as f64);
+        let mut b = a.clone();
+        h.apply_right(&mut b);
+        assert!(b.approx_eq(&(&a * &dense), 1e-12, 0.0));
+
+        // already along e₁
+        let e = Householder::new(&Vector::new(vec![-2.0, 0.0]));
+        assert_eq!((e.tau(), e.beta()), (0.0, -2.0));
+    }
+
+    #[test]
+    fn on_views() {
+        // zero the first column below the diagonal, then the second, working on trailing blocks
+        let mut a: Matrix<f64> = matrix![4.0, 1.0, -2.0;
+                                         3.0, 2.0, 0.0;
+                                         0.0, 1.0, 5.0;
+                                         2.0, -1.0, 1.0];
+        let original = a.clone();
+        for k in 0..2 {
+            let x = Vector::new((k..4).map(|r| a[(r, k)]).collect());
+            Householder::new(&x).apply_left(&mut a.view_mut(k..4, k..3));
+        }
+        for &(r, c) in &[(1, 0), (2, 0), (3, 0), (2, 1), (3, 1)] {
+            assert!(a[(r, c)].abs() < 1e-14);
+        }
+        // an orthogonal transformation keeps the Frobenius norm
+        assert!((a.norm(crate::NormKind::Frobenius) - original.norm(crate::NormKind::Frobenius)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn givens_rotation() {
+        let g = Givens::new(3.0, 4.0);
+        assert_eq!(g.r(), 5.0);
+
+        let mut a: Matrix<f64> = matrix![1.0, 3.0;
+                                         2.0, 7.0;
+                                         0.0, 4.0];
+        g.apply_left(&mut a, 0, 2);
+        assert!(a.approx_eq(&matrix![0.6, 5.0; 2.0, 7.0; -0.8, 0.0], 1e-14, 0.0));
+
+        let mut b: Matrix<f64> = matrix![3.0, 1.0, 4.0];
+        g.apply_right(&mut b, 0, 2);
+        assert!(b.approx_eq(&matrix![5.0, 1.0, 0.0], 1e-14, 0.0));
+
+        let mut view_target: Matrix<f64> = Matrix::identity(3);
+        Givens::new(0.0, 0.0).apply_left(&mut view_target.as_view_mut(), 0, 1);
+        assert_eq!(view_target, Matrix::identity(3));
+    }
+}
@@ -0,0 +1,222 @@
+//! Operations that are applied to every element on its own, or to every row or column on its own.
+
+use std::ops;
+
+use crate::{Matrix, Real};
+
+impl<T: Default> Matrix<T> {
+    /// Returns a new matrix of the same shape, with `f` applied to every element.
+    pub fn map<U: Default, F: FnMut(&T) -> U>(&self, f: F) -> Matrix<U> {
+        Matrix {
+            rows: self.rows,
+            columns: self.columns,
+            contents: self.contents.iter().map(f).collect()
+        }
+    }
+
+    /// Applies `f` to every element in place.
+    pub fn map_mut<F: FnMut(&mut T)>(&mut self, f: F) {
+        self.contents.iter_mut().for_each(f);
+    }
+
+    /// Combines two matrices of the same shape element by element.
+    ///
+    /// For example, `a.zip_with(&b, |x, y| x.max(y))` gives the element-wise maximum.
+    /// This panics if the shapes differ.
+    pub fn zip_with<U: Default, V: Default, F: FnMut(&T, &U) -> V>(&self, o: &Matrix<U>, mut f: F) -> Matrix<V> {
+        assert!(self.rows == o.rows && self.columns == o.columns,
+            "Can only zip matrices of the same dimension, but got ({}, {}) and ({}, {}).",
+            self.rows, self.columns, o.rows, o.columns);
+
+        Matrix {
+            rows: self.rows,
+            columns: self.columns,
+            contents: self.contents.iter().zip(&o.contents).map(|(a, b)| f(a, b)).collect()
+        }
+    }
+}
+
+impl<T: Default> Matrix<T> {
+    /// Builds a new matrix by handing every row to `f` and using what it returns as the
+    /// corresponding row of the result.
+    ///
+    /// The returned rows may be longer or shorter than the original ones, but they all need
+    /// to have the same length; this panics otherwise.
+    pub fn map_rows<U: Default, F: FnMut(&[T]) -> Vec<U>>(&self, mut f: F) -> Matrix<U> {
+        let mapped: Vec<Vec<U>> = (0..self.rows)
+            .map(|r| f(&self.contents[r * self.columns..(r + 1) * self.columns]))
+            .collect();
+
+        assemble(mapped, "row")
+    }
+}
+
+impl<T: Default + Clone> Matrix<T> {
+    /// Builds a new matrix by handing every column to `f` and using what it returns as the
+    /// corresponding column of the result. As with `map_rows`, all returned columns need
+    /// to have the same length.
+    pub fn map_cols<U: Default + Clone, F: FnMut(&[T]) -> Vec<U>>(&self, mut f: F) -> Matrix<U> {
+        let mapped: Vec<Vec<U>> = self.columns()
+            .map(|column| f(&column.cloned().collect::<Vec<T>>()))
+            .collect();
+
+        assemble(mapped, "column").transpose()
+    }
+}
+
+impl<T: Default + Copy + ops::Div<Output = T>> Matrix<T> {
+    /// Divides element by element, the counterpart of `hadamard`. This panics if the shapes differ.
+    pub fn component_div(&self, o: &Matrix<T>) -> Matrix<T> {
+        assert!(self.rows == o.rows && self.columns == o.columns,
+            "Can only divide matrices of the same dimension element-wise.");
+        self.zip_with(o, |&a, &b| a / b)
+    }
+}
+
+impl<T: Default + Clone + PartialOrd> Matrix<T> {
+    /// The smaller of every pair of corresponding elements. This panics if the shapes differ.
+    pub fn component_min(&self, o: &Matrix<T>) -> Matrix<T> {
+        self.zip_with(o, |a, b| if b < a { b.clone() } else { a.clone() })
+    }
+
+    /// The larger of every pair of corresponding elements. This panics if the shapes differ.
+    pub fn component_max(&self, o: &Matrix<T>) -> Matrix<T> {
+        self.zip_with(o, |a, b| if b > a { b.clone() } else { a.clone() })
+    }
+}
+
+impl<T: Real> Matrix<T> {
+    /// The absolute value of every element.
+    pub fn abs(&self) -> Matrix<T> {
+        self.map(|e| e.abs())
+    }
+
+    /// Raises every element to the integer power `n`. This is not the matrix power; see `pow`.
+    pub fn powi(&self, n: i32) -> Matrix<T> {
+        self.map(|e| e.powi(n))
+    }
+
+    /// Raises every element to the power `n`.
+    pub fn powf(&self, n: T) -> Matrix<T> {
+        self.map(|e| e.powf(n))
+    }
+}
+
+// turns the mapped rows into a matrix, checking that they are all the same length
+fn assemble<U: Default>(mapped: Vec<Vec<U>>, what: &str) -> Matrix<U> {
+    let width = mapped.first().map_or(0, Vec::len);
+    if let Some(i) = mapped.iter().position(|v| v.len() != width) {
+        panic!("The mapped {} {} has length {}, but the first one has length {}.", what, i, mapped[i].len(), width);
+    }
+
+    Matrix::from(mapped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn map_changes_type() {
+        let m: Matrix<i32> = matrix![1, -2;
+                                     3, -4];
+        let signs: Matrix<bool> = m.map(|&x| x > 0);
+
+        assert_eq!(signs.shape(), m.shape());
+        assert_eq!(signs.contents, vec![true, false, true, false]);
+    }
+
+    #[test]
+    fn map_in_place() {
+        let mut m: Matrix<f64> = matrix![1.0, 4.0, 9.0];
+        m.map_mut(|x| *x = x.sqrt());
+
+        assert_eq!(m.contents, vec![1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn zip_two_matrices() {
+        let a: Matrix<i32> = matrix![1, 5; 3, 2];
+        let b: Matrix<i32> = matrix![4, 2; 3, 6];
+
+        assert_eq!(a.zip_with(&b, |x, y| *x.max(y)).contents, vec![4, 5, 3, 6]);
+        assert_eq!(a.zip_with(&b, |x, y| x == y).contents, vec![false, false, true, false]);
+    }
+
+    #[test]
+    fn softmax_rows() {
+        let m: Matrix<f64> = matrix![0.0, 0.0;
+                                     1.0, 1.0;
+                                     0.0, 2.0f64.ln()];
+
+        let p = m.map_rows(|row| {
+            let exps: Vec<f64> = row.iter().map(|x| x.exp()).collect();
+            let total: f64 = exps.iter().sum();
+            exps.into_iter().map(|e| e / total).collect()
+        });
+
+        assert_eq!(p.shape(), (3, 2).into());
+        assert_eq!(p.row(0).contents, vec![0.5, 0.5]);
+        assert!((p[(2, 1)] - 2.0 / 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn demean_columns() {
+        let m: Matrix<f64> = matrix![1.0, 10.0;
+                                     3.0, 20.0];
+
+        let centered = m.map_cols(|col| {
+            let mean = col.iter().sum::<f64>() / col.len() as f64;
+            col.iter().map(|x| x - mean).collect()
+        });
+        assert_eq!(centered.contents, vec![-1.0, -5.0, 1.0, 5.0]);
+
+        let summed: Matrix<f64> = m.map_cols(|col| vec![col.iter().sum()]);
+        assert_eq!(summed.shape(), (1, 2).into());
+        assert_eq!(summed.contents, vec![4.0, 30.0]);
+    }
+
+    #[test]
+    fn component_operations() {
+        let a: Matrix<f64> = matrix![1.0, -4.0;
+                                     9.0, 2.0];
+        let b: Matrix<f64> = matrix![2.0, 2.0;
+                                     3.0, -1.0];
+
+        assert_eq!(a.component_div(&b), matrix![0.5, -2.0; 3.0, -2.0]);
+        assert_eq!(a.component_min(&b), matrix![1.0, -4.0; 3.0, -1.0]);
+        assert_eq!(a.component_max(&b), matrix![2.0, 2.0; 9.0, 2.0]);
+    }
+
+    #[test]
+    fn float_functions() {
+        let m: Matrix<f64> = matrix![-2.0, 4.0];
+
+        assert_eq!(m.abs(), matrix![2.0, 4.0]);
+        assert_eq!(m.powi(2), matrix![4.0, 16.0]);
+        assert_eq!(m.abs().powf(0.5), matrix![2.0f64.sqrt(), 2.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Can only divide matrices of the same dimension element-wise.")]
+    fn component_div_mismatched() {
+        let a: Matrix<i32> = Matrix::ones(2, 2);
+        a.component_div(&Matrix::ones(1, 2));
+    }
+
+    #[test]
+    #[should_panic(expected = "The mapped row 1 has length 2")]
+    fn ragged_mapping() {
+        let m: Matrix<i32> = matrix![1, 2; 3, 4];
+        m.map_rows(|row| row.iter().filter(|&&x| x > 1).copied().collect());
+    }
+
+    #[test]
+    #[should_panic(expected = "Can only zip matrices of the same dimension")]
+    fn zip_mismatched() {
+        let a: Matrix<i32> = Matrix::zeroes(2, 2);
+        let b: Matrix<i32> = Matrix::zeroes(2, 3);
+
+        a.zip_with(&b, |x, y| x + y);
+    }
+}
@@ -0,0 +1,88 @@
+use std::error;
+use std::fmt;
+
+/// Everything that can go wrong when working with matrices.
+///
+/// The operators (`+`, `*`, indexing, ...) still panic on bad input, since that's what
+/// you want most of the time. Every fallible operation also has a `try_` counterpart
+/// that returns one of these instead, for when a bad shape shouldn't bring down the whole program.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MatrixError {
+    /// The shapes of the operands don't fit the operation, e.g. adding a 2x3 matrix to a 3x2 one.
+    /// Shapes are given as `(rows, columns)`.
+    DimensionMismatch {
+        operation: &'static str,
+        left: (usize, usize),
+        right: (usize, usize)
+    },
+    /// The number of elements supplied doesn't fit the requested shape.
+    ElementCount {
+        expected: usize,
+        found: usize
+    },
+    /// The operation is only defined for square matrices.
+    NotSquare {
+        rows: usize,
+        columns: usize
+    },
+    /// The matrix is singular (or numerically indistinguishable from singular).
+    Singular,
+    /// The operation needs a symmetric positive definite matrix, and this one isn't.
+    NotPositiveDefinite,
+    /// An iterative algorithm didn't reach the requested accuracy within its iteration limit.
+    NoConvergence {
+        iterations: usize
+    },
+    /// A system of equations has fewer equations (rows) than unknowns (columns), so its
+    /// solution isn't unique.
+    Underdetermined {
+        rows: usize,
+        columns: usize
+    },
+    /// A matrix function (e.g. the square root or the logarithm) isn't defined for this matrix
+    /// as a real matrix, typically because of eigenvalues on the negative real axis.
+    NoRealResult {
+        operation: &'static str
+    },
+    /// An element outside the matrix was addressed.
+    IndexOutOfBounds {
+        index: (usize, usize),
+        shape: (usize, usize)
+    },
+    /// The operation has no result for a matrix without any elements, e.g. an eigenvalue of a
+    /// 0×0 matrix.
+    Empty {
+        operation: &'static str
+    }
+}
+
+impl fmt::Display for MatrixError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MatrixError::DimensionMismatch { operation, left, right } =>
+                write!(f, "Can't perform {} on matrices of dimensions ({}, {}) and ({}, {}).",
+                    operation, left.0, left.1, right.0, right.1),
+            MatrixError::ElementCount { expected, found } =>
+                write!(f, "Expected {} elements, but {} were given.", expected, found),
+            MatrixError::NotSquare { rows, columns } =>
+                write!(f, "This operation requires a square matrix, but got one of dimensions ({}, {}).", rows, columns),
+            MatrixError::Singular =>
+                write!(f, "The matrix is singular."),
+            MatrixError::NotPositiveDefinite =>
+                write!(f, "The matrix is not positive definite."),
+            MatrixError::NoConvergence { iterations } =>
+                write!(f, "The algorithm didn't converge within {} iterations.", iterations),
+            MatrixError::Underdetermined { rows, columns } =>
+                write!(f, "A system with {} equations and {} unknowns has no unique solution.", rows, columns),
+            MatrixError::NoRealResult { operation } =>
+                write!(f, "The {} of this matrix isn't a real matrix.", operation),
+            MatrixError::IndexOutOfBounds { index, shape } =>
+                write!(f, "Index ({}, {}) is out of bounds for a matrix of dimensions ({}, {}).",
+                    index.0, index.1, shape.0, shape.1),
+            MatrixError::Empty { operation } =>
+                write!(f, "Can't perform {} on an empty matrix.", operation)
+        }
+    }
+}
+
+impl error::Error for MatrixError {}
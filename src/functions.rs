@@ -0,0 +1,235 @@
+//! Functions of square matrices, such as the exponential, the square root and the logarithm.
+
+use crate::{Matrix, MatrixError, NormKind, Real};
+
+// the coefficients of the degree 13 Padé approximant to the exponential
+const PADE_13: [f64; 14] = [
+    64764752532480000.0, 32382376266240000.0, 7771770303897600.0, 1187353796428800.0,
+    129060195264000.0, 10559470521600.0, 670442572800.0, 33522128640.0,
+    1323241920.0, 40840800.0, 960960.0, 16380.0, 182.0, 1.0
+];
+
+// the largest 1-norm for which the degree 13 approximant is accurate to double precision
+const THETA_13: f64 = 5.371920351148152;
+
+impl<T: Real> Matrix<T> {
+    /// The matrix exponential `e^A = I + A + A²/2! + ...`.
+    ///
+    /// This is the scaling and squaring method of Higham (2005): `A` is divided by a power of two
+    /// 2ˢ until its norm is small, the exponential of that is approximated by a degree 13 Padé
+    /// approximant, and the result is squared s times. `(A t).expm() * x0` solves the linear
+    /// differential equation `x' = A x` with `x(0) = x0`. Fails with `NotSquare`.
+    pub fn expm(&self) -> Result<Matrix<T>, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare { rows: self.rows, columns: self.columns });
+        }
+
+        let n = self.rows;
+        let norm = self.norm(NormKind::One);
+        let squarings = if norm.to_f64() > THETA_13 {
+            (norm.to_f64() / THETA_13).log2().ceil() as i32
+        } else {
+            0
+        };
+        let a = self * T::from_f64(2.0).powi(-squarings);
+
+        let b: Vec<T> = PADE_13.iter().map(|&c| T::from_f64(c)).collect();
+        let identity = Matrix::identity(n);
+        let a2 = &a * &a;
+        let a4 = &a2 * &a2;
+        let a6 = &a4 * &a2;
+
+        // the odd part U and the even part V of the numerator, so that the approximant is (V - U)⁻¹ (V + U)
+        let u_inner = &a6 * &combine(&[(b[13], &a6), (b[11], &a4), (b[9], &a2)])
+            + combine(&[(b[7], &a6), (b[5], &a4), (b[3], &a2), (b[1], &identity)]);
+        let u = &a * &u_inner;
+        let v = &a6 * &combine(&[(b[12], &a6), (b[10], &a4), (b[8], &a2)])
+            + combine(&[(b[6], &a6), (b[4], &a4), (b[2], &a2), (b[0], &identity)]);
+
+        let mut result = (&v - &u).solve(&(&v + &u))?;
+        for _ in 0..squarings {
+            result = &result * &result;
+        }
+        Ok(result)
+    }
+
+    /// The principal square root, the unique `X` with `X X = A` whose eigenvalues all have
+    /// positive real parts.
+    ///
+    /// Computed with the Denman–Beavers iteration. The principal square root of a real matrix is
+    /// real exactly when none of its eigenvalues are zero or negative real numbers; this fails
+    /// with `NoRealResult` otherwise, and with `NotSquare` or `NoConvergence` as usual.
+    pub fn sqrtm(&self) -> Result<Matrix<T>, MatrixError> {
+        self.check_principal("square root")?;
+        self.denman_beavers()
+    }
+
+    /// The principal logarithm, the unique `X` with `e^X = A` whose eigenvalues all have
+    /// imaginary parts in `(-π, π)`.
+    ///
+    /// This is the inverse scaling and squaring method: square roots are taken until the matrix
+    /// is close to the identity, the logarithm of that is summed as a series, and the result is
+    /// scaled back up. It exists as a real matrix under the same conditions as `sqrtm`, and fails
+    /// in the same ways.
+    pub fn logm(&self) -> Result<Matrix<T>, MatrixError> {
+        const MAX_TERMS: usize = 100;
+        self.check_principal("logarithm")?;
+
+        let n = self.rows;
+        let identity = Matrix::identity(n);
+        let mut a = self.clone();
+        let mut roots = 0;
+        while (&a - &identity).norm(NormKind::One) > T::from_f64(0.25) {
+            a = a.denman_beavers()?;
+            roots += 1;
+        }
+
+        // log(A) = 2 atanh(Z) = 2 (Z + Z³/3 + Z⁵/5 + ...) with Z = (A - I)(A + I)⁻¹, which is
+        // small now. The factors commute, so solving from the left gives the same Z
+        let z = (&a + &identity).solve(&(&a - &identity))?;
+        let z2 = &z * &z;
+        let mut power = z.clone();
+        let mut sum = z;
+        let mut converged = false;
+        for k in 1..MAX_TERMS {
+            power = &power * &z2;
+            let term = &power * (T::one() / T::from_f64((2 * k + 1) as f64));
+            sum += &term;
+            if term.norm(NormKind::One) <= T::epsilon() * sum.norm(NormKind::One) {
+                converged = true;
+                break;
+            }
+        }
+        if !converged {
+            return Err(MatrixError::NoConvergence { iterations: MAX_TERMS });
+        }
+
+        Ok(sum * T::from_f64(2f64.powi(roots + 1)))
+    }
+
+    // sqrtm and logm need a square matrix without eigenvalues on the closed negative real axis
+    fn check_principal(&self, operation: &'static str) -> Result<(), MatrixError> {
+        let tolerance = self.norm(NormKind::One) * T::epsilon() * T::from_f64(self.rows as f64);
+        let eigenvalues = self.eigenvalues()?;
+        if eigenvalues.iter().any(|e| e.im.is_zero() && e.re <= tolerance) {
+            return Err(MatrixError::NoRealResult { operation });
+        }
+        Ok(())
+    }
+
+    fn denman_beavers(&self) -> Result<Matrix<T>, MatrixError> {
+        const MAX_ITERATIONS: usize = 100;
+        let half = T::from_f64(0.5);
+        let mut y = self.clone();
+        let mut z = Matrix::identity(self.rows);
+
+        // Y → A^½ and Z → A^-½
+        for _ in 0..MAX_ITERATIONS {
+            let next_y = (&y + &z.inverse()?) * half;
+            let next_z = (&z + &y.inverse()?) * half;
+            let change = (&next_y - &y).norm(NormKind::Frobenius);
+            y = next_y;
+            z = next_z;
+            if change <= T::epsilon() * T::from_f64(self.rows as f64) * y.norm(NormKind::Frobenius) {
+                return Ok(y);
+            }
+        }
+        Err(MatrixError::NoConvergence { iterations: MAX_ITERATIONS })
+    }
+}
+
+// the linear combination of equally shaped matrices
+fn combine<T: Real>(terms: &[(T, &Matrix<T>)]) -> Matrix<T> {
+    let (rows, columns) = (terms[0].1.rows, terms[0].1.columns);
+    let mut out = Matrix::zeroes(rows, columns);
+    for (k, m) in terms {
+        for (o, &e) in out.contents.iter_mut().zip(&m.contents) {
+            *o = *o + *k * e;
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diagonal_and_nilpotent() {
+        let mut d: Matrix<f64> = Matrix::zeroes(3, 3);
+        d.set_diagonal(&[1.0, -2.0, 0.0]);
+        let mut expected: Matrix<f64> = Matrix::zeroes(3, 3);
+        expected.set_diagonal(&[1f64.exp(), (-2f64).exp(), 1.0]);
+        assert!(d.expm().unwrap().approx_eq(&expected, 1e-14, 1e-14));
+
+        // the series stops after A, since A² = 0
+        let nilpotent: Matrix<f64> = matrix![0.0, 3.0;
+                                             0.0, 0.0];
+        assert!(nilpotent.expm().unwrap().approx_eq(&matrix![1.0, 3.0; 0.0, 1.0], 1e-14, 0.0));
+        assert_eq!(Matrix::<f64>::zeroes(2, 2).expm().unwrap(), Matrix::identity(2));
+    }
+
+    #[test]
+    fn rotation() {
+        // x' = A x with this A rotates x at unit angular speed
+        let t = 10.0f64;
+        let a: Matrix<f64> = matrix![0.0, -t;
+                                     t, 0.0];
+
+        let rotation = a.expm().unwrap();
+        assert!(rotation.approx_eq(&matrix![t.cos(), -t.sin(); t.sin(), t.cos()], 1e-12, 0.0));
+    }
+
+    #[test]
+    fn exponential_of_sum_of_commuting() {
+        let a: Matrix<f64> = matrix![1.0, 2.0;
+                                     -1.0, 3.0];
+        let twice = (&a + &a).expm().unwrap();
+        let e = a.expm().unwrap();
+
+        assert!((&e * &e).approx_eq(&twice, 0.0, 1e-12));
+        assert_eq!(Matrix::<f64>::zeroes(2, 3).expm(), Err(MatrixError::NotSquare { rows: 2, columns: 3 }));
+    }
+
+    #[test]
+    fn square_root() {
+        let a: Matrix<f64> = matrix![33.0, 24.0;
+                                     48.0, 57.0];
+
+        let root = a.sqrtm().unwrap();
+        assert!(root.approx_eq(&matrix![5.0, 2.0; 4.0, 7.0], 1e-12, 0.0));
+
+        // a rotation by 90 degrees has the rotation by 45 degrees as its root
+        let quarter: Matrix<f64> = matrix![0.0, -1.0;
+                                           1.0, 0.0];
+        let eighth = quarter.sqrtm().unwrap();
+        assert!((&eighth * &eighth).approx_eq(&quarter, 1e-12, 0.0));
+        assert!((eighth[(0, 0)] - 0.5f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn logarithm() {
+        let a: Matrix<f64> = matrix![1.0, 2.0;
+                                     -1.0, 3.0];
+        let log = a.expm().unwrap().logm().unwrap();
+        assert!(log.approx_eq(&a, 1e-10, 0.0));
+
+        let spd: Matrix<f64> = matrix![4.0, 1.0, 0.0;
+                                       1.0, 3.0, 1.0;
+                                       0.0, 1.0, 2.0];
+        assert!(spd.logm().unwrap().expm().unwrap().approx_eq(&spd, 1e-12, 0.0));
+        assert_eq!(Matrix::<f64>::identity(3).logm().unwrap(), Matrix::zeroes(3, 3));
+    }
+
+    #[test]
+    fn undefined_roots_and_logarithms() {
+        let negative: Matrix<f64> = matrix![-1.0, 0.0;
+                                            0.0, 4.0];
+        let singular: Matrix<f64> = matrix![1.0, 2.0;
+                                            2.0, 4.0];
+
+        assert_eq!(negative.sqrtm(), Err(MatrixError::NoRealResult { operation: "square root" }));
+        assert_eq!(singular.logm(), Err(MatrixError::NoRealResult { operation: "logarithm" }));
+        assert_eq!(Matrix::<f64>::zeroes(1, 2).sqrtm(), Err(MatrixError::NotSquare { rows: 1, columns: 2 }));
+    }
+}
@@ -0,0 +1,176 @@
+//! Constructors for evenly spaced ranges, coordinate grids and classic structured matrices.
+
+use crate::{Matrix, One, Real};
+
+impl<T: Real> Matrix<T> {
+    /// Returns a row of `n` evenly spaced values from `start` to `end`, both included.
+    ///
+    /// Use `.transpose()` on the result if a column is needed. For `n = 1` the only value
+    /// is `start`, and `n = 0` gives a 1×0 matrix.
+    pub fn linspace(start: T, end: T, n: usize) -> Matrix<T> {
+        let step = if n > 1 { (end - start) / T::from_f64((n - 1) as f64) } else { T::zero() };
+        let mut contents: Vec<T> = (0..n).map(|i| start + step * T::from_f64(i as f64)).collect();
+        if n > 1 {
+            // avoid rounding errors at the end of the range
+            contents[n - 1] = end;
+        }
+
+        Matrix { rows: 1, columns: n, contents }
+    }
+
+    /// Returns a row holding `start`, `start + step`, `start + 2 * step` and so on, for as
+    /// long as the values stay below `end` (or above it, for a negative `step`).
+    ///
+    /// Like Rust's ranges, `end` itself is never included. This panics if `step` is zero.
+    pub fn arange(start: T, end: T, step: T) -> Matrix<T> {
+        assert!(!step.is_zero(), "The step of a range can't be zero.");
+
+        let mut contents = Vec::new();
+        loop {
+            let x = start + step * T::from_f64(contents.len() as f64);
+            let inside = if step > T::zero() { x < end } else { x > end };
+            if !inside {
+                break;
+            }
+            contents.push(x);
+        }
+
+        Matrix { rows: 1, columns: contents.len(), contents }
+    }
+}
+
+impl<T: Default + Clone> Matrix<T> {
+    /// Turns two lists of coordinates into a pair of matrices holding the x and y coordinate of
+    /// every point of the grid they span.
+    ///
+    /// Both matrices are `ys.len()` by `xs.len()`: every row of the first one is a copy of `xs`,
+    /// and every column of the second one is a copy of `ys`. This matches NumPy's default
+    /// `meshgrid`, and makes `f(xs[c], ys[r])` end up at position `(r, c)`.
+    pub fn meshgrid(xs: &[T], ys: &[T]) -> (Matrix<T>, Matrix<T>) {
+        let x = Matrix::from_fn(ys.len(), xs.len(), |_, c| xs[c].clone());
+        let y = Matrix::from_fn(ys.len(), xs.len(), |r, _| ys[r].clone());
+        (x, y)
+    }
+}
+
+impl<T: Real> Matrix<T> {
+    /// The n×n Hilbert matrix, with `1 / (r + c + 1)` at position `(r, c)`.
+    ///
+    /// It is notoriously ill-conditioned, which makes it a good stress test for solvers.
+    pub fn hilbert(n: usize) -> Matrix<T> {
+        Matrix::from_fn(n, n, |r, c| T::one() / T::from_f64((r + c + 1) as f64))
+    }
+}
+
+impl<T: Default + Clone + One> Matrix<T> {
+    /// The square Vandermonde matrix of `xs`, whose row `r` holds the powers
+    /// `1, xs[r], xs[r]^2, ...` up to `xs[r]^(n - 1)`.
+    ///
+    /// Solving a system with it gives the coefficients of the polynomial through the points.
+    pub fn vandermonde(xs: &[T]) -> Matrix<T> {
+        let n = xs.len();
+        let mut contents = Vec::with_capacity(n * n);
+        for x in xs {
+            let mut power = T::one();
+            for _ in 0..n {
+                contents.push(power.clone());
+                power = power * x.clone();
+            }
+        }
+
+        Matrix { rows: n, columns: n, contents }
+    }
+}
+
+impl<T: Default + Clone> Matrix<T> {
+    /// The Toeplitz matrix with first column `col` and first row `row`, which is constant
+    /// along every diagonal.
+    ///
+    /// The top left element is taken from `col`, so `row[0]` is ignored. This panics if `row` is empty
+    /// while `col` isn't, or the other way around.
+    pub fn toeplitz(col: &[T], row: &[T]) -> Matrix<T> {
+        assert!(col.is_empty() == row.is_empty(),
+            "A Toeplitz matrix needs either both or neither of its first row and column, but got {} and {} elements.",
+            row.len(), col.len());
+
+        Matrix::from_fn(col.len(), row.len(), |r, c| {
+            if r >= c { col[r - c].clone() } else { row[c - r].clone() }
+        })
+    }
+
+    /// The circulant matrix whose rows are `first_row` shifted to the right by one more
+    /// position each time, wrapping around at the end.
+    pub fn circulant(first_row: &[T]) -> Matrix<T> {
+        let n = first_row.len();
+        Matrix::from_fn(n, n, |r, c| first_row[(c + n - r) % n].clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn linspace() {
+        let m: Matrix<f64> = Matrix::linspace(0.0, 1.0, 5);
+
+        assert_eq!(m.shape(), (1, 5).into());
+        assert_eq!(m.contents, vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+        assert_eq!(Matrix::linspace(1.0, 0.1, 10).contents[9], 0.1);
+        assert_eq!(Matrix::linspace(2.0, 3.0, 1).contents, vec![2.0]);
+        assert!(Matrix::<f64>::linspace(2.0, 3.0, 0).is_empty());
+    }
+
+    #[test]
+    fn arange() {
+        assert_eq!(Matrix::arange(0.0, 1.0, 0.25).contents, vec![0.0, 0.25, 0.5, 0.75]);
+        assert_eq!(Matrix::arange(3.0, 0.0, -1.0).contents, vec![3.0, 2.0, 1.0]);
+        assert!(Matrix::arange(1.0, 0.0, 0.5).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "The step of a range can't be zero.")]
+    fn arange_zero_step() {
+        Matrix::arange(0.0, 1.0, 0.0);
+    }
+
+    #[test]
+    fn meshgrid() {
+        let (x, y) = Matrix::meshgrid(&[1, 2, 3], &[10, 20]);
+
+        assert_eq!(x, matrix![1, 2, 3; 1, 2, 3]);
+        assert_eq!(y, matrix![10, 10, 10; 20, 20, 20]);
+    }
+
+    #[test]
+    fn hilbert() {
+        let h: Matrix<f64> = Matrix::hilbert(3);
+
+        assert_eq!(h.row(0).contents, vec![1.0, 0.5, 1.0 / 3.0]);
+        assert_eq!(h[(2, 2)], 0.2);
+        assert!(h.is_symmetric());
+    }
+
+    #[test]
+    fn vandermonde() {
+        let v: Matrix<i32> = Matrix::vandermonde(&[1, 2, 3]);
+
+        assert_eq!(v, matrix![1, 1, 1;
+                              1, 2, 4;
+                              1, 3, 9]);
+    }
+
+    #[test]
+    fn toeplitz_and_circulant() {
+        let t = Matrix::toeplitz(&[1, 2, 3], &[1, 4, 5, 6]);
+        assert_eq!(t, matrix![1, 4, 5, 6;
+                              2, 1, 4, 5;
+                              3, 2, 1, 4]);
+
+        let c = Matrix::circulant(&[1, 2, 3]);
+        assert_eq!(c, matrix![1, 2, 3;
+                              3, 1, 2;
+                              2, 3, 1]);
+        assert!(Matrix::<i32>::circulant(&[]).is_empty());
+    }
+}
@@ -0,0 +1,261 @@
+//! Inverting matrices, and the generalized inverse of those that can't be inverted.
+
+use crate::decomposition::pivot_tolerance;
+use crate::{Field, Matrix, MatrixError, Real, Vector};
+
+impl<T: Real> Matrix<T> {
+    /// The inverse, computed by Gauss–Jordan elimination with partial pivoting.
+    ///
+    /// Fails with `NotSquare` for non-square matrices and with `Singular` if a pivot is zero,
+    /// or so small relative to the largest element that the result would be meaningless.
+    pub fn inverse(&self) -> Result<Matrix<T>, MatrixError> {
+        let mut out = Matrix { rows: 0, columns: 0, contents: Vec::new() };
+        self.try_inverse_into(&mut out)?;
+        Ok(out)
+    }
+
+    /// The Moore–Penrose pseudoinverse `A⁺`, computed through the SVD.
+    ///
+    /// Singular values at or below `tolerance` count as zero and are left out, rather than
+    /// inverted into huge numbers. A common choice is the largest singular value times
+    /// `T::epsilon()` times the larger dimension. For an invertible matrix and a small enough
+    /// tolerance, this is just the inverse; for an m×n matrix in general, it is n×m and
+    /// `A⁺ b` is the least squares solution of smallest norm.
+    pub fn pinv(&self, tolerance: T) -> Matrix<T> {
+        self.svd().solve(&Matrix::identity(self.rows), tolerance)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Given this matrix as the inverse `A⁻¹`, computes the inverse of the rank one update
+    /// `A + u vᵀ` with the Sherman–Morrison formula, in O(n²) instead of O(n³):
+    ///
+    /// `(A + u vᵀ)⁻¹ = A⁻¹ - (A⁻¹ u)(vᵀ A⁻¹) / (1 + vᵀ A⁻¹ u)`
+    ///
+    /// Fails with `Singular` if the updated matrix is (numerically) singular, with `NotSquare`,
+    /// and with `DimensionMismatch` if `u` or `v` has the wrong length.
+    pub fn sherman_morrison(&self, u: &Vector<T>, v: &Vector<T>) -> Result<Matrix<T>, MatrixError> {
+        self.check_update("a Sherman–Morrison update", (u.len(), 1), (v.len(), 1))?;
+
+        let au = self.apply(u.as_slice());
+        let va = self.apply_transposed(v.as_slice());
+        let term = v.dot(&au);
+        let denominator = T::one() + term;
+        if denominator.abs() <= T::epsilon() * (T::one() + term.abs()) {
+            return Err(MatrixError::Singular);
+        }
+
+        Ok(self - &(Matrix::outer(&au, &va) * (T::one() / denominator)))
+    }
+
+    /// Given this matrix as the inverse `A⁻¹`, computes the inverse of the rank k update
+    /// `A + U Vᵀ`, where `U` and `V` are n×k, with the Woodbury identity:
+    ///
+    /// `(A + U Vᵀ)⁻¹ = A⁻¹ - A⁻¹ U (I + Vᵀ A⁻¹ U)⁻¹ Vᵀ A⁻¹`
+    ///
+    /// Only a k×k matrix is inverted, which pays off for k much smaller than n. Fails in the
+    /// same ways as `sherman_morrison`.
+    pub fn woodbury(&self, u: &Matrix<T>, v: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        self.check_update("a Woodbury update", (u.rows, u.columns), (v.rows, v.columns))?;
+        if u.columns != v.columns {
+            return Err(MatrixError::DimensionMismatch { operation: "a Woodbury update", left: (u.rows, u.columns), right: (v.rows, v.columns) });
+        }
+
+        let au = self * u;
+        let va = &v.transpose() * self;
+        let capacitance = Matrix::identity(u.columns) + &(&v.transpose() * &au);
+        Ok(self - &(&(&au * &capacitance.inverse()?) * &va))
+    }
+
+    fn check_update(&self, operation: &'static str, u: (usize, usize), v: (usize, usize)) -> Result<(), MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare { rows: self.rows, columns: self.columns });
+        }
+        for &shape in &[u, v] {
+            if shape.0 != self.rows {
+                return Err(MatrixError::DimensionMismatch { operation, left: (self.rows, self.columns), right: shape });
+            }
+        }
+        Ok(())
+    }
+
+    /// Like `inverse`, but writes the result into `out`, reusing its allocation. `out` is
+    /// reshaped as needed; if this fails, its contents are unspecified.
+    pub fn try_inverse_into(&self, out: &mut Matrix<T>) -> Result<(), MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare { rows: self.rows, columns: self.columns });
+        }
+
+        let n = self.rows;
+        out.rows = n;
+        out.columns = n;
+        out.contents.clear();
+        out.contents.resize(n * n, T::zero());
+        for i in 0..n {
+            out.contents[i * n + i] = T::one();
+        }
+
+        let mut a = self.clone();
+        let tolerance = pivot_tolerance(self);
+
+        for col in 0..n {
+            // the row with the largest pivot candidate, for numerical stability
+            let mut pivot_row = col;
+            for r in col + 1..n {
+                if a.contents[r * n + col].abs() > a.contents[pivot_row * n + col].abs() {
+                    pivot_row = r;
+                }
+            }
+            let pivot = a.contents[pivot_row * n + col];
+            if pivot.abs() <= tolerance || !pivot.is_finite() {
+                return Err(MatrixError::Singular);
+            }
+
+            a.swap_rows(col, pivot_row);
+            out.swap_rows(col, pivot_row);
+            for c in 0..n {
+                a.contents[col * n + c] = a.contents[col * n + c] / pivot;
+                out.contents[col * n + c] = out.contents[col * n + c] / pivot;
+            }
+
+            for r in (0..n).filter(|&r| r != col) {
+                let factor = a.contents[r * n + col];
+                if !factor.is_zero() {
+                    a.add_scaled_row(col, r, -factor);
+                    out.add_scaled_row(col, r, -factor);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T: Field> Matrix<T> {
+    /// The exact inverse, by Gauss–Jordan elimination without rounding. Fails with `NotSquare`,
+    /// and with `Singular` only if the matrix really is singular.
+    pub fn inverse_exact(&self) -> Result<Matrix<T>, MatrixError> {
+        self.solve_exact(&Matrix::identity(self.rows))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Rational64, Ratio};
+
+    #[test]
+    fn inverts() {
+        let a: Matrix<f64> = matrix![4.0, 7.0;
+                                     2.0, 6.0];
+
+        let inv = a.inverse().unwrap();
+        assert!(inv.approx_eq(&matrix![0.6, -0.7; -0.2, 0.4], 1e-12, 0.0));
+        assert!((&a * &inv).approx_eq(&Matrix::identity(2), 1e-12, 0.0));
+    }
+
+    #[test]
+    fn needs_pivoting() {
+        let a: Matrix<f64> = matrix![0.0, 1.0, 2.0;
+                                     1.0, 0.0, 3.0;
+                                     4.0, -3.0, 8.0];
+
+        let inv = a.inverse().unwrap();
+        assert!((&inv * &a).approx_eq(&Matrix::identity(3), 1e-12, 0.0));
+    }
+
+    #[test]
+    fn pseudoinverse() {
+        let a: Matrix<f64> = matrix![4.0, 7.0;
+                                     2.0, 6.0];
+        assert!(a.pinv(1e-12).approx_eq(&a.inverse().unwrap(), 1e-12, 0.0));
+
+        let tall: Matrix<f64> = matrix![1.0, 0.0;
+                                        0.0, 1.0;
+                                        1.0, 1.0];
+        let p = tall.pinv(1e-12);
+        assert_eq!(p.shape(), (2, 3).into());
+        assert!((&p * &tall).approx_eq(&Matrix::identity(2), 1e-12, 0.0));
+    }
+
+    #[test]
+    fn pseudoinverse_of_rank_deficient() {
+        let a: Matrix<f64> = matrix![1.0, 2.0;
+                                     2.0, 4.0];
+        let p = a.pinv(1e-12);
+
+        // the Penrose conditions A A⁺ A = A and A⁺ A A⁺ = A⁺
+        assert!((&(&a * &p) * &a).approx_eq(&a, 1e-12, 0.0));
+        assert!((&(&p * &a) * &p).approx_eq(&p, 1e-12, 0.0));
+        assert!(p.approx_eq(&(a.clone() * 0.04), 1e-12, 0.0));
+
+        // a tolerance above every singular value leaves nothing
+        assert_eq!(a.pinv(10.0), Matrix::zeroes(2, 2));
+    }
+
+    #[test]
+    fn rank_one_update() {
+        let a: Matrix<f64> = matrix![4.0, 1.0, 0.0;
+                                     1.0, 3.0, 1.0;
+                                     0.0, 1.0, 2.0];
+        let u = Vector::new(vec![1.0, 0.0, 2.0]);
+        let v = Vector::new(vec![0.5, -1.0, 1.0]);
+
+        let updated = a.inverse().unwrap().sherman_morrison(&u, &v).unwrap();
+        let expected = (&a + &Matrix::outer(&u, &v)).inverse().unwrap();
+        assert!(updated.approx_eq(&expected, 1e-12, 0.0));
+
+        // A + u vᵀ with vᵀ A⁻¹ u = -1 is singular
+        let identity: Matrix<f64> = Matrix::identity(2);
+        let e = Vector::new(vec![1.0, 0.0]);
+        assert_eq!(identity.sherman_morrison(&e, &-e.clone()), Err(MatrixError::Singular));
+    }
+
+    #[test]
+    fn rank_k_update() {
+        let a: Matrix<f64> = Matrix::from_fn(5, 5, |r, c| if r == c { 4.0 } else { 1.0 / (r + c + 1) as f64 });
+        let u: Matrix<f64> = Matrix::from_fn(5, 2, |r, c| (r + 2 * c) as f64 * 0.1);
+        let v: Matrix<f64> = Matrix::from_fn(5, 2, |r, c| 1.0 - (r * c) as f64 * 0.2);
+
+        let updated = a.inverse().unwrap().woodbury(&u, &v).unwrap();
+        let expected = (&a + &(&u * &v.transpose())).inverse().unwrap();
+        assert!(updated.approx_eq(&expected, 1e-12, 0.0));
+
+        assert_eq!(a.woodbury(&u, &Matrix::zeroes(5, 3)),
+            Err(MatrixError::DimensionMismatch { operation: "a Woodbury update", left: (5, 2), right: (5, 3) }));
+        assert_eq!(a.woodbury(&u, &Matrix::zeroes(4, 2)),
+            Err(MatrixError::DimensionMismatch { operation: "a Woodbury update", left: (5, 5), right: (4, 2) }));
+    }
+
+    #[test]
+    fn failures() {
+        let singular: Matrix<f64> = matrix![1.0, 2.0;
+                                            2.0, 4.0];
+
+        assert_eq!(singular.inverse(), Err(MatrixError::Singular));
+        assert_eq!(Matrix::<f64>::zeroes(2, 2).inverse(), Err(MatrixError::Singular));
+        assert_eq!(Matrix::<f64>::zeroes(2, 3).inverse(), Err(MatrixError::NotSquare { rows: 2, columns: 3 }));
+        assert_eq!(Matrix::<f64>::zeroes(0, 0).inverse(), Ok(Matrix::zeroes(0, 0)));
+    }
+
+    #[test]
+    fn reuses_buffer() {
+        let a: Matrix<f64> = matrix![2.0, 0.0;
+                                     0.0, 4.0];
+        let mut out = Matrix::zeroes(5, 5);
+
+        a.try_inverse_into(&mut out).unwrap();
+        assert_eq!(out, matrix![0.5, 0.0; 0.0, 0.25]);
+    }
+
+    #[test]
+    fn exact_inverse() {
+        let a: Matrix<Rational64> = matrix![1, 2, 3;
+                                            4, 5, 6;
+                                            7, 8, 10].map(|&e| Ratio::from(e));
+
+        let inverse = a.inverse_exact().unwrap();
+        assert_eq!(&inverse * &a, Matrix::identity(3));
+        assert_eq!(inverse[(0, 0)], Ratio::new(-2, 3));
+        assert_eq!(format!("{}", inverse[(0, 1)]), "-4/3");
+        assert_eq!(Matrix::<Rational64>::zeroes(2, 3).inverse_exact().unwrap_err(), MatrixError::NotSquare { rows: 2, columns: 3 });
+    }
+}
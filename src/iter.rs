@@ -0,0 +1,297 @@
+//! Ways of walking over the elements of a matrix.
+
+use std::iter::{FromIterator, FusedIterator};
+use std::slice;
+use std::vec;
+
+use crate::{Matrix, MatrixError};
+
+impl<T: Default> Matrix<T> {
+    /// Iterates over all elements in row-major order.
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.contents.iter()
+    }
+
+    /// Iterates mutably over all elements in row-major order.
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
+        self.contents.iter_mut()
+    }
+
+    /// Iterates over the rows, each given as a slice.
+    pub fn rows(&self) -> Rows<'_, T> {
+        Rows { contents: &self.contents, columns: self.columns, front: 0, back: self.rows }
+    }
+
+    /// Iterates over the columns, each given as an iterator over its elements from top to bottom.
+    pub fn columns(&self) -> Columns<'_, T> {
+        Columns { contents: &self.contents, rows: self.rows, columns: self.columns, front: 0, back: self.columns }
+    }
+
+    /// Iterates over all elements in row-major order, together with their `(row, column)` position.
+    pub fn enumerate_indices(&self) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        let columns = self.columns;
+        self.contents.iter().enumerate().map(move |(i, e)| ((i / columns, i % columns), e))
+    }
+}
+
+impl<T: Default> Matrix<T> {
+    /// Builds a matrix of the given shape from the first `rows * columns` elements of an
+    /// iterator, filling it row by row.
+    ///
+    /// Any elements beyond that are left in the iterator, so this works fine with infinite
+    /// iterators. Running out of elements early is an error.
+    pub fn from_iter_with_shape<I: IntoIterator<Item = T>>(rows: usize, columns: usize, iter: I) -> Result<Matrix<T>, MatrixError> {
+        let contents: Vec<T> = iter.into_iter().take(rows * columns).collect();
+        if contents.len() != rows * columns {
+            return Err(MatrixError::ElementCount { expected: rows * columns, found: contents.len() });
+        }
+
+        Ok(Matrix { rows, columns, contents })
+    }
+}
+
+/// Consumes the matrix, yielding its elements in row-major order.
+impl<T: Default> IntoIterator for Matrix<T> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    fn into_iter(self) -> vec::IntoIter<T> {
+        self.contents.into_iter()
+    }
+}
+
+impl<'a, T: Default> IntoIterator for &'a Matrix<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> slice::Iter<'a, T> {
+        self.contents.iter()
+    }
+}
+
+impl<'a, T: Default> IntoIterator for &'a mut Matrix<T> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> slice::IterMut<'a, T> {
+        self.contents.iter_mut()
+    }
+}
+
+/// Collects rows into a matrix. Like `From<Vec<Vec<T>>>`, this panics if the rows
+/// aren't all the same length.
+impl<T: Default> FromIterator<Vec<T>> for Matrix<T> {
+    fn from_iter<I: IntoIterator<Item = Vec<T>>>(iter: I) -> Matrix<T> {
+        Matrix::from(iter.into_iter().collect::<Vec<Vec<T>>>())
+    }
+}
+
+/// Iterator over the rows of a matrix, returned by `Matrix::rows`.
+#[derive(Clone, Debug)]
+pub struct Rows<'a, T> {
+    contents: &'a [T],
+    columns: usize,
+    front: usize,
+    back: usize
+}
+
+impl<'a, T> Iterator for Rows<'a, T> {
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<&'a [T]> {
+        if self.front == self.back {
+            return None;
+        }
+
+        let row = &self.contents[self.front * self.columns..(self.front + 1) * self.columns];
+        self.front += 1;
+        Some(row)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Rows<'a, T> {
+    fn next_back(&mut self) -> Option<&'a [T]> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        Some(&self.contents[self.back * self.columns..(self.back + 1) * self.columns])
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Rows<'a, T> {}
+impl<'a, T> FusedIterator for Rows<'a, T> {}
+
+/// Iterator over the columns of a matrix, returned by `Matrix::columns`.
+#[derive(Clone, Debug)]
+pub struct Columns<'a, T> {
+    contents: &'a [T],
+    rows: usize,
+    columns: usize,
+    front: usize,
+    back: usize
+}
+
+impl<'a, T> Columns<'a, T> {
+    fn column(&self, j: usize) -> Column<'a, T> {
+        Column { contents: self.contents, stride: self.columns, next: j, remaining: self.rows }
+    }
+}
+
+impl<'a, T> Iterator for Columns<'a, T> {
+    type Item = Column<'a, T>;
+
+    fn next(&mut self) -> Option<Column<'a, T>> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.front += 1;
+        Some(self.column(self.front - 1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Columns<'a, T> {
+    fn next_back(&mut self) -> Option<Column<'a, T>> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        Some(self.column(self.back))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Columns<'a, T> {}
+impl<'a, T> FusedIterator for Columns<'a, T> {}
+
+/// Iterator over the elements of a single column, from top to bottom.
+#[derive(Clone, Debug)]
+pub struct Column<'a, T> {
+    contents: &'a [T],
+    stride: usize,
+    next: usize,
+    remaining: usize
+}
+
+impl<'a, T> Iterator for Column<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let e = &self.contents[self.next];
+        self.next += self.stride;
+        self.remaining -= 1;
+        Some(e)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Column<'a, T> {}
+impl<'a, T> FusedIterator for Column<'a, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn element_iterators() {
+        let mut m: Matrix<i32> = matrix![1, 2;
+                                         3, 4];
+
+        assert_eq!(m.iter().sum::<i32>(), 10);
+
+        for e in m.iter_mut() {
+            *e *= 2;
+        }
+        assert_eq!(m.contents, vec![2, 4, 6, 8]);
+    }
+
+    #[test]
+    fn row_iterator() {
+        let m: Matrix<i32> = matrix![1, 2, 3;
+                                     4, 5, 6];
+
+        let rows: Vec<&[i32]> = m.rows().collect();
+        assert_eq!(rows, vec![&[1, 2, 3][..], &[4, 5, 6][..]]);
+        assert_eq!(m.rows().next_back(), Some(&[4, 5, 6][..]));
+        assert_eq!(m.rows().len(), 2);
+    }
+
+    #[test]
+    fn column_iterator() {
+        let m: Matrix<i32> = matrix![1, 2, 3;
+                                     4, 5, 6];
+
+        let columns: Vec<Vec<i32>> = m.columns().map(|c| c.copied().collect()).collect();
+        assert_eq!(columns, vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+        assert_eq!(m.columns().next_back().map(|c| c.sum::<i32>()), Some(9));
+    }
+
+    #[test]
+    fn degenerate_shapes() {
+        let wide: Matrix<i32> = Matrix::zeroes(0, 3);
+        let tall: Matrix<i32> = Matrix::zeroes(3, 0);
+
+        assert_eq!(wide.rows().count(), 0);
+        assert_eq!(wide.columns().map(|c| c.count()).collect::<Vec<_>>(), vec![0, 0, 0]);
+        assert_eq!(tall.rows().map(|r| r.len()).collect::<Vec<_>>(), vec![0, 0, 0]);
+        assert_eq!(tall.columns().count(), 0);
+    }
+
+    #[test]
+    fn into_iterator() {
+        let mut m: Matrix<i32> = matrix![1, 2;
+                                         3, 4];
+
+        for e in &mut m {
+            *e += 1;
+        }
+        assert_eq!((&m).into_iter().max(), Some(&5));
+        assert_eq!(m.into_iter().collect::<Vec<_>>(), vec![2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn with_shape() {
+        let m: Matrix<usize> = Matrix::from_iter_with_shape(2, 3, 0..).unwrap();
+        assert_eq!(m.shape(), (2, 3).into());
+        assert_eq!(m.contents, vec![0, 1, 2, 3, 4, 5]);
+
+        let short: Result<Matrix<usize>, MatrixError> = Matrix::from_iter_with_shape(2, 2, 0..3);
+        assert_eq!(short.err(), Some(MatrixError::ElementCount { expected: 4, found: 3 }));
+    }
+
+    #[test]
+    fn collect_rows() {
+        let m: Matrix<i32> = (1..4).map(|i| vec![i, i * i]).collect();
+
+        assert_eq!(m.shape(), (3, 2).into());
+        assert_eq!(m.contents, vec![1, 1, 2, 4, 3, 9]);
+    }
+
+    #[test]
+    fn indices() {
+        let m: Matrix<char> = matrix!['a', 'b';
+                                      'c', 'd'];
+
+        let found: Vec<((usize, usize), char)> = m.enumerate_indices().map(|(i, &e)| (i, e)).collect();
+        assert_eq!(found, vec![((0, 0), 'a'), ((0, 1), 'b'), ((1, 0), 'c'), ((1, 1), 'd')]);
+    }
+}
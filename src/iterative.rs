@@ -0,0 +1,781 @@
+//! Iterative solvers and eigenvalue methods, which only ever multiply by the matrix and so never
+//! need to factorize it.
+
+use crate::{Complex, Matrix, MatrixError, Real, Vector, Zero};
+
+/// The result of an iterative solver: the solution, and how the solver got there.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IterativeSolution<T> {
+    /// The approximate solution `x`.
+    pub solution: Vector<T>,
+    /// The number of iterations taken.
+    pub iterations: usize,
+    /// The norm of the residual `b - A x`, before the first iteration and after every one.
+    pub residuals: Vec<T>
+}
+
+/// Some eigenvalues, and unit eigenvectors in the same order.
+pub type Eigenpairs<T> = (Vec<T>, Vec<Vector<T>>);
+
+impl<T: Real> Matrix<T> {
+    /// Solves `A x = b` for a symmetric positive definite `A` with the conjugate gradient method.
+    ///
+    /// Starting from zero, this stops as soon as the residual norm drops to `tolerance` times
+    /// the norm of `b`. Fails with `NoConvergence` if that takes more than `max_iterations`,
+    /// which is also what happens when `A` isn't positive definite, and with `NotSquare` or
+    /// `DimensionMismatch` for the wrong shapes.
+    pub fn solve_cg(&self, b: &Vector<T>, tolerance: T, max_iterations: usize) -> Result<IterativeSolution<T>, MatrixError> {
+        self.solve_pcg(b, tolerance, max_iterations, |r| r.clone())
+    }
+
+    /// The preconditioned conjugate gradient method. `preconditioner` applies `M⁻¹` to a
+    /// residual, where `M` is a symmetric positive definite approximation of `A` that is cheap to
+    /// solve with; the closer, the fewer iterations. The Jacobi preconditioner, for one, divides
+    /// by the diagonal of `A`. Otherwise this is just like `solve_cg`.
+    pub fn solve_pcg<F>(&self, b: &Vector<T>, tolerance: T, max_iterations: usize, preconditioner: F)
+        -> Result<IterativeSolution<T>, MatrixError> where F: FnMut(&Vector<T>) -> Vector<T> {
+        check_system(self, b)?;
+        conjugate_gradient(|v| self.apply(v.as_slice()), b, tolerance, max_iterations, preconditioner)
+    }
+
+    /// Solves `A x = b` for a general square `A` with restarted GMRES; see `gmres`.
+    pub fn solve_gmres(&self, b: &Vector<T>, restart: usize, tolerance: T, max_iterations: usize)
+        -> Result<IterativeSolution<T>, MatrixError> {
+        check_system(self, b)?;
+        gmres(|v| self.apply(v.as_slice()), b, restart, tolerance, max_iterations)
+    }
+
+    /// Solves `A x = b` for a general square `A` with BiCGSTAB; see `bicgstab`.
+    pub fn solve_bicgstab(&self, b: &Vector<T>, tolerance: T, max_iterations: usize)
+        -> Result<IterativeSolution<T>, MatrixError> {
+        check_system(self, b)?;
+        bicgstab(|v| self.apply(v.as_slice()), b, tolerance, max_iterations)
+    }
+}
+
+impl<T: Real> Matrix<T> {
+    /// The eigenvalue of largest magnitude and a unit eigenvector for it, by power iteration.
+    ///
+    /// See `power_iteration`. This is all that e.g. PageRank needs, at a fraction of the cost of
+    /// a full eigendecomposition. Fails with `NotSquare`, with `Empty` for a 0×0 matrix, or
+    /// with `NoConvergence`.
+    pub fn dominant_eigenpair(&self, tolerance: T, max_iterations: usize) -> Result<(T, Vector<T>), MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare { rows: self.rows, columns: self.columns });
+        }
+        power_iteration(|v| self.apply(v.as_slice()), self.rows, tolerance, max_iterations)
+    }
+
+    /// The eigenvalue of smallest magnitude and a unit eigenvector for it, by inverse iteration
+    /// with an LU decomposition. See `inverse_iteration`. Fails with `NotSquare`, with
+    /// `Singular` (in which case zero is an eigenvalue, and `null_space` has its eigenvectors),
+    /// with `Empty` for a 0×0 matrix, or with `NoConvergence`.
+    pub fn smallest_eigenpair(&self, tolerance: T, max_iterations: usize) -> Result<(T, Vector<T>), MatrixError> {
+        let lu = self.lu()?;
+        if lu.is_singular() {
+            return Err(MatrixError::Singular);
+        }
+        inverse_iteration(|v| lu.solve(v), self.rows, tolerance, max_iterations)
+    }
+}
+
+/// Power iteration for the eigenvalue of largest magnitude of an n×n operator, given by
+/// `matvec`, which computes `A v`, together with a unit eigenvector.
+///
+/// The vector is multiplied by `A` and normalised over and over, which makes it turn towards
+/// the dominant eigenvector, at a speed that depends on how much larger the dominant eigenvalue
+/// is than the next one. The estimate of the eigenvalue is the Rayleigh quotient `λ = vᵀ A v`,
+/// and the iteration stops once the residual `|A v - λ v|` is at most `tolerance` times `|λ|`.
+/// Fails with `NoConvergence` after `max_iterations` multiplications, which also happens if the
+/// dominant eigenvalue isn't unique, e.g. for a complex conjugate pair, and with `Empty` if `n`
+/// is zero, since there are no eigenvalues then.
+pub fn power_iteration<T, A>(mut matvec: A, n: usize, tolerance: T, max_iterations: usize)
+    -> Result<(T, Vector<T>), MatrixError> where
+    T: Real,
+    A: FnMut(&Vector<T>) -> Vector<T> {
+    if n == 0 {
+        return Err(MatrixError::Empty { operation: "power iteration" });
+    }
+    let mut v = start_vector(n);
+    for _ in 0..max_iterations {
+        let w = matvec(&v);
+        let lambda = v.dot(&w);
+        let residual = (&w - &(v.clone() * lambda)).norm();
+        if residual <= tolerance * lambda.abs() || w.norm().is_zero() {
+            return Ok((lambda, v));
+        }
+
+        v = w;
+        v.normalize();
+    }
+    Err(MatrixError::NoConvergence { iterations: max_iterations })
+}
+
+/// Inverse iteration for the eigenvalue of smallest magnitude of an n×n operator, together with
+/// a unit eigenvector. `solve` solves `A x = v` for `x`, e.g. with a decomposition that was
+/// computed once up front, or one of the iterative solvers.
+///
+/// This is power iteration on `A⁻¹`, whose dominant eigenvalue is the reciprocal of the
+/// smallest one of `A`, and fails in the same ways; errors returned by `solve` are passed on.
+pub fn inverse_iteration<T, S>(mut solve: S, n: usize, tolerance: T, max_iterations: usize)
+    -> Result<(T, Vector<T>), MatrixError> where
+    T: Real,
+    S: FnMut(&Vector<T>) -> Result<Vector<T>, MatrixError> {
+    if n == 0 {
+        return Err(MatrixError::Empty { operation: "inverse iteration" });
+    }
+    let mut error = None;
+    let result = power_iteration(|v| solve(v).unwrap_or_else(|e| {
+        error = Some(e);
+        Vector::zeroes(n)
+    }), n, tolerance, max_iterations);
+
+    match (error, result) {
+        (Some(e), _) => Err(e),
+        (None, Ok((mu, v))) => Ok((T::one() / mu, v)),
+        (None, Err(e)) => Err(e)
+    }
+}
+
+impl<T: Real> Matrix<T> {
+    /// The `k` eigenvalues of largest magnitude of a symmetric matrix, and unit eigenvectors
+    /// for them, by the Lanczos method. See `lanczos`; the tolerance here is the square root of
+    /// the machine epsilon. Fails with `NotSquare`.
+    pub fn lanczos(&self, k: usize) -> Result<Eigenpairs<T>, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare { rows: self.rows, columns: self.columns });
+        }
+        lanczos(|v| self.apply(v.as_slice()), self.rows, k, T::epsilon().sqrt())
+    }
+
+    /// The `k` eigenvalues of largest magnitude of a general square matrix, and unit
+    /// eigenvectors for them, by the Arnoldi method. See `arnoldi`; the tolerance here is the
+    /// square root of the machine epsilon. Fails with `NotSquare`.
+    pub fn arnoldi(&self, k: usize) -> Result<Eigenpairs<Complex<T>>, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare { rows: self.rows, columns: self.columns });
+        }
+        arnoldi(|v| self.apply(v.as_slice()), self.rows, k, T::epsilon().sqrt())
+    }
+}
+
+/// The `k` eigenvalues of largest magnitude of a symmetric n×n operator, given by `matvec`,
+/// which computes `A v`, together with unit eigenvectors for them.
+///
+/// The Lanczos method builds an orthonormal basis of the Krylov space spanned by `v, A v, A² v,
+/// ...` one vector at a time, in which `A` is tridiagonal. The eigenpairs of that small
+/// tridiagonal matrix (the Ritz pairs) approximate the extreme eigenpairs of `A` long before the
+/// basis is complete. The basis is grown until the residual `|A x - θ x|` of each of the `k`
+/// Ritz pairs is at most `tolerance` times the largest `|θ|`; with exact arithmetic that's
+/// certain after n steps. The basis is kept orthogonal explicitly, which costs O(n) memory per
+/// step but avoids the spurious copies of eigenvalues the plain method is known for.
+///
+/// The eigenvalues come sorted by decreasing magnitude. This panics if `k` exceeds `n`.
+pub fn lanczos<T, A>(mut matvec: A, n: usize, k: usize, tolerance: T) -> Result<Eigenpairs<T>, MatrixError> where
+    T: Real,
+    A: FnMut(&Vector<T>) -> Vector<T> {
+    assert!(k <= n, "Can't find {} eigenvalues of an operator on vectors of length {}.", k, n);
+    let mut basis = vec![start_vector(n)];
+    let mut alphas: Vec<T> = Vec::new();
+    let mut betas: Vec<T> = Vec::new();
+
+    loop {
+        let j = alphas.len();
+        let mut w = matvec(&basis[j]);
+        let size = w.norm();
+        let alpha = w.dot(&basis[j]);
+        orthogonalize(&mut w, &basis);
+        let beta = w.norm();
+        alphas.push(alpha);
+
+        let m = alphas.len();
+        if m >= k {
+            let t = Matrix::from_fn(m, m, |r, c| {
+                if r == c { alphas[r] } else if r == c + 1 { betas.get(c).copied().unwrap_or_else(T::zero) } else { T::zero() }
+            });
+            let eigen = t.symmetric_eigen()?;
+            let order = by_magnitude(eigen.eigenvalues(), |&x| x.abs());
+            let largest = order.first().map_or(T::zero(), |&i| eigen.eigenvalues()[i].abs());
+            let selected = &order[..k];
+
+            let s = eigen.eigenvectors();
+            let converged = selected.iter().all(|&i| (beta * s[(m - 1, i)]).abs() <= tolerance * largest);
+            if converged || m == n {
+                let values = selected.iter().map(|&i| eigen.eigenvalues()[i]).collect();
+                let vectors = selected.iter().map(|&i| {
+                    let mut x = Vector::zeroes(n);
+                    for (r, v) in basis.iter().enumerate().take(m) {
+                        x = x + v.clone() * s[(r, i)];
+                    }
+                    x.normalize();
+                    x
+                }).collect();
+                return Ok((values, vectors));
+            }
+        }
+
+        let (next, beta) = next_basis_vector(w, beta, size, &basis);
+        betas.push(beta);
+        basis.push(next);
+    }
+}
+
+/// The `k` eigenvalues of largest magnitude of a general n×n operator, given by `matvec`,
+/// which computes `A v`, together with unit eigenvectors for them.
+///
+/// This is the Arnoldi method, the general counterpart of `lanczos`: in the orthonormal Krylov
+/// basis, `A` is upper Hessenberg instead of tridiagonal. Its eigenvalues, and so the results,
+/// may be complex, in which case they come in conjugate pairs. They are sorted by decreasing
+/// magnitude, and the iteration stops under the same condition as in `lanczos`. This panics if
+/// `k` exceeds `n`.
+pub fn arnoldi<T, A>(mut matvec: A, n: usize, k: usize, tolerance: T) -> Result<Eigenpairs<Complex<T>>, MatrixError> where
+    T: Real,
+    A: FnMut(&Vector<T>) -> Vector<T> {
+    assert!(k <= n, "Can't find {} eigenvalues of an operator on vectors of length {}.", k, n);
+    let mut basis = vec![start_vector(n)];
+    // the columns of the Hessenberg matrix, each one element longer than the one before
+    let mut h: Vec<Vec<T>> = Vec::new();
+
+    loop {
+        let j = h.len();
+        let mut w = matvec(&basis[j]);
+        let size = w.norm();
+        let mut column = vec![T::zero(); basis.len()];
+        for _ in 0..2 {
+            for (c, v) in column.iter_mut().zip(&basis) {
+                *c = *c + orthogonalize_against(&mut w, v);
+            }
+        }
+        let beta = w.norm();
+        h.push(column);
+
+        let m = h.len();
+        if m >= k {
+            let hm = Matrix::from_fn(m, m, |r, c| h[c].get(r).copied().unwrap_or_else(T::zero));
+            let thetas = hm.eigenvalues()?;
+            let order = by_magnitude(&thetas, |z| z.norm());
+            let largest = order.first().map_or(T::zero(), |&i| thetas[i].norm());
+            let selected: Vec<(Complex<T>, Vec<Complex<T>>)> = order[..k].iter()
+                .map(|&i| (thetas[i], hessenberg_eigenvector(&hm, thetas[i])))
+                .collect();
+
+            let converged = selected.iter().all(|(_, y)| beta * y[m - 1].norm() <= tolerance * largest);
+            if converged || m == n {
+                let values = selected.iter().map(|(theta, _)| *theta).collect();
+                let vectors = selected.into_iter().map(|(_, y)| {
+                    let x: Vec<Complex<T>> = (0..n).map(|r| {
+                        basis.iter().zip(&y).fold(Complex::zero(), |acc, (v, &yi)| acc + yi * Complex::from(v[r]))
+                    }).collect();
+                    let length = x.iter().fold(T::zero(), |acc, z| acc.hypot(z.norm()));
+                    x.into_iter().map(|z| z / Complex::from(length)).collect()
+                }).collect();
+                return Ok((values, vectors));
+            }
+        }
+
+        let (next, beta) = next_basis_vector(w, beta, size, &basis);
+        h[j].push(beta);
+        basis.push(next);
+    }
+}
+
+// a fixed but irregular unit vector, which is very unlikely to be orthogonal to what we're after
+pub(crate) fn start_vector<T: Real>(n: usize) -> Vector<T> {
+    let mut v: Vector<T> = (0..n).map(|i| T::from_f64(1.0 + (i as f64 * 0.618_033_988_75).fract())).collect();
+    v.normalize();
+    v
+}
+
+// removes the component along v, returning its size
+fn orthogonalize_against<T: Real>(w: &mut Vector<T>, v: &Vector<T>) -> T {
+    let coefficient = w.dot(v);
+    for (x, &y) in w.iter_mut().zip(v.iter()) {
+        *x = *x - coefficient * y;
+    }
+    coefficient
+}
+
+// makes w orthogonal to the whole basis; twice, since once isn't enough in floating point
+fn orthogonalize<T: Real>(w: &mut Vector<T>, basis: &[Vector<T>]) {
+    for _ in 0..2 {
+        for v in basis {
+            orthogonalize_against(w, v);
+        }
+    }
+}
+
+// the next Krylov basis vector from the orthogonalized w = A v of norm beta (size before
+// orthogonalizing), and the coupling to it. If the Krylov space has run out, i.e. is invariant
+// under A, the coupling is zero and the search continues in a fresh direction
+fn next_basis_vector<T: Real>(w: Vector<T>, beta: T, size: T, basis: &[Vector<T>]) -> (Vector<T>, T) {
+    let n = w.len();
+    if beta > size * T::epsilon() * T::from_f64(n as f64) {
+        return (w * (T::one() / beta), beta);
+    }
+
+    for i in 0..n {
+        let mut e = Vector::zeroes(n);
+        e[i] = T::one();
+        orthogonalize(&mut e, basis);
+        if e.norm() > T::from_f64(0.5) {
+            e.normalize();
+            return (e, T::zero());
+        }
+    }
+    unreachable!("Every vector is in the span of fewer than n basis vectors.")
+}
+
+// the indices of the values, by decreasing magnitude
+fn by_magnitude<V, F: Fn(&V) -> R, R: Real>(values: &[V], magnitude: F) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by(|&a, &b| magnitude(&values[b]).partial_cmp(&magnitude(&values[a])).unwrap_or(std::cmp::Ordering::Equal));
+    order
+}
+
+// a unit eigenvector of the (small) matrix h for its eigenvalue theta, by two steps of complex
+// inverse iteration. The shift is exact, so tiny pivots are bumped to keep it solvable
+fn hessenberg_eigenvector<T: Real>(h: &Matrix<T>, theta: Complex<T>) -> Vec<Complex<T>> {
+    let m = h.rows;
+    let norm = h.norm(crate::NormKind::One);
+    let scale = if norm > T::one() { norm * T::epsilon() } else { T::epsilon() };
+    let mut a: Vec<Vec<Complex<T>>> = (0..m).map(|r| (0..m).map(|c| {
+        let e = Complex::from(h[(r, c)]);
+        if r == c { e - theta } else { e }
+    }).collect()).collect();
+
+    // LU with partial pivoting, in place
+    let mut perm: Vec<usize> = (0..m).collect();
+    for k in 0..m {
+        let p = (k..m).fold(k, |best, r| if a[r][k].norm() > a[best][k].norm() { r } else { best });
+        a.swap(k, p);
+        perm.swap(k, p);
+        if a[k][k].norm() < scale {
+            a[k][k] = Complex::from(scale);
+        }
+        let (top, bottom) = a.split_at_mut(k + 1);
+        let pivot_row = &top[k];
+        for row in bottom {
+            let factor = row[k] / pivot_row[k];
+            row[k] = factor;
+            for (x, &y) in row[k + 1..].iter_mut().zip(&pivot_row[k + 1..]) {
+                *x = *x - factor * y;
+            }
+        }
+    }
+
+    let mut y = vec![Complex::from(T::one()); m];
+    for _ in 0..2 {
+        let mut x: Vec<Complex<T>> = perm.iter().map(|&i| y[i]).collect();
+        for r in 0..m {
+            for c in 0..r {
+                let update = a[r][c] * x[c];
+                x[r] = x[r] - update;
+            }
+        }
+        for r in (0..m).rev() {
+            for c in r + 1..m {
+                let update = a[r][c] * x[c];
+                x[r] = x[r] - update;
+            }
+            x[r] = x[r] / a[r][r];
+        }
+        let length = x.iter().fold(T::zero(), |acc, z| acc.hypot(z.norm()));
+        y = x.into_iter().map(|z| z / Complex::from(length)).collect();
+    }
+    y
+}
+
+/// The preconditioned conjugate gradient method for a symmetric positive definite operator,
+/// given by `matvec`, which computes `A v`. See `Matrix::solve_pcg`.
+pub fn conjugate_gradient<T, A, P>(mut matvec: A, b: &Vector<T>, tolerance: T, max_iterations: usize, mut preconditioner: P)
+    -> Result<IterativeSolution<T>, MatrixError> where
+    T: Real,
+    A: FnMut(&Vector<T>) -> Vector<T>,
+    P: FnMut(&Vector<T>) -> Vector<T> {
+    let target = tolerance * b.norm();
+    let mut x = Vector::zeroes(b.len());
+    let mut r = b.clone();
+    let mut z = preconditioner(&r);
+    let mut p = z.clone();
+    let mut rz = r.dot(&z);
+    let mut residuals = vec![r.norm()];
+
+    for iteration in 1..=max_iterations {
+        if residuals[residuals.len() - 1] <= target {
+            return Ok(IterativeSolution { solution: x, iterations: iteration - 1, residuals });
+        }
+
+        let ap = matvec(&p);
+        let alpha = rz / p.dot(&ap);
+        x = x + p.clone() * alpha;
+        r = r - ap * alpha;
+        residuals.push(r.norm());
+
+        z = preconditioner(&r);
+        let next_rz = r.dot(&z);
+        p = z + p * (next_rz / rz);
+        rz = next_rz;
+    }
+
+    finish(x, max_iterations, residuals, target)
+}
+
+/// Restarted GMRES(m) for a general linear operator, given by `matvec`, which computes `A v`.
+///
+/// Every cycle builds an orthonormal basis of up to `restart` Krylov vectors and picks the
+/// combination of them that minimises the residual, so the residual never grows; after
+/// `restart` steps, the basis is thrown away to bound the memory, and the next cycle starts
+/// from the current solution. Larger `restart`s converge in fewer iterations. Starting from
+/// zero, this stops once the residual norm is at most `tolerance` times the norm of `b`, and
+/// fails with `NoConvergence` after `max_iterations` matrix-vector products.
+pub fn gmres<T, A>(mut matvec: A, b: &Vector<T>, restart: usize, tolerance: T, max_iterations: usize)
+    -> Result<IterativeSolution<T>, MatrixError> where
+    T: Real,
+    A: FnMut(&Vector<T>) -> Vector<T> {
+    assert!(restart > 0, "GMRES needs to restart after at least one step.");
+    let target = tolerance * b.norm();
+    let mut x = Vector::zeroes(b.len());
+    let mut r = b.clone();
+    let mut residuals = vec![r.norm()];
+    let mut iterations = 0;
+
+    while residuals[residuals.len() - 1] > target && iterations < max_iterations {
+        let beta = r.norm();
+        let mut basis = vec![r * (T::one() / beta)];
+        // the Hessenberg matrix of the Arnoldi process, column by column, turned upper
+        // triangular by Givens rotations as it grows
+        let mut h: Vec<Vec<T>> = Vec::new();
+        let mut rotations: Vec<(T, T)> = Vec::new();
+        let mut g = vec![beta];
+
+        for j in 0..restart {
+            let mut w = matvec(&basis[j]);
+            iterations += 1;
+
+            let mut column = Vec::with_capacity(j + 2);
+            for v in &basis {
+                let coefficient = w.dot(v);
+                w = w - v.clone() * coefficient;
+                column.push(coefficient);
+            }
+            let next_norm = w.norm();
+            column.push(next_norm);
+
+            for (i, &(c, s)) in rotations.iter().enumerate() {
+                let (a, b) = (column[i], column[i + 1]);
+                column[i] = c * a + s * b;
+                column[i + 1] = c * b - s * a;
+            }
+            let length = column[j].hypot(column[j + 1]);
+            let (c, s) = if length.is_zero() { (T::one(), T::zero()) } else { (column[j] / length, column[j + 1] / length) };
+            column[j] = length;
+            column[j + 1] = T::zero();
+            rotations.push((c, s));
+            g.push(-s * g[j]);
+            g[j] = c * g[j];
+
+            h.push(column);
+            residuals.push(g[j + 1].abs());
+            if g[j + 1].abs() <= target || iterations == max_iterations || next_norm.is_zero() {
+                break;
+            }
+            basis.push(w * (T::one() / next_norm));
+        }
+
+        // the least squares problem is now the triangular system R y = g
+        let k = h.len();
+        let mut y = vec![T::zero(); k];
+        for i in (0..k).rev() {
+            let sum = (i + 1..k).fold(g[i], |acc, j| acc - h[j][i] * y[j]);
+            y[i] = if h[i][i].is_zero() { T::zero() } else { sum / h[i][i] };
+        }
+        for (v, &coefficient) in basis.iter().zip(&y) {
+            x = x + v.clone() * coefficient;
+        }
+
+        // the true residual, which rounding may have made drift from the estimate
+        r = b - &matvec(&x);
+        let last = residuals.len() - 1;
+        residuals[last] = r.norm();
+        if h[k - 1][k - 1].is_zero() {
+            break;
+        }
+    }
+
+    finish(x, iterations, residuals, target)
+}
+
+/// BiCGSTAB, the stabilised biconjugate gradient method, for a general linear operator, given
+/// by `matvec`, which computes `A v`.
+///
+/// Unlike GMRES, it needs a fixed amount of memory, but its residual may go up and down along
+/// the way, and it can break down. Every iteration takes two matrix-vector products. Starting
+/// from zero, this stops once the residual norm is at most `tolerance` times the norm of `b`, and
+/// fails with `NoConvergence` after `max_iterations` iterations or on a breakdown.
+pub fn bicgstab<T, A>(mut matvec: A, b: &Vector<T>, tolerance: T, max_iterations: usize)
+    -> Result<IterativeSolution<T>, MatrixError> where
+    T: Real,
+    A: FnMut(&Vector<T>) -> Vector<T> {
+    let target = tolerance * b.norm();
+    let mut x = Vector::zeroes(b.len());
+    let mut r = b.clone();
+    let shadow = r.clone();
+    let (mut rho, mut alpha, mut omega) = (T::one(), T::one(), T::one());
+    let mut v = Vector::zeroes(b.len());
+    let mut p = Vector::zeroes(b.len());
+    let mut residuals = vec![r.norm()];
+
+    for iteration in 1..=max_iterations {
+        if residuals[residuals.len() - 1] <= target {
+            return Ok(IterativeSolution { solution: x, iterations: iteration - 1, residuals });
+        }
+
+        let next_rho = shadow.dot(&r);
+        if next_rho.is_zero() || omega.is_zero() {
+            return Err(MatrixError::NoConvergence { iterations: iteration - 1 });
+        }
+        let beta = (next_rho / rho) * (alpha / omega);
+        p = r.clone() + (p - v.clone() * omega) * beta;
+        v = matvec(&p);
+        alpha = next_rho / shadow.dot(&v);
+        let s = r - v.clone() * alpha;
+        if s.norm() <= target {
+            x = x + p * alpha;
+            residuals.push(s.norm());
+            return Ok(IterativeSolution { solution: x, iterations: iteration, residuals });
+        }
+
+        let t = matvec(&s);
+        omega = t.dot(&s) / t.dot(&t);
+        x = x + p.clone() * alpha + s.clone() * omega;
+        r = s - t * omega;
+        rho = next_rho;
+        residuals.push(r.norm());
+    }
+
+    finish(x, max_iterations, residuals, target)
+}
+
+fn finish<T: Real>(solution: Vector<T>, iterations: usize, residuals: Vec<T>, target: T) -> Result<IterativeSolution<T>, MatrixError> {
+    if residuals[residuals.len() - 1] <= target {
+        Ok(IterativeSolution { solution, iterations, residuals })
+    } else {
+        Err(MatrixError::NoConvergence { iterations })
+    }
+}
+
+// iterative solvers need a square matrix and a right hand side that fits it
+fn check_system<T: Real>(a: &Matrix<T>, b: &Vector<T>) -> Result<(), MatrixError> {
+    if !a.is_square() {
+        return Err(MatrixError::NotSquare { rows: a.rows, columns: a.columns });
+    }
+    if b.len() != a.rows {
+        return Err(MatrixError::DimensionMismatch { operation: "solving", left: (a.rows, a.columns), right: (b.len(), 1) });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn laplacian(n: usize) -> Matrix<f64> {
+        Matrix::from_fn(n, n, |i, j| if i == j { 2.0 } else if i + 1 == j || j + 1 == i { -1.0 } else { 0.0 })
+    }
+
+    #[test]
+    fn conjugate_gradient() {
+        let a = laplacian(20);
+        let b = Vector::new((0..20).map(|i| (i % 3) as f64).collect());
+
+        let result = a.solve_cg(&b, 1e-12, 100).unwrap();
+        let expected = a.solve(&Matrix::from(b.clone())).unwrap();
+        assert!(Matrix::from(result.solution).approx_eq(&expected, 1e-9, 0.0));
+        // in exact arithmetic, CG is done after n steps
+        assert!(result.iterations <= 20);
+        assert_eq!(result.residuals.len(), result.iterations + 1);
+        assert!(result.residuals[result.iterations] <= 1e-12 * b.norm());
+    }
+
+    #[test]
+    fn preconditioning_helps() {
+        // a widely spread diagonal, which the Jacobi preconditioner divides out
+        let l = laplacian(30);
+        let a = Matrix::from_fn(30, 30, |i, j| l[(i, j)] + if i == j { (i * i) as f64 } else { 0.0 });
+        let b = Vector::new(vec![1.0; 30]);
+        let diagonal = a.diagonal();
+
+        let plain = a.solve_cg(&b, 1e-10, 1000).unwrap();
+        let jacobi = a.solve_pcg(&b, 1e-10, 1000, |r| r.iter().zip(&diagonal).map(|(&x, &d)| x / d).collect()).unwrap();
+        assert!(jacobi.iterations < plain.iterations);
+        assert!(Matrix::from(jacobi.solution).approx_eq(&Matrix::from(plain.solution), 1e-6, 1e-6));
+    }
+
+    fn convection_diffusion(n: usize) -> Matrix<f64> {
+        Matrix::from_fn(n, n, |i, j| if i == j { 4.0 } else if i + 1 == j { -1.5 } else if j + 1 == i { -0.5 } else { 0.0 })
+    }
+
+    #[test]
+    fn gmres_nonsymmetric() {
+        let a = convection_diffusion(40);
+        let b = Vector::new((0..40).map(|i| (i as f64).sin()).collect());
+        let expected = a.solve(&Matrix::from(b.clone())).unwrap();
+
+        let full = a.solve_gmres(&b, 40, 1e-12, 200).unwrap();
+        assert!(Matrix::from(full.solution).approx_eq(&expected, 1e-10, 0.0));
+        assert!(full.residuals.windows(2).all(|w| w[1] <= w[0] * (1.0 + 1e-12)));
+
+        let restarted = a.solve_gmres(&b, 5, 1e-12, 500).unwrap();
+        assert!(Matrix::from(restarted.solution).approx_eq(&expected, 1e-10, 0.0));
+        assert!(restarted.iterations >= full.iterations);
+        assert_eq!(restarted.residuals.len(), restarted.iterations + 1);
+    }
+
+    #[test]
+    fn bicgstab_nonsymmetric() {
+        let a = convection_diffusion(40);
+        let b = Vector::new(vec![1.0; 40]);
+        let expected = a.solve(&Matrix::from(b.clone())).unwrap();
+
+        let result = a.solve_bicgstab(&b, 1e-12, 200).unwrap();
+        assert!(Matrix::from(result.solution).approx_eq(&expected, 1e-10, 0.0));
+        assert!(result.residuals[result.residuals.len() - 1] <= 1e-12 * b.norm());
+    }
+
+    #[test]
+    fn matrix_free() {
+        // the operator x ↦ (2 x_i + x_{i+1}) never exists as a matrix
+        let n = 10;
+        let matvec = |v: &Vector<f64>| (0..n).map(|i| 2.0 * v[i] + if i + 1 < n { v[i + 1] } else { 0.0 }).collect();
+        let b = Vector::new(vec![3.0; n]);
+
+        let x = gmres(matvec, &b, 10, 1e-12, 100).unwrap().solution;
+        assert!(matvec(&x).iter().all(|&ax| (ax - 3.0).abs() < 1e-10));
+        let y = bicgstab(matvec, &b, 1e-12, 100).unwrap().solution;
+        assert!(Matrix::from(x).approx_eq(&Matrix::from(y), 1e-9, 0.0));
+    }
+
+    #[test]
+    fn dominant_eigenvalue() {
+        let a: Matrix<f64> = matrix![2.0, 1.0;
+                                     1.0, 3.0];
+        let expected = (5.0 + 5f64.sqrt()) / 2.0;
+
+        let (lambda, v) = a.dominant_eigenpair(1e-12, 1000).unwrap();
+        assert!((lambda - expected).abs() < 1e-10);
+        assert!((v.norm() - 1.0).abs() < 1e-12);
+        assert!(Matrix::from(a.apply(v.as_slice())).approx_eq(&Matrix::from(v * lambda), 1e-10, 0.0));
+
+        // negative and non-symmetric works too
+        let b: Matrix<f64> = matrix![-5.0, 1.0;
+                                     0.0, 2.0];
+        assert!((b.dominant_eigenpair(1e-12, 1000).unwrap().0 + 5.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn smallest_eigenvalue() {
+        let a = laplacian(10);
+        // the eigenvalues of the second difference matrix are 2 - 2 cos(k π / (n + 1))
+        let expected = 2.0 - 2.0 * (std::f64::consts::PI / 11.0).cos();
+
+        let (lambda, v) = a.smallest_eigenpair(1e-12, 1000).unwrap();
+        assert!((lambda - expected).abs() < 1e-10);
+        assert!(v.iter().all(|&x| x > 0.0) || v.iter().all(|&x| x < 0.0));
+
+        // the same through CG as the solver
+        let (lambda, _) = inverse_iteration(|v| a.solve_cg(v, 1e-14, 100).map(|s| s.solution), 10, 1e-10, 1000).unwrap();
+        assert!((lambda - expected).abs() < 1e-8);
+    }
+
+    #[test]
+    fn eigenpair_failures() {
+        // a rotation has no real dominant eigenvalue
+        let rotation: Matrix<f64> = matrix![0.0, -1.0;
+                                            1.0, 0.0];
+        assert_eq!(rotation.dominant_eigenpair(1e-12, 50), Err(MatrixError::NoConvergence { iterations: 50 }));
+        assert_eq!(Matrix::<f64>::zeroes(2, 2).smallest_eigenpair(1e-12, 50), Err(MatrixError::Singular));
+        assert_eq!(inverse_iteration(|_| Err(MatrixError::Singular), 2, 1e-12, 50), Err::<(f64, _), _>(MatrixError::Singular));
+
+        // a 0×0 matrix has no eigenvalues at all
+        let empty: Matrix<f64> = Matrix::zeroes(0, 0);
+        assert_eq!(empty.dominant_eigenpair(1e-12, 50), Err(MatrixError::Empty { operation: "power iteration" }));
+        assert_eq!(empty.smallest_eigenpair(1e-12, 50), Err(MatrixError::Empty { operation: "inverse iteration" }));
+    }
+
+    #[test]
+    fn lanczos_extremes() {
+        let a = laplacian(30);
+        let eigenvalue = |k: usize| 2.0 - 2.0 * (k as f64 * std::f64::consts::PI / 31.0).cos();
+
+        let (values, vectors) = a.lanczos(3).unwrap();
+        for (i, (&lambda, x)) in values.iter().zip(&vectors).enumerate() {
+            assert!((lambda - eigenvalue(30 - i)).abs() < 1e-10);
+            assert!((x.norm() - 1.0).abs() < 1e-12);
+            assert!(Matrix::from(a.apply(x.as_slice())).approx_eq(&Matrix::from(x.clone() * lambda), 1e-6, 0.0));
+        }
+    }
+
+    #[test]
+    fn lanczos_dense() {
+        let n = 80;
+        let a: Matrix<f64> = Matrix::from_fn(n, n, |r, c| {
+            let (i, j) = (r.min(c) as f64, r.max(c) as f64);
+            (i * 12.9898 + j * 78.233).sin() * 43758.5453 % 1.0
+        });
+
+        let mut expected = a.symmetric_eigen().unwrap().eigenvalues().to_vec();
+        expected.sort_by(|x, y| y.abs().partial_cmp(&x.abs()).unwrap());
+        let (values, _) = a.lanczos(3).unwrap();
+        for (lambda, e) in values.iter().zip(&expected) {
+            assert!((lambda - e).abs() < 1e-8);
+        }
+    }
+
+    #[test]
+    fn lanczos_restarts_in_invariant_subspaces() {
+        // the start vector only sees the first block, so the second has to be found separately
+        let a: Matrix<f64> = matrix![2.0, 0.0, 0.0;
+                                     0.0, 2.0, 0.0;
+                                     0.0, 0.0, -7.0];
+        let (values, _) = a.lanczos(2).unwrap();
+        assert!((values[0] + 7.0).abs() < 1e-12 && (values[1] - 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn arnoldi_extremes() {
+        // a rotation by 90 degrees scaled by 3 dominates a small upper triangular part
+        let a: Matrix<f64> = matrix![0.0, -3.0, 1.0, 0.0;
+                                     3.0, 0.0, 0.0, 1.0;
+                                     0.0, 0.0, 1.0, 0.5;
+                                     0.0, 0.0, 0.0, -0.5];
+
+        let (values, vectors) = a.arnoldi(2).unwrap();
+        assert!((values[0].norm() - 3.0).abs() < 1e-10 && values[0].re.abs() < 1e-10);
+        assert!((values[0].im + values[1].im).abs() < 1e-10);
+
+        // A x = λ x, with complex arithmetic
+        for (&lambda, x) in values.iter().zip(&vectors) {
+            for r in 0..4 {
+                let ax = (0..4).fold(Complex::zero(), |acc, c| acc + Complex::from(a[(r, c)]) * x[c]);
+                assert!((ax - lambda * x[r]).norm() < 1e-8);
+            }
+        }
+
+        let (values, _) = a.arnoldi(4).unwrap();
+        assert!((values[3].re + 0.5).abs() < 1e-10);
+    }
+
+    #[test]
+    fn failures() {
+        let a = laplacian(50);
+        let b = Vector::new(vec![1.0; 50]);
+
+        assert_eq!(a.solve_cg(&b, 1e-12, 3), Err(MatrixError::NoConvergence { iterations: 3 }));
+        assert_eq!(a.solve_cg(&Vector::new(vec![1.0]), 1e-12, 3),
+            Err(MatrixError::DimensionMismatch { operation: "solving", left: (50, 50), right: (1, 1) }));
+        assert_eq!(Matrix::<f64>::zeroes(2, 2).solve_cg(&Vector::zeroes(2), 1e-12, 3).unwrap().iterations, 0);
+        assert_eq!(a.solve_gmres(&b, 2, 1e-12, 4), Err(MatrixError::NoConvergence { iterations: 4 }));
+        assert_eq!(Matrix::<f64>::zeroes(2, 3).solve_bicgstab(&Vector::zeroes(2), 1e-12, 4),
+            Err(MatrixError::NotSquare { rows: 2, columns: 3 }));
+    }
+}
@@ -0,0 +1,781 @@
+use std::default::Default;
+use std::fmt;
+use std::ops;
+
+#[macro_use]
+mod macros;
+mod approx;
+mod arithmetic;
+mod banded;
+mod bareiss;
+mod bitmatrix;
+mod broadcast;
+mod builder;
+mod complex;
+pub mod decomposition;
+mod definiteness;
+mod diagonal;
+mod echelon;
+mod editing;
+mod elementary;
+mod elementwise;
+mod error;
+mod functions;
+mod generators;
+mod inverse;
+pub mod iter;
+pub mod iterative;
+mod manipulation;
+mod mask;
+mod modular;
+mod norm;
+mod normal_form;
+pub mod num;
+mod overflow;
+mod permutation;
+mod products;
+mod rational;
+mod reduce;
+mod row_ops;
+mod semiring;
+mod shape;
+mod solve;
+mod stack;
+mod subspace;
+mod submatrix;
+mod tridiagonal;
+mod vector;
+mod view;
+#[cfg(feature = "rand")]
+pub mod random;
+
+pub use banded::{BandedLu, BandedMatrix};
+pub use bitmatrix::BitMatrix;
+pub use builder::MatrixBuilder;
+pub use definiteness::Definiteness;
+pub use complex::{Complex, Conjugate};
+pub use elementary::{Givens, Householder};
+pub use error::MatrixError;
+pub use iterative::IterativeSolution;
+pub use modular::ModInt;
+pub use norm::NormKind;
+pub use permutation::PermutationMatrix;
+pub use num::{Field, Integer, One, Real, Zero};
+pub use rational::{Ratio, Rational32, Rational64};
+pub use row_ops::Normalization;
+pub use semiring::{MinPlus, OrAnd, Semiring, Standard};
+pub use shape::{Axis, Shape};
+pub use tridiagonal::Tridiagonal;
+pub use vector::{ColVector, RowVector, Vector};
+pub use view::{MatrixLike, MatrixView, MatrixViewMut};
+
+/// A generic matrix struct which defines addition, multiplication and other essential operations.
+///
+/// Due to the fact that this matrix is generic, most operations will not be defined properly.
+/// However, for all numeric types this works fine. This has the added benefit that you can define
+/// your own custom type and its corresponding operations, and it'll work out of the box.
+///
+/// Matrices with zero rows, zero columns or both are perfectly valid and behave the way the
+/// shapes suggest: every constructor and operator accepts them, reductions over them give the
+/// neutral element (or `None` where there is none), and multiplying an n×0 by a 0×m matrix
+/// gives an n×m matrix of zeroes.
+///
+/// Two matrices are equal when they have the same shape and the same elements, so a 2×3 and a
+/// 3×2 matrix are different even if their elements happen to line up.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Matrix<T: Default> {
+    rows: usize,
+    columns: usize,
+    contents: Vec<T>
+}
+
+/// Draws the matrix as a bracketed grid with right-aligned columns. Empty matrices still get
+/// their brackets, with one line per row, so that a 3×0 matrix looks different from a 0×3 one.
+impl<T: Default + fmt::Display> fmt::Display for Matrix<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        format_grid(f, self.rows, self.columns, self.contents.iter())
+    }
+}
+
+/// Draws elements, given in row-major order, as a bracketed grid. This is shared between
+/// everything that can be displayed like a matrix.
+pub(crate) fn format_grid<'a, T, I>(f: &mut fmt::Formatter<'_>, rows: usize, columns: usize, elements: I) -> fmt::Result where
+    T: fmt::Display + 'a,
+    I: Iterator<Item = &'a T> {
+    let elements: Vec<&T> = elements.collect();
+    let mut string_reps: Vec::<String> = vec![String::from(""); elements.len()];
+    let mut longest = 0;
+    for e in &elements {
+        let len = e.to_string().len();
+        if len > longest {
+            longest = len;
+        }
+    }
+
+    // now that we have the length of the longest element; get string reps
+    // and right-pad them with spaces to fit the length.
+
+    for (pos, e) in elements.iter().enumerate() {
+        string_reps[pos] = format!("{:>width$} ", *e, width = longest)
+    }
+
+    // we need to add two to accomodate for the top and bottom rows
+    let mut row_reps: Vec::<String> = vec![String::from(""); rows + 2]; 
+
+    for i in 0..rows + 2 {
+        
+        // top and bottom delimiters
+        if i == 0        {row_reps[i] = format!("{}{}{}", "┌ ", " ".repeat((longest + 1) * columns), "┐"); continue}
+        if i == rows + 1 {row_reps[i] = format!("{}{}{}", "└ ", " ".repeat((longest + 1) * columns), "┘"); continue}
+        
+        // remaining rows
+        //let content = &string_reps[(i-1)*columns .. (i-1) * columns].join("");
+        row_reps[i] = ["│ ", &string_reps[(i-1)*columns .. (i) * columns].join(""), "│"].join("");
+
+        //println!("row: {}", row_reps[i]);
+    }
+    
+    //println!("{:?}", row_reps);
+    //println!("{:?}", string_reps);
+    write!(f, "{}", row_reps.join("\n"))
+}
+
+impl<T: Default + Clone> Matrix<T> {
+    /// Returns a matrix of the given size and populates it with the given data.
+    /// 
+    /// Note that if more elements are supplied than the matrix can hold,
+    /// this will panic. If less are given, the remaining slots are filled with zeroes.
+    pub fn new(rows: usize, columns: usize, mut elements: Vec<T>) -> Matrix::<T> {
+        assert!(elements.len() <= rows * columns, 
+                "{elements} elements were given, but a {rows} by {columns} matrix can only hold {max}.",
+                elements = elements.len(),
+                rows = rows,
+                columns = columns,
+                max = rows * columns
+        );
+        
+        //this line is to make sure that the length is always correct
+        elements.resize_with(rows * columns, Default::default); 
+
+        Matrix::<T> {
+            rows,
+            columns,
+            contents: elements
+        }
+    }
+
+    /// The non-panicking version of `new`. Returns an error if more elements are
+    /// supplied than the matrix can hold.
+    pub fn try_new(rows: usize, columns: usize, elements: Vec<T>) -> Result<Matrix::<T>, MatrixError> {
+        if elements.len() > rows * columns {
+            return Err(MatrixError::ElementCount { expected: rows * columns, found: elements.len() });
+        }
+
+        Ok(Matrix::new(rows, columns, elements))
+    }
+
+    /// Returns a matrix of the given size where every element is computed from its position.
+    ///
+    /// The closure is called with `(row, column)` for every element, in row-major order.
+    /// For example, `Matrix::from_fn(3, 3, |r, c| (r * c) as f64)` builds a multiplication table.
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(rows: usize, columns: usize, mut f: F) -> Matrix::<T> {
+        let mut contents: Vec<T> = Vec::with_capacity(rows * columns);
+        for r in 0..rows {
+            for c in 0..columns {
+                contents.push(f(r, c));
+            }
+        }
+
+        Matrix::<T> {
+            rows,
+            columns,
+            contents
+        }
+    }
+}
+
+impl<T: Default + Clone + Zero> Matrix<T> {
+    /// Returns an all-zero matrix of the given size.
+    ///
+    /// Works for any type with an additive identity, so integer, float and custom
+    /// numeric types all get a proper zero matrix.
+    pub fn zeroes(rows: usize, columns: usize) -> Matrix::<T> {
+        Matrix::<T> {
+            rows,
+            columns,
+            contents: vec![T::zero(); rows * columns]
+        }
+    }
+}
+
+impl<T: Default + Clone + One> Matrix<T> {
+    /// Returns an all-ones matrix of the given size.
+    pub fn ones(rows: usize, columns: usize) -> Matrix::<T> {
+        Matrix::<T> {
+            rows,
+            columns,
+            contents: vec![T::one(); rows * columns]
+        }
+    }
+}
+
+impl<T: Default + Clone + Zero + One> Matrix<T> {
+    /// Returns the n by n identity matrix, with ones on the main diagonal and zeroes elsewhere.
+    ///
+    /// Multiplying any compatible matrix by this leaves it unchanged, i.e. `I * A == A`.
+    pub fn identity(n: usize) -> Matrix::<T> {
+        let mut contents: Vec<T> = vec![T::zero(); n * n];
+        for i in 0..n {
+            contents[i * n + i] = T::one();
+        }
+
+        Matrix::<T> {
+            rows: n,
+            columns: n,
+            contents
+        }
+    }
+}
+
+/// Builds a matrix from a list of rows.
+///
+/// This panics if the rows aren't all the same length. An empty list gives a 0×0 matrix, since
+/// there is no row to take the number of columns from.
+impl<T: Default> From<Vec<Vec<T>>> for Matrix<T> {
+    fn from(rows: Vec<Vec<T>>) -> Matrix<T> {
+        let columns = rows.first().map_or(0, |row| row.len());
+        for (i, row) in rows.iter().enumerate() {
+            assert!(row.len() == columns,
+                "Row {} has {} elements, but the first row has {}. All rows must have the same length.",
+                i, row.len(), columns);
+        }
+
+        Matrix::<T> {
+            rows: rows.len(),
+            columns,
+            contents: rows.into_iter().flatten().collect()
+        }
+    }
+}
+
+/// Builds a matrix from a nested array. Since the dimensions are part of the type,
+/// this can never fail.
+impl<T: Default, const R: usize, const C: usize> From<[[T; C]; R]> for Matrix<T> {
+    fn from(rows: [[T; C]; R]) -> Matrix<T> {
+        Matrix::<T> {
+            rows: R,
+            columns: C,
+            contents: IntoIterator::into_iter(rows).flatten().collect()
+        }
+    }
+}
+
+/// Row-major element access, so `m[(row, column)]` reads a single element.
+///
+/// Indexing outside the matrix panics with a message that includes its shape.
+impl<T: Default> ops::Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+    fn index(&self, (row, column): (usize, usize)) -> &T {
+        assert!(row < self.rows && column < self.columns,
+            "Index ({}, {}) is out of bounds for a matrix of dimensions ({}, {}).",
+            row, column, self.rows, self.columns);
+
+        &self.contents[row * self.columns + column]
+    }
+}
+
+impl<T: Default> ops::IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut T {
+        assert!(row < self.rows && column < self.columns,
+            "Index ({}, {}) is out of bounds for a matrix of dimensions ({}, {}).",
+            row, column, self.rows, self.columns);
+
+        &mut self.contents[row * self.columns + column]
+    }
+}
+
+impl<T: Default> Matrix<T> {
+    /// Returns a reference to the element at `(row, column)`, or `None` if that's out of bounds.
+    pub fn get(&self, row: usize, column: usize) -> Option<&T> {
+        if row < self.rows && column < self.columns {
+            self.contents.get(row * self.columns + column)
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable reference to the element at `(row, column)`, or `None` if that's out of bounds.
+    pub fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut T> {
+        if row < self.rows && column < self.columns {
+            self.contents.get_mut(row * self.columns + column)
+        } else {
+            None
+        }
+    }
+
+    /// Returns a reference to the element at `(row, column)` without any bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// `row` must be less than the number of rows and `column` less than the number of columns.
+    /// Anything else is undefined behaviour, even if the flat index happens to land inside the buffer.
+    pub unsafe fn get_unchecked(&self, row: usize, column: usize) -> &T {
+        self.contents.get_unchecked(row * self.columns + column)
+    }
+
+    /// Returns a mutable reference to the element at `(row, column)` without any bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// Same as `get_unchecked`.
+    pub unsafe fn get_unchecked_mut(&mut self, row: usize, column: usize) -> &mut T {
+        self.contents.get_unchecked_mut(row * self.columns + column)
+    }
+}
+
+/// The (admittedly quite ugly) Add implementation for matrices.
+/// 
+/// If a Matrix is of non-numerical type, it can still be added if that type implements
+/// a closed Add. In this context, 'closed' means that the addition operation cannot return
+/// a different type than it started with (for example, adding two integers can never give you a fraction).
+impl<T: Default + Clone + ops::Add<Output = T> + Copy> ops::Add<Matrix<T>> for Matrix<T> {
+    type Output = Matrix<T>;
+    fn add(self, o: Matrix<T>) -> Matrix<T> {
+        assert!(self.columns == o.columns && self.rows == o.rows, "Can only add matrices of the same dimension.");
+        
+        let mut result: Vec<T> = Vec::new();
+        for i in 0..self.contents.len() {
+            result.push(self.contents[i] + o.contents[i]);
+        }
+        
+        Matrix::<T> {
+            rows: self.rows, 
+            columns: self.columns, 
+            contents: result
+        }
+    }
+}
+
+impl<T: Default + Clone + ops::Add<Output = T> + Copy> Matrix<T> {
+    /// The non-panicking version of `+`.
+    pub fn try_add(self, o: Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        if self.columns != o.columns || self.rows != o.rows {
+            return Err(MatrixError::DimensionMismatch {
+                operation: "addition",
+                left: (self.rows, self.columns),
+                right: (o.rows, o.columns)
+            });
+        }
+
+        Ok(self + o)
+    }
+}
+
+/// Subtraction, which works just like addition.
+impl<T: Default + Clone + ops::Sub<Output = T> + Copy> ops::Sub<Matrix<T>> for Matrix<T> {
+    type Output = Matrix<T>;
+    fn sub(self, o: Matrix<T>) -> Matrix<T> {
+        assert!(self.columns == o.columns && self.rows == o.rows, "Can only subtract matrices of the same dimension.");
+
+        Matrix::<T> {
+            rows: self.rows,
+            columns: self.columns,
+            contents: self.contents.iter().zip(&o.contents).map(|(&a, &b)| a - b).collect()
+        }
+    }
+}
+
+impl<T: Default + Clone + ops::Sub<Output = T> + Copy> Matrix<T> {
+    /// The non-panicking version of `-`.
+    pub fn try_sub(self, o: Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        if self.columns != o.columns || self.rows != o.rows {
+            return Err(MatrixError::DimensionMismatch {
+                operation: "subtraction",
+                left: (self.rows, self.columns),
+                right: (o.rows, o.columns)
+            });
+        }
+
+        Ok(self - o)
+    }
+}
+
+/// Multiplication. Again, quite ugly, but it'll have to do.
+///
+/// Note that matrix multiplication is not commutative. This means that
+/// it's entirely possible that A * B is valid, but B * A is not. Even if
+/// they are both valid, they don't necessarily have to have the same value.
+impl<T: Default + Clone + ops::Add<Output = T> + ops::Mul<Output = T> + Copy> ops::Mul<Matrix<T>> for Matrix<T> {
+    type Output = Matrix<T>;
+    fn mul(self, o: Matrix<T>) -> Matrix<T> {
+        assert!(self.columns == o.rows, 
+            "Matrices of dimensions ({}, {}) and ({}, {}) aren't multiplicable.",
+            self.rows, self.columns, o.rows, o.columns);
+            
+        let mut result: Vec<T> = Vec::with_capacity(self.rows * o.columns);
+        for y in 0..self.rows {
+            for x in 0..o.columns {
+                // dot product of row y of self with column x of o
+                let mut acc: T = Default::default();
+                for k in 0..self.columns {
+                    acc = acc + self.contents[y * self.columns + k] * o.contents[k * o.columns + x];
+                }
+                result.push(acc);
+            }
+        }
+
+        Matrix::<T> {
+            rows: self.rows,
+            columns: o.columns,
+            contents: result
+        }
+    }
+}
+
+impl<T: Default + Clone + ops::Add<Output = T> + ops::Mul<Output = T> + Copy> Matrix<T> {
+    /// The non-panicking version of `*`.
+    pub fn try_mul(self, o: Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        if self.columns != o.rows {
+            return Err(MatrixError::DimensionMismatch {
+                operation: "multiplication",
+                left: (self.rows, self.columns),
+                right: (o.rows, o.columns)
+            });
+        }
+
+        Ok(self * o)
+    }
+}
+
+/// Hadamard multiplication
+/// 
+/// If a Matrix is of non-numerical type, it can still be multiplied if that type implements
+/// a closed Mul. In this context, 'closed' means that the addition operation cannot return
+/// a different type than it started with (for example, multiplying two integers can never 
+/// give you a fraction).
+impl<T: Default + Clone + ops::Mul<Output = T> + Copy> Matrix<T> {
+    pub fn hadamard(self, o: Matrix<T>) -> Matrix<T> {
+        assert!(self.columns == o.columns && self.rows == o.rows, "Can only perform Hadamard multiplication on matrices of the same dimension.");
+        
+        let mut result: Vec<T> = Vec::new();
+        for i in 0..self.contents.len() {
+            result.push(self.contents[i] * o.contents[i]);
+        }
+        
+        Matrix::<T> {
+            rows: self.rows, 
+            columns: self.columns, 
+            contents: result
+        }
+    }
+
+    /// The non-panicking version of `hadamard`.
+    pub fn try_hadamard(self, o: Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        if self.columns != o.columns || self.rows != o.rows {
+            return Err(MatrixError::DimensionMismatch {
+                operation: "Hadamard multiplication",
+                left: (self.rows, self.columns),
+                right: (o.rows, o.columns)
+            });
+        }
+
+        Ok(self.hadamard(o))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unitary_addition() {
+        let o: Matrix<f64> = Matrix::ones(3, 4);
+        let z: Matrix<f64> = Matrix::zeroes(3, 4);
+
+        assert_eq!(o.contents, (z + Matrix::ones(3, 4)).contents)
+    }
+
+    #[test]
+    #[should_panic]
+    fn unequal_dimension_add() {
+        let p: Matrix<f64> = Matrix::ones(3, 7);
+        let q: Matrix<f64> = Matrix::ones(5, 6);
+
+        println!("{}", p + q);
+    }
+
+    #[test]
+    fn square_multiplication() {
+        let a: Matrix<i32> = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+        let b: Matrix<i32> = Matrix::new(2, 2, vec![5, 6, 7, 8]);
+
+        let c = a * b;
+        assert_eq!((c.rows, c.columns), (2, 2));
+        assert_eq!(c.contents, vec![19, 22, 43, 50]);
+    }
+
+    #[test]
+    fn non_square_multiplication() {
+        let a: Matrix<i32> = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+        let b: Matrix<i32> = Matrix::new(3, 1, vec![7, 8, 9]);
+
+        let c = a * b;
+        assert_eq!((c.rows, c.columns), (2, 1));
+        assert_eq!(c.contents, vec![50, 122]);
+
+        let d: Matrix<i32> = Matrix::new(1, 2, vec![1, 2]);
+        let e: Matrix<i32> = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+
+        let f = d * e;
+        assert_eq!((f.rows, f.columns), (1, 3));
+        assert_eq!(f.contents, vec![9, 12, 15]);
+    }
+
+    #[test]
+    fn integer_constructors() {
+        let z: Matrix<i32> = Matrix::zeroes(2, 3);
+        let o: Matrix<u8> = Matrix::ones(3, 2);
+
+        assert_eq!(z.contents, vec![0; 6]);
+        assert_eq!(o.contents, vec![1; 6]);
+        assert_eq!((o.rows, o.columns), (3, 2));
+    }
+
+    #[test]
+    fn identity_layout() {
+        let i: Matrix<i32> = Matrix::identity(3);
+
+        assert_eq!((i.rows, i.columns), (3, 3));
+        assert_eq!(i.contents, vec![1, 0, 0, 0, 1, 0, 0, 0, 1]);
+    }
+
+    #[test]
+    fn identity_round_trip() {
+        let a: Matrix<f64> = Matrix::new(3, 2, vec![1.5, -2.0, 3.0, 0.5, 4.0, 7.25]);
+
+        let left = Matrix::<f64>::identity(3) * a.clone();
+        let right = a.clone() * Matrix::<f64>::identity(2);
+
+        assert_eq!(left, a);
+        assert_eq!(right, a);
+    }
+
+    #[test]
+    fn from_fn_row_major() {
+        let m: Matrix<usize> = Matrix::from_fn(2, 3, |r, c| r * 10 + c);
+
+        assert_eq!((m.rows, m.columns), (2, 3));
+        assert_eq!(m.contents, vec![0, 1, 2, 10, 11, 12]);
+    }
+
+    #[test]
+    fn from_fn_stateful_closure() {
+        let mut counter = 0;
+        let m: Matrix<i32> = Matrix::from_fn(2, 2, |_, _| { counter += 1; counter });
+
+        assert_eq!(m.contents, vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn from_nested_vec() {
+        let m: Matrix<i32> = Matrix::from(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+        assert_eq!((m.rows, m.columns), (2, 3));
+        assert_eq!(m.contents, vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    #[should_panic(expected = "All rows must have the same length")]
+    fn from_ragged_vec() {
+        let _: Matrix<i32> = Matrix::from(vec![vec![1, 2], vec![3]]);
+    }
+
+    #[test]
+    fn from_nested_array() {
+        let m: Matrix<f64> = [[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]].into();
+
+        assert_eq!((m.rows, m.columns), (3, 2));
+        assert_eq!(m.contents, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    }
+
+    #[test]
+    fn matrix_macro() {
+        let m: Matrix<i32> = matrix![1, 2, 3;
+                                     4, 5, 6];
+
+        assert_eq!((m.rows, m.columns), (2, 3));
+        assert_eq!(m.contents, vec![1, 2, 3, 4, 5, 6]);
+
+        let column: Matrix<f64> = matrix![1.0; 2.0; 3.0];
+        assert_eq!((column.rows, column.columns), (3, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "must have the same length")]
+    fn matrix_macro_ragged() {
+        let _: Matrix<i32> = matrix![1, 2; 3];
+    }
+
+    #[test]
+    fn indexing() {
+        let mut m: Matrix<i32> = matrix![1, 2, 3;
+                                         4, 5, 6];
+
+        assert_eq!(m[(0, 2)], 3);
+        assert_eq!(m[(1, 0)], 4);
+
+        m[(1, 2)] = 10;
+        assert_eq!(m.contents, vec![1, 2, 3, 4, 5, 10]);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds for a matrix of dimensions (2, 3)")]
+    fn indexing_out_of_bounds() {
+        let m: Matrix<i32> = Matrix::zeroes(2, 3);
+
+        // this is in bounds of the buffer, but not of the matrix
+        println!("{}", m[(0, 3)]);
+    }
+
+    #[test]
+    fn checked_access() {
+        let mut m: Matrix<i32> = matrix![1, 2;
+                                         3, 4];
+
+        assert_eq!(m.get(1, 0), Some(&3));
+        assert_eq!(m.get(0, 2), None);
+        assert_eq!(m.get(2, 0), None);
+
+        if let Some(x) = m.get_mut(0, 1) {
+            *x = 20;
+        }
+        assert!(m.get_mut(5, 5).is_none());
+        assert_eq!(m.contents, vec![1, 20, 3, 4]);
+    }
+
+    #[test]
+    fn unchecked_access() {
+        let mut m: Matrix<i32> = matrix![1, 2;
+                                         3, 4];
+
+        unsafe {
+            *m.get_unchecked_mut(1, 1) += 1;
+            assert_eq!(*m.get_unchecked(1, 1), 5);
+        }
+    }
+
+    #[test]
+    fn fallible_construction() {
+        let m: Result<Matrix<i32>, MatrixError> = Matrix::try_new(2, 2, vec![1, 2, 3]);
+        assert_eq!(m.map(|m| m.contents), Ok(vec![1, 2, 3, 0]));
+
+        let m: Result<Matrix<i32>, MatrixError> = Matrix::try_new(1, 2, vec![1, 2, 3]);
+        assert_eq!(m.err(), Some(MatrixError::ElementCount { expected: 2, found: 3 }));
+    }
+
+    #[test]
+    fn fallible_operators() {
+        let a: Matrix<i32> = Matrix::ones(2, 3);
+        let b: Matrix<i32> = Matrix::ones(2, 3);
+        assert_eq!(a.try_add(b).map(|m| m.contents), Ok(vec![2; 6]));
+
+        let a: Matrix<i32> = Matrix::ones(2, 3);
+        let b: Matrix<i32> = Matrix::ones(2, 3);
+        assert_eq!(a.try_mul(b).err(), Some(MatrixError::DimensionMismatch {
+            operation: "multiplication",
+            left: (2, 3),
+            right: (2, 3)
+        }));
+
+        let a: Matrix<i32> = Matrix::ones(2, 3);
+        let b: Matrix<i32> = Matrix::ones(3, 2);
+        assert!(a.try_hadamard(b).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "aren't multiplicable")]
+    fn unequal_dimension_mul() {
+        let p: Matrix<f64> = Matrix::ones(3, 2);
+        let q: Matrix<f64> = Matrix::ones(3, 2);
+
+        println!("{}", p * q);
+    }
+
+    #[test]
+    fn equality_includes_shape() {
+        let a: Matrix<i32> = matrix![1, 2, 3; 4, 5, 6];
+        let mut b = a.clone();
+
+        assert_eq!(a, b);
+        b[(1, 2)] = 0;
+        assert_ne!(a, b);
+        assert_ne!(a, Matrix::new(3, 2, vec![1, 2, 3, 4, 5, 6]));
+    }
+
+    #[test]
+    fn hashing_and_debug() {
+        use std::collections::HashMap;
+
+        let mut seen = HashMap::new();
+        seen.insert(Matrix::<i32>::identity(2), "identity");
+        assert_eq!(seen.get(&matrix![1, 0; 0, 1]), Some(&"identity"));
+
+        let m: Matrix<i32> = matrix![1, 2];
+        assert_eq!(format!("{:?}", m), "Matrix { rows: 1, columns: 2, contents: [1, 2] }");
+    }
+
+    #[test]
+    fn empty_constructors() {
+        let shapes = [(0, 0), (0, 3), (3, 0)];
+        for &(rows, columns) in &shapes {
+            let zeroes: Matrix<f64> = Matrix::zeroes(rows, columns);
+            assert!(zeroes.is_empty());
+            assert_eq!(zeroes.shape(), (rows, columns).into());
+
+            assert_eq!(Matrix::new(rows, columns, vec![]), zeroes);
+            assert_eq!(Matrix::from_fn(rows, columns, |_, _| 0.0), zeroes);
+            if rows > 0 {
+                assert_eq!(Matrix::from(vec![vec![0.0; columns]; rows]), zeroes);
+            }
+            assert_eq!(zeroes.transpose().shape(), (columns, rows).into());
+        }
+
+        let literal: Matrix<i32> = matrix![];
+        assert_eq!(literal, Matrix::identity(0));
+    }
+
+    #[test]
+    fn empty_operators() {
+        let tall: Matrix<i32> = Matrix::zeroes(3, 0);
+        let wide: Matrix<i32> = Matrix::zeroes(0, 2);
+
+        assert_eq!(tall.clone() + tall.clone(), tall);
+        assert_eq!(tall.clone() * wide.clone(), Matrix::zeroes(3, 2));
+        assert_eq!(wide * Matrix::zeroes(2, 0), Matrix::zeroes(0, 0));
+        assert_eq!(tall.row_sums(), vec![0, 0, 0]);
+        assert_eq!(tall.product(), 1);
+        assert_eq!(tall.argmax(), None);
+    }
+
+    #[test]
+    fn empty_display() {
+        assert_eq!(Matrix::<i32>::zeroes(0, 0).to_string(), "┌ ┐\n└ ┘");
+        assert_eq!(Matrix::<i32>::zeroes(2, 0).to_string(), "┌ ┐\n│ │\n│ │\n└ ┘");
+    }
+
+    #[test]
+    fn empty_views() {
+        let m: Matrix<i32> = Matrix::from_fn(3, 3, |r, c| (r * 3 + c) as i32);
+        for (rows, columns) in &[(0..0, 0..0), (1..1, 0..3), (0..3, 2..2)] {
+            let view = m.view(rows.clone(), columns.clone());
+            let owned: Matrix<i32> = Matrix::zeroes(rows.len(), columns.len());
+
+            assert_eq!(view.shape(), owned.shape());
+            assert_eq!(view.iter().count(), 0);
+            assert_eq!(view.to_string(), owned.to_string());
+            assert_eq!(view.to_matrix(), owned);
+            assert_eq!(owned.as_view().to_string(), owned.to_string());
+        }
+    }
+}
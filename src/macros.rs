@@ -0,0 +1,34 @@
+/// Builds a matrix from a literal, with rows separated by `;` and elements by `,`.
+///
+/// The dimensions are worked out from the literal itself, so there's no way to
+/// accidentally pass the wrong element count to `Matrix::new`.
+///
+/// ```
+/// use matricks::matrix;
+///
+/// let m = matrix![1, 2, 3;
+///                 4, 5, 6];
+/// println!("{}", m);
+/// ```
+///
+/// All rows need to have the same number of elements, otherwise this panics.
+#[macro_export]
+macro_rules! matrix {
+    (@count $($x:expr),+) => {
+        <[()]>::len(&[$($crate::matrix!(@unit $x)),+])
+    };
+    (@unit $x:expr) => {
+        ()
+    };
+    () => {
+        $crate::Matrix::new(0, 0, ::std::vec::Vec::new())
+    };
+    ($($($x:expr),+ $(,)?);+ $(;)?) => {{
+        let widths: &[usize] = &[$($crate::matrix!(@count $($x),+)),+];
+        let columns = widths[0];
+        assert!(widths.iter().all(|&w| w == columns),
+            "All rows in a matrix! literal must have the same length, but got row lengths {:?}.", widths);
+
+        $crate::Matrix::new(widths.len(), columns, ::std::vec![$($($x),+),+])
+    }};
+}
@@ -0,0 +1,340 @@
+//! Operations that rearrange the elements of a matrix without doing any arithmetic on them.
+
+use std::ops::Range;
+
+use crate::{Axis, Matrix, MatrixError};
+
+/// Side length of the tiles used by the in-place transpose, chosen so that
+/// a pair of tiles of `f64`s comfortably fits in L1 cache.
+const TRANSPOSE_BLOCK: usize = 32;
+
+impl<T: Default + Clone> Matrix<T> {
+    /// Returns the transpose of the matrix, i.e. the matrix with rows and columns swapped.
+    pub fn transpose(&self) -> Matrix<T> {
+        Matrix::from_fn(self.columns, self.rows, |r, c| self.contents[c * self.columns + r].clone())
+    }
+}
+
+impl<T: Default> Matrix<T> {
+    /// Transposes a square matrix in place, without allocating.
+    ///
+    /// The matrix is walked in small tiles, so that both the rows being read and the
+    /// columns being written stay in cache. This panics for non-square matrices;
+    /// use `transpose` for those.
+    pub fn transpose_in_place(&mut self) {
+        assert!(self.rows == self.columns,
+            "Can only transpose square matrices in place, but this one has dimensions ({}, {}).",
+            self.rows, self.columns);
+
+        let n = self.rows;
+        for block_r in (0..n).step_by(TRANSPOSE_BLOCK) {
+            for block_c in (block_r..n).step_by(TRANSPOSE_BLOCK) {
+                for r in block_r..(block_r + TRANSPOSE_BLOCK).min(n) {
+                    // on diagonal tiles, only swap the part above the diagonal
+                    let start = if block_r == block_c { r + 1 } else { block_c };
+                    for c in start..(block_c + TRANSPOSE_BLOCK).min(n) {
+                        self.contents.swap(r * n + c, c * n + r);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Changes the shape of the matrix without touching the underlying row-major buffer.
+    ///
+    /// The elements are read off in the same order as before, so reshaping a 2x3 matrix
+    /// into a 3x2 one gives you `[[a, b], [c, d], [e, f]]` rather than the transpose.
+    /// Fails (and leaves the matrix untouched) if the element counts differ.
+    pub fn reshape(&mut self, rows: usize, columns: usize) -> Result<(), MatrixError> {
+        if rows * columns != self.contents.len() {
+            return Err(MatrixError::DimensionMismatch {
+                operation: "reshape",
+                left: (self.rows, self.columns),
+                right: (rows, columns)
+            });
+        }
+
+        self.rows = rows;
+        self.columns = columns;
+        Ok(())
+    }
+
+    /// The consuming version of `reshape`.
+    pub fn into_shape(mut self, rows: usize, columns: usize) -> Result<Matrix<T>, MatrixError> {
+        self.reshape(rows, columns)?;
+        Ok(self)
+    }
+}
+
+impl<T: Default + Clone> Matrix<T> {
+    /// Changes the size of the matrix, keeping the elements in the top left corner where they are.
+    ///
+    /// Any newly created space is filled with `fill`; anything outside of the new size is dropped.
+    pub fn resize(&mut self, rows: usize, columns: usize, fill: T) {
+        let mut contents: Vec<T> = Vec::with_capacity(rows * columns);
+        for r in 0..rows {
+            for c in 0..columns {
+                if r < self.rows && c < self.columns {
+                    contents.push(self.contents[r * self.columns + c].clone());
+                } else {
+                    contents.push(fill.clone());
+                }
+            }
+        }
+
+        self.rows = rows;
+        self.columns = columns;
+        self.contents = contents;
+    }
+
+    /// Surrounds the matrix with a border of `value`, with the given thickness on each side.
+    pub fn pad(&mut self, top: usize, bottom: usize, left: usize, right: usize, value: T) {
+        let rows = top + self.rows + bottom;
+        let columns = left + self.columns + right;
+
+        let mut contents: Vec<T> = Vec::with_capacity(rows * columns);
+        contents.resize(top * columns, value.clone());
+        for r in 0..self.rows {
+            contents.resize(contents.len() + left, value.clone());
+            contents.extend_from_slice(&self.contents[r * self.columns..(r + 1) * self.columns]);
+            contents.resize(contents.len() + right, value.clone());
+        }
+        contents.resize(rows * columns, value);
+
+        self.rows = rows;
+        self.columns = columns;
+        self.contents = contents;
+    }
+
+    /// Shrinks the matrix down to the given rows and columns (end exclusive, like any range).
+    ///
+    /// This panics if either range reaches outside the matrix.
+    pub fn crop(&mut self, rows: Range<usize>, columns: Range<usize>) {
+        assert!(rows.start <= rows.end && rows.end <= self.rows && columns.start <= columns.end && columns.end <= self.columns,
+            "Can't crop rows {:?} and columns {:?} out of a matrix of dimensions ({}, {}).",
+            rows, columns, self.rows, self.columns);
+
+        let mut contents: Vec<T> = Vec::with_capacity(rows.len() * columns.len());
+        for r in rows.clone() {
+            contents.extend_from_slice(&self.contents[r * self.columns + columns.start..r * self.columns + columns.end]);
+        }
+
+        self.rows = rows.len();
+        self.columns = columns.len();
+        self.contents = contents;
+    }
+}
+
+impl<T: Default> Matrix<T> {
+    /// Mirrors the matrix left to right, so the first column becomes the last.
+    pub fn flip_horizontal(&mut self) {
+        if self.columns > 0 {
+            for row in self.contents.chunks_mut(self.columns) {
+                row.reverse();
+            }
+        }
+    }
+
+    /// Mirrors the matrix top to bottom, so the first row becomes the last.
+    pub fn flip_vertical(&mut self) {
+        for r in 0..self.rows / 2 {
+            self.swap_rows(r, self.rows - 1 - r);
+        }
+    }
+
+    /// Rotates the matrix a quarter turn clockwise. An m by n matrix becomes n by m.
+    pub fn rotate90(&mut self) {
+        let rows = self.rows;
+        self.rearrange(self.columns, self.rows, |r, c| (rows - 1 - c, r));
+    }
+
+    /// Rotates the matrix half a turn.
+    pub fn rotate180(&mut self) {
+        self.contents.reverse();
+    }
+
+    /// Rotates the matrix a quarter turn counter-clockwise. An m by n matrix becomes n by m.
+    pub fn rotate270(&mut self) {
+        let columns = self.columns;
+        self.rearrange(self.columns, self.rows, |r, c| (c, columns - 1 - r));
+    }
+
+    /// Cyclically shifts the rows down (for `Axis::Rows`) or the columns to the right
+    /// (for `Axis::Columns`) by `shift` places. Negative shifts go the other way, and
+    /// whatever falls off one edge comes back in at the opposite one.
+    pub fn roll(&mut self, shift: isize, axis: Axis) {
+        match axis {
+            Axis::Rows => {
+                if self.rows > 0 {
+                    let shift = shift.rem_euclid(self.rows as isize) as usize;
+                    self.contents.rotate_right(shift * self.columns);
+                }
+            },
+            Axis::Columns => {
+                if self.columns > 0 {
+                    let shift = shift.rem_euclid(self.columns as isize) as usize;
+                    for row in self.contents.chunks_mut(self.columns) {
+                        row.rotate_right(shift);
+                    }
+                }
+            }
+        }
+    }
+
+    // moves every element into a matrix of the given shape, where `source` gives the
+    // position in the current matrix that ends up at `(row, column)`
+    fn rearrange<F: Fn(usize, usize) -> (usize, usize)>(&mut self, rows: usize, columns: usize, source: F) {
+        let mut old: Vec<Option<T>> = std::mem::take(&mut self.contents).into_iter().map(Some).collect();
+        let mut contents: Vec<T> = Vec::with_capacity(rows * columns);
+        for r in 0..rows {
+            for c in 0..columns {
+                let (sr, sc) = source(r, c);
+                contents.push(old[sr * self.columns + sc].take().expect("rearranging must be a permutation"));
+            }
+        }
+
+        self.rows = rows;
+        self.columns = columns;
+        self.contents = contents;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transpose_rectangular() {
+        let m: Matrix<i32> = matrix![1, 2, 3;
+                                     4, 5, 6];
+        let t = m.transpose();
+
+        assert_eq!(t.shape(), (3, 2).into());
+        assert_eq!(t.contents, vec![1, 4, 2, 5, 3, 6]);
+    }
+
+    #[test]
+    fn transpose_in_place_matches() {
+        // large enough to span several tiles, and not a multiple of the tile size
+        let m: Matrix<usize> = Matrix::from_fn(70, 70, |r, c| r * 70 + c);
+        let expected = m.transpose();
+
+        let mut n = m;
+        n.transpose_in_place();
+        assert_eq!(n.contents, expected.contents);
+    }
+
+    #[test]
+    fn reshape_keeps_order() {
+        let mut m: Matrix<i32> = matrix![1, 2, 3;
+                                         4, 5, 6];
+
+        assert!(m.reshape(3, 2).is_ok());
+        assert_eq!(m.shape(), (3, 2).into());
+        assert_eq!(m[(1, 0)], 3);
+
+        let row = m.into_shape(1, 6).unwrap();
+        assert_eq!(row.contents, vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn reshape_wrong_count() {
+        let mut m: Matrix<i32> = Matrix::zeroes(2, 3);
+
+        assert!(m.reshape(4, 2).is_err());
+        assert_eq!(m.shape(), (2, 3).into());
+        assert!(m.into_shape(5, 1).is_err());
+    }
+
+    #[test]
+    fn resize_grow_and_shrink() {
+        let mut m: Matrix<i32> = matrix![1, 2;
+                                         3, 4];
+
+        m.resize(3, 3, 9);
+        assert_eq!(m.contents, vec![1, 2, 9, 3, 4, 9, 9, 9, 9]);
+
+        m.resize(1, 2, 0);
+        assert_eq!(m.shape(), (1, 2).into());
+        assert_eq!(m.contents, vec![1, 2]);
+    }
+
+    #[test]
+    fn pad_border() {
+        let mut m: Matrix<i32> = matrix![1, 2];
+
+        m.pad(1, 0, 2, 1, 0);
+        assert_eq!(m.shape(), (2, 5).into());
+        assert_eq!(m.contents, vec![0, 0, 0, 0, 0,
+                                    0, 0, 1, 2, 0]);
+    }
+
+    #[test]
+    fn crop_region() {
+        let mut m: Matrix<usize> = Matrix::from_fn(4, 4, |r, c| r * 4 + c);
+
+        m.crop(1..3, 2..4);
+        assert_eq!(m.shape(), (2, 2).into());
+        assert_eq!(m.contents, vec![6, 7, 10, 11]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Can't crop")]
+    fn crop_out_of_bounds() {
+        let mut m: Matrix<i32> = Matrix::zeroes(2, 2);
+        m.crop(0..3, 0..1);
+    }
+
+    #[test]
+    fn flips() {
+        let mut m: Matrix<i32> = matrix![1, 2, 3;
+                                         4, 5, 6];
+
+        m.flip_horizontal();
+        assert_eq!(m.contents, vec![3, 2, 1, 6, 5, 4]);
+        m.flip_vertical();
+        assert_eq!(m.contents, vec![6, 5, 4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn rotations() {
+        let mut m: Matrix<i32> = matrix![1, 2, 3;
+                                         4, 5, 6];
+
+        m.rotate90();
+        assert_eq!(m.shape(), (3, 2).into());
+        assert_eq!(m.contents, vec![4, 1, 5, 2, 6, 3]);
+
+        m.rotate90();
+        assert_eq!(m.shape(), (2, 3).into());
+        assert_eq!(m.contents, vec![6, 5, 4, 3, 2, 1]);
+
+        m.rotate180();
+        m.rotate270();
+        assert_eq!(m.shape(), (3, 2).into());
+        assert_eq!(m.contents, vec![3, 6, 2, 5, 1, 4]);
+
+        m.rotate90();
+        assert_eq!(m.contents, vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn rolling() {
+        let mut m: Matrix<i32> = matrix![1, 2, 3;
+                                         4, 5, 6;
+                                         7, 8, 9];
+
+        m.roll(1, Axis::Rows);
+        assert_eq!(m.contents, vec![7, 8, 9, 1, 2, 3, 4, 5, 6]);
+        m.roll(-1, Axis::Rows);
+        m.roll(-4, Axis::Columns);
+        assert_eq!(m.contents, vec![2, 3, 1, 5, 6, 4, 8, 9, 7]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Can only transpose square matrices in place")]
+    fn transpose_in_place_non_square() {
+        let mut m: Matrix<i32> = Matrix::zeroes(2, 3);
+        m.transpose_in_place();
+    }
+}
@@ -0,0 +1,214 @@
+//! Element-wise comparisons, which produce boolean masks, and selecting or overwriting elements through those masks.
+
+use crate::Matrix;
+
+impl<T: Default + PartialOrd> Matrix<T> {
+    /// Which elements are greater than `value`.
+    pub fn gt(&self, value: &T) -> Matrix<bool> {
+        self.map(|e| e > value)
+    }
+
+    /// Which elements are greater than or equal to `value`.
+    pub fn ge(&self, value: &T) -> Matrix<bool> {
+        self.map(|e| e >= value)
+    }
+
+    /// Which elements are less than `value`.
+    pub fn lt(&self, value: &T) -> Matrix<bool> {
+        self.map(|e| e < value)
+    }
+
+    /// Which elements are less than or equal to `value`.
+    pub fn le(&self, value: &T) -> Matrix<bool> {
+        self.map(|e| e <= value)
+    }
+
+    /// Which elements are greater than the corresponding ones in `o`. This panics if the shapes differ.
+    pub fn gt_elementwise(&self, o: &Matrix<T>) -> Matrix<bool> {
+        self.zip_with(o, |a, b| a > b)
+    }
+
+    /// Which elements are greater than or equal to the corresponding ones in `o`.
+    pub fn ge_elementwise(&self, o: &Matrix<T>) -> Matrix<bool> {
+        self.zip_with(o, |a, b| a >= b)
+    }
+
+    /// Which elements are less than the corresponding ones in `o`.
+    pub fn lt_elementwise(&self, o: &Matrix<T>) -> Matrix<bool> {
+        self.zip_with(o, |a, b| a < b)
+    }
+
+    /// Which elements are less than or equal to the corresponding ones in `o`.
+    pub fn le_elementwise(&self, o: &Matrix<T>) -> Matrix<bool> {
+        self.zip_with(o, |a, b| a <= b)
+    }
+}
+
+impl<T: Default + PartialEq> Matrix<T> {
+    /// Which elements are equal to `value`.
+    pub fn eq_scalar(&self, value: &T) -> Matrix<bool> {
+        self.map(|e| e == value)
+    }
+
+    /// Which elements are not equal to `value`.
+    pub fn ne_scalar(&self, value: &T) -> Matrix<bool> {
+        self.map(|e| e != value)
+    }
+
+    /// Which elements are equal to the corresponding ones in `o`. This panics if the shapes differ.
+    pub fn eq_elementwise(&self, o: &Matrix<T>) -> Matrix<bool> {
+        self.zip_with(o, |a, b| a == b)
+    }
+
+    /// Which elements differ from the corresponding ones in `o`.
+    pub fn ne_elementwise(&self, o: &Matrix<T>) -> Matrix<bool> {
+        self.zip_with(o, |a, b| a != b)
+    }
+}
+
+impl Matrix<bool> {
+    /// Whether at least one element is `true`. This is `false` for an empty mask.
+    pub fn any(&self) -> bool {
+        self.contents.iter().any(|&b| b)
+    }
+
+    /// Whether every element is `true`. This is `true` for an empty mask.
+    pub fn all(&self) -> bool {
+        self.contents.iter().all(|&b| b)
+    }
+
+    /// The number of `true` elements.
+    pub fn count_true(&self) -> usize {
+        self.contents.iter().filter(|&&b| b).count()
+    }
+}
+
+impl<T: Default + Clone> Matrix<T> {
+    /// Picks every element from `self` where `mask` is `true` and from `other` where it is `false`.
+    ///
+    /// This is the element-wise `if mask { self } else { other }`, and panics unless all three
+    /// matrices have the same shape.
+    pub fn select(&self, mask: &Matrix<bool>, other: &Matrix<T>) -> Matrix<T> {
+        self.check_mask(mask);
+        assert!(self.rows == other.rows && self.columns == other.columns,
+            "Can only select between matrices of the same dimension, but got ({}, {}) and ({}, {}).",
+            self.rows, self.columns, other.rows, other.columns);
+
+        Matrix {
+            rows: self.rows,
+            columns: self.columns,
+            contents: self.contents.iter().zip(&other.contents).zip(&mask.contents)
+                .map(|((a, b), &m)| if m { a.clone() } else { b.clone() })
+                .collect()
+        }
+    }
+
+    /// Overwrites every element where `mask` is `true` with `value`.
+    pub fn set_where(&mut self, mask: &Matrix<bool>, value: T) {
+        self.check_mask(mask);
+
+        for (e, _) in self.contents.iter_mut().zip(&mask.contents).filter(|(_, &m)| m) {
+            *e = value.clone();
+        }
+    }
+
+    /// Returns a matrix made up of only those rows for which `keep` returns `true`, in their
+    /// original order. The result keeps the number of columns even if no rows match.
+    pub fn filter_rows<F: FnMut(&[T]) -> bool>(&self, mut keep: F) -> Matrix<T> {
+        let mut contents = Vec::new();
+        let mut rows = 0;
+        for r in 0..self.rows {
+            let row = &self.contents[r * self.columns..(r + 1) * self.columns];
+            if keep(row) {
+                contents.extend_from_slice(row);
+                rows += 1;
+            }
+        }
+
+        Matrix { rows, columns: self.columns, contents }
+    }
+
+    fn check_mask(&self, mask: &Matrix<bool>) {
+        assert!(self.rows == mask.rows && self.columns == mask.columns,
+            "A mask of dimensions ({}, {}) doesn't fit a matrix of dimensions ({}, {}).",
+            mask.rows, mask.columns, self.rows, self.columns);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scalar_comparisons() {
+        let m: Matrix<i32> = matrix![1, 5;
+                                     3, 2];
+
+        assert_eq!(m.gt(&2).contents, vec![false, true, true, false]);
+        assert_eq!(m.ge(&2).contents, vec![false, true, true, true]);
+        assert_eq!(m.lt(&3).contents, vec![true, false, false, true]);
+        assert_eq!(m.le(&1).contents, vec![true, false, false, false]);
+        assert_eq!(m.eq_scalar(&3).contents, vec![false, false, true, false]);
+        assert_eq!(m.ne_scalar(&3).count_true(), 3);
+    }
+
+    #[test]
+    fn matrix_comparisons() {
+        let a: Matrix<f64> = matrix![1.0, 2.0, 3.0];
+        let b: Matrix<f64> = matrix![3.0, 2.0, 1.0];
+
+        assert_eq!(a.gt_elementwise(&b).contents, vec![false, false, true]);
+        assert_eq!(a.le_elementwise(&b).contents, vec![true, true, false]);
+        assert_eq!(a.eq_elementwise(&b).contents, vec![false, true, false]);
+        assert!(a.eq_elementwise(&a).all());
+        assert!(!a.ne_elementwise(&a).any());
+    }
+
+    #[test]
+    fn empty_masks() {
+        let m: Matrix<bool> = Matrix::new(0, 3, vec![]);
+
+        assert!(m.all());
+        assert!(!m.any());
+        assert_eq!(m.count_true(), 0);
+    }
+
+    #[test]
+    fn selecting() {
+        let a: Matrix<i32> = matrix![1, -2;
+                                     -3, 4];
+        let zeroes: Matrix<i32> = Matrix::zeroes(2, 2);
+
+        let relu = a.select(&a.gt(&0), &zeroes);
+        assert_eq!(relu.contents, vec![1, 0, 0, 4]);
+    }
+
+    #[test]
+    fn setting_where() {
+        let mut m: Matrix<f64> = matrix![0.5, 7.0;
+                                         -1.0, 2.0];
+        let mask = m.gt(&1.0);
+        m.set_where(&mask, 1.0);
+
+        assert_eq!(m.contents, vec![0.5, 1.0, -1.0, 1.0]);
+    }
+
+    #[test]
+    fn filtering_rows() {
+        let m: Matrix<i32> = matrix![1, 2;
+                                     -3, 4;
+                                     5, 6];
+
+        let positive = m.filter_rows(|row| row.iter().all(|&x| x > 0));
+        assert_eq!(positive.shape(), (2, 2).into());
+        assert_eq!(positive.contents, vec![1, 2, 5, 6]);
+        assert_eq!(m.filter_rows(|_| false).shape(), (0, 2).into());
+    }
+
+    #[test]
+    #[should_panic(expected = "A mask of dimensions (1, 2) doesn't fit")]
+    fn mismatched_mask() {
+        let mut m: Matrix<i32> = Matrix::zeroes(2, 2);
+        m.set_where(&Matrix::new(1, 2, vec![true, false]), 1);
+    }
+}
@@ -0,0 +1,170 @@
+//! Integers modulo a prime, for exact linear algebra over the finite fields GF(p).
+//!
+//! `ModInt<P>` is a `Field`, so besides the usual arithmetic, matrices of them can be reduced,
+//! solved and inverted with `rref_exact`, `solve_exact` and `inverse_exact`, and have a
+//! `det_exact`. The modulus is part of the type, so elements of different fields can't be
+//! mixed up by accident.
+
+use std::fmt;
+use std::ops;
+
+use crate::{Field, One, Zero};
+
+/// An integer modulo `P`, which should be a prime for division to make sense.
+///
+/// The value is always kept in `0..P`. Products are formed in 128 bits, so any `P` that fits
+/// in a `u64` works.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ModInt<const P: u64>(u64);
+
+impl<const P: u64> ModInt<P> {
+    /// The residue of `x` modulo `P`.
+    pub fn new(x: u64) -> ModInt<P> {
+        ModInt(x % P)
+    }
+
+    /// The representative in `0..P`.
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+
+    /// `self` to the power `e`, by repeated squaring.
+    pub fn pow(self, mut e: u64) -> ModInt<P> {
+        let (mut base, mut acc) = (self, ModInt::one());
+        while e > 0 {
+            if e & 1 == 1 {
+                acc = acc * base;
+            }
+            base = base * base;
+            e >>= 1;
+        }
+        acc
+    }
+
+    /// The multiplicative inverse, by Fermat's little theorem, or `None` for zero.
+    pub fn inverse(self) -> Option<ModInt<P>> {
+        if self.0 == 0 { None } else { Some(self.pow(P - 2)) }
+    }
+}
+
+impl<const P: u64> From<u64> for ModInt<P> {
+    fn from(x: u64) -> ModInt<P> {
+        ModInt::new(x)
+    }
+}
+
+impl<const P: u64> From<i64> for ModInt<P> {
+    fn from(x: i64) -> ModInt<P> {
+        ModInt((x as i128).rem_euclid(P as i128) as u64)
+    }
+}
+
+impl<const P: u64> ops::Add for ModInt<P> {
+    type Output = ModInt<P>;
+    fn add(self, o: ModInt<P>) -> ModInt<P> {
+        ModInt(((self.0 as u128 + o.0 as u128) % P as u128) as u64)
+    }
+}
+
+impl<const P: u64> ops::Sub for ModInt<P> {
+    type Output = ModInt<P>;
+    fn sub(self, o: ModInt<P>) -> ModInt<P> {
+        self + -o
+    }
+}
+
+impl<const P: u64> ops::Mul for ModInt<P> {
+    type Output = ModInt<P>;
+    fn mul(self, o: ModInt<P>) -> ModInt<P> {
+        ModInt((self.0 as u128 * o.0 as u128 % P as u128) as u64)
+    }
+}
+
+impl<const P: u64> ops::Div for ModInt<P> {
+    type Output = ModInt<P>;
+    fn div(self, o: ModInt<P>) -> ModInt<P> {
+        let inverse = o.inverse().unwrap_or_else(|| panic!("Can't divide by zero modulo {}.", P));
+        ops::Mul::mul(self, inverse)
+    }
+}
+
+impl<const P: u64> ops::Neg for ModInt<P> {
+    type Output = ModInt<P>;
+    fn neg(self) -> ModInt<P> {
+        if self.0 == 0 { self } else { ModInt(P - self.0) }
+    }
+}
+
+impl<const P: u64> Zero for ModInt<P> {
+    fn zero() -> ModInt<P> {
+        ModInt(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl<const P: u64> One for ModInt<P> {
+    fn one() -> ModInt<P> {
+        ModInt::new(1)
+    }
+}
+
+impl<const P: u64> Field for ModInt<P> {}
+
+/// Renders as the representative in `0..P`.
+impl<const P: u64> fmt::Display for ModInt<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Matrix, MatrixError};
+
+    type F7 = ModInt<7>;
+
+    fn mod7(m: Matrix<i64>) -> Matrix<F7> {
+        m.map(|&e| F7::from(e))
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(F7::new(5) + F7::new(4), F7::new(2));
+        assert_eq!(F7::new(2) - F7::new(5), F7::new(4));
+        assert_eq!(F7::from(-1i64), F7::new(6));
+        assert_eq!(F7::new(3) * F7::new(5), F7::new(1));
+        assert_eq!(F7::new(1) / F7::new(3), F7::new(5));
+        assert_eq!(F7::new(0).inverse(), None);
+        assert_eq!(F7::new(3).pow(6), F7::one());
+
+        // products that overflow 64 bits
+        type Big = ModInt<18446744073709551557>;
+        let x = Big::new(u64::MAX - 1);
+        assert_eq!(x * x.inverse().unwrap(), Big::one());
+    }
+
+    #[test]
+    fn linear_algebra() {
+        let a = mod7(matrix![1, 2, 3;
+                             4, 5, 6;
+                             0, 1, 1]);
+
+        // det = 1·(5 - 6) - 2·(4 - 0) + 3·(4 - 0) = 3
+        assert_eq!(a.det_exact(), F7::new(3));
+        let inverse = a.inverse_exact().unwrap();
+        assert_eq!(&a * &inverse, Matrix::identity(3));
+
+        // singular modulo 7, though not over the rationals
+        let b = mod7(matrix![1, 2;
+                             3, 13]);
+        assert_eq!(b.det_exact(), F7::zero());
+        assert_eq!(b.inverse_exact().unwrap_err(), MatrixError::Singular);
+        let (r, pivots) = b.rref_exact();
+        assert_eq!(pivots, vec![0]);
+        assert_eq!(r, mod7(matrix![1, 2; 0, 0]));
+    }
+}
@@ -0,0 +1,115 @@
+//! Matrix norms.
+
+use crate::{iterative, Matrix, Real};
+
+/// Which matrix norm to compute with `Matrix::norm`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NormKind {
+    /// The largest absolute column sum, the operator norm induced by the vector 1-norm.
+    One,
+    /// The largest absolute row sum, the operator norm induced by the vector ∞-norm.
+    Infinity,
+    /// The square root of the sum of the squares of all elements.
+    Frobenius,
+    /// The largest singular value, the operator norm induced by the euclidean vector norm.
+    Spectral
+}
+
+impl<T: Real> Matrix<T> {
+    /// The norm of the given kind. All of them are zero for empty matrices.
+    ///
+    /// The spectral norm is found by power iteration on `Aᵀ A`, which is cheap but converges
+    /// slowly if the two largest singular values are close; `singular_values` is the more
+    /// expensive but exact alternative.
+    pub fn norm(&self, kind: NormKind) -> T {
+        match kind {
+            NormKind::One => largest(self.fold_cols(T::zero(), |acc, e| acc + e.abs())),
+            NormKind::Infinity => largest(self.fold_rows(T::zero(), |acc, e| acc + e.abs())),
+            NormKind::Frobenius => self.contents.iter().fold(T::zero(), |acc, &e| acc + e * e).sqrt(),
+            NormKind::Spectral => self.spectral_norm()
+        }
+    }
+
+    fn spectral_norm(&self) -> T {
+        const MAX_ITERATIONS: usize = 1000;
+        let gram = &self.transpose() * self;
+
+        if gram.is_empty() {
+            return T::zero();
+        }
+        let mut v = iterative::start_vector::<T>(gram.columns).into_vec();
+
+        let mut estimate = T::zero();
+        for _ in 0..MAX_ITERATIONS {
+            let l = length(&v);
+            if l.is_zero() || !l.is_finite() {
+                return T::zero();
+            }
+            v.iter_mut().for_each(|x| *x = *x / l);
+
+            // the Rayleigh quotient vᵀ (Aᵀ A) v approaches the largest eigenvalue, σ²
+            let w = gram.apply(&v).into_vec();
+            let next = v.iter().zip(&w).fold(T::zero(), |acc, (&a, &b)| acc + a * b);
+            v = w;
+            if (next - estimate).abs() <= T::epsilon() * next {
+                estimate = next;
+                break;
+            }
+            estimate = next;
+        }
+        estimate.sqrt()
+    }
+}
+
+fn largest<T: Real>(values: Vec<T>) -> T {
+    values.into_iter().fold(T::zero(), |acc, x| if x > acc { x } else { acc })
+}
+
+fn length<T: Real>(v: &[T]) -> T {
+    v.iter().fold(T::zero(), |acc, &x| acc + x * x).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn elementwise_norms() {
+        let a: Matrix<f64> = matrix![1.0, -2.0;
+                                     -3.0, 4.0];
+
+        assert_eq!(a.norm(NormKind::One), 6.0);
+        assert_eq!(a.norm(NormKind::Infinity), 7.0);
+        assert_eq!(a.norm(NormKind::Frobenius), 30f64.sqrt());
+        assert_eq!(Matrix::<f64>::zeroes(0, 0).norm(NormKind::One), 0.0);
+    }
+
+    #[test]
+    fn spectral_norm() {
+        let a: Matrix<f64> = matrix![3.0, 2.0, 2.0;
+                                     2.0, 3.0, -2.0];
+        assert!((a.norm(NormKind::Spectral) - 5.0).abs() < 1e-12);
+
+        let random_ish: Matrix<f64> = Matrix::from_fn(5, 4, |r, c| ((r * 3 + c * 7) % 5) as f64 - 2.0);
+        let expected = random_ish.singular_values()[0];
+        assert!((random_ish.norm(NormKind::Spectral) - expected).abs() < 1e-10 * expected);
+
+        // the largest column of Aᵀ A is an eigenvector, but not the dominant one
+        let block: Matrix<f64> = matrix![3.5, 0.0, 0.0;
+                                         0.0, 2.0, 2.0;
+                                         0.0, 2.0, 2.0];
+        assert!((block.norm(NormKind::Spectral) - 4.0).abs() < 1e-12);
+
+        assert_eq!(Matrix::<f64>::zeroes(3, 2).norm(NormKind::Spectral), 0.0);
+        assert_eq!(Matrix::<f64>::zeroes(3, 0).norm(NormKind::Spectral), 0.0);
+    }
+
+    #[test]
+    fn norm_inequalities() {
+        let a: Matrix<f64> = Matrix::hilbert(4);
+        let spectral = a.norm(NormKind::Spectral);
+
+        assert!(spectral <= a.norm(NormKind::Frobenius));
+        assert!(spectral * spectral <= a.norm(NormKind::One) * a.norm(NormKind::Infinity) + 1e-12);
+    }
+}
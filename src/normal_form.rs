@@ -0,0 +1,221 @@
+//! Canonical forms of integer matrices under unimodular transformations, i.e. invertible
+//! integer matrices whose inverses are integer matrices too.
+//!
+//! Everything here is computed with the plain integer operators, so intermediate values that
+//! overflow `T` panic in debug builds and wrap around in release builds. Prefer a wide type
+//! such as `i64` or `i128`; the entries of the transformations can get much larger than those
+//! of the matrix itself.
+
+use std::ops;
+
+use crate::{Integer, Matrix};
+
+impl<T> Matrix<T> where
+    T: Integer + ops::Sub<Output = T> + ops::Div<Output = T> + ops::Rem<Output = T> + ops::Neg<Output = T> {
+    /// The Smith normal form `S`, together with unimodular `U` and `V` such that `U A V = S`.
+    ///
+    /// `S` has the shape of `A` and is zero off the diagonal. Its diagonal elements
+    /// `d₁, d₂, ...` are nonnegative and each divides the next, with the nonzero ones first;
+    /// these invariant factors are unique, even though `U` and `V` are not. They describe the
+    /// abelian group generated by the columns, and `A x = b` has an integer solution exactly
+    /// when `U b` is divisible elementwise by the diagonal of `S` (and zero past it), with
+    /// `x = V y` for `y` the quotients.
+    pub fn smith_normal_form(&self) -> (Matrix<T>, Matrix<T>, Matrix<T>) {
+        let (m, n) = (self.rows, self.columns);
+        let mut s = self.clone();
+        let mut u = Matrix::identity(m);
+        let mut v = Matrix::identity(n);
+
+        for t in 0..m.min(n) {
+            loop {
+                // the smallest nonzero element left becomes the pivot
+                let pivot = (t..m).flat_map(|r| (t..n).map(move |c| (r, c)))
+                    .filter(|&(r, c)| !s[(r, c)].is_zero())
+                    .min_by_key(|&(r, c)| magnitude(s[(r, c)]));
+                let (pr, pc) = match pivot {
+                    Some(p) => p,
+                    None => break
+                };
+                s.swap_rows(t, pr);
+                u.swap_rows(t, pr);
+                s.swap_cols(t, pc);
+                v.swap_cols(t, pc);
+
+                // clear the pivot row and column, as far as division allows
+                let pivot = s[(t, t)];
+                for r in t + 1..m {
+                    let q = s[(r, t)] / pivot;
+                    if !q.is_zero() {
+                        s.add_scaled_row(t, r, -q);
+                        u.add_scaled_row(t, r, -q);
+                    }
+                }
+                for c in t + 1..n {
+                    let q = s[(t, c)] / pivot;
+                    if !q.is_zero() {
+                        s.add_scaled_col(t, c, -q);
+                        v.add_scaled_col(t, c, -q);
+                    }
+                }
+
+                // remainders left behind are smaller than the pivot, so another round makes progress
+                if (t + 1..m).any(|r| !s[(r, t)].is_zero()) || (t + 1..n).any(|c| !s[(t, c)].is_zero()) {
+                    continue;
+                }
+                // the pivot has to divide everything that is left, or the row of an element that
+                // it doesn't divide is added to the pivot row, which then leaves a remainder
+                let indivisible = (t + 1..m).find(|&r| (t + 1..n).any(|c| !(s[(r, c)] % pivot).is_zero()));
+                match indivisible {
+                    Some(r) => {
+                        s.add_scaled_row(r, t, T::one());
+                        u.add_scaled_row(r, t, T::one());
+                    }
+                    None => break
+                }
+            }
+
+            if s[(t, t)] < T::zero() {
+                s.scale_row(t, -T::one());
+                u.scale_row(t, -T::one());
+            }
+        }
+
+        (s, u, v)
+    }
+
+    /// The (row style) Hermite normal form `H`, together with a unimodular `U` such that `U A = H`.
+    ///
+    /// `H` is in row echelon form, with its zero rows at the bottom. Every pivot is positive,
+    /// and the elements above it lie in `0..pivot`, which makes `H` unique. The nonzero rows
+    /// are a basis of the lattice spanned by the rows of `A`, and so two matrices generate the
+    /// same lattice exactly when their Hermite normal forms agree.
+    pub fn hermite_normal_form(&self) -> (Matrix<T>, Matrix<T>) {
+        let (m, n) = (self.rows, self.columns);
+        let mut h = self.clone();
+        let mut u = Matrix::identity(m);
+
+        let mut row = 0;
+        for c in 0..n {
+            if row == m {
+                break;
+            }
+
+            // a Euclidean algorithm on the column, run on whole rows
+            loop {
+                let pivot = (row..m).filter(|&r| !h[(r, c)].is_zero()).min_by_key(|&r| magnitude(h[(r, c)]));
+                let p = match pivot {
+                    Some(p) => p,
+                    None => break
+                };
+                h.swap_rows(row, p);
+                u.swap_rows(row, p);
+
+                let pivot = h[(row, c)];
+                for r in row + 1..m {
+                    let q = h[(r, c)] / pivot;
+                    if !q.is_zero() {
+                        h.add_scaled_row(row, r, -q);
+                        u.add_scaled_row(row, r, -q);
+                    }
+                }
+                if (row + 1..m).all(|r| h[(r, c)].is_zero()) {
+                    break;
+                }
+            }
+            if h[(row, c)].is_zero() {
+                continue;
+            }
+
+            if h[(row, c)] < T::zero() {
+                h.scale_row(row, -T::one());
+                u.scale_row(row, -T::one());
+            }
+            let pivot = h[(row, c)];
+            for r in 0..row {
+                // rounding the quotient down leaves a remainder in 0..pivot
+                let mut q = h[(r, c)] / pivot;
+                if (h[(r, c)] % pivot) < T::zero() {
+                    q = q - T::one();
+                }
+                if !q.is_zero() {
+                    h.add_scaled_row(row, r, -q);
+                    u.add_scaled_row(row, r, -q);
+                }
+            }
+            row += 1;
+        }
+
+        (h, u)
+    }
+}
+
+// the absolute value, which `Integer` has no method for
+fn magnitude<T: Integer + ops::Neg<Output = T>>(x: T) -> T {
+    if x < T::zero() { -x } else { x }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_smith(a: &Matrix<i64>, diagonal: &[i64]) {
+        let (s, u, v) = a.smith_normal_form();
+        assert_eq!(&(&u * a) * &v, s);
+        assert_eq!(s.diagonal(), diagonal.to_vec());
+        for r in 0..s.rows {
+            for c in (0..s.columns).filter(|&c| c != r) {
+                assert_eq!(s[(r, c)], 0);
+            }
+        }
+        // unimodular
+        assert_eq!(u.checked_det().unwrap().abs(), 1);
+        assert_eq!(v.checked_det().unwrap().abs(), 1);
+    }
+
+    #[test]
+    fn smith_form() {
+        let a: Matrix<i64> = matrix![2, 4, 4;
+                                     -6, 6, 12;
+                                     10, -4, -16];
+        check_smith(&a, &[2, 6, 12]);
+
+        // the invariant factors of diag(4, 6) are 2 and 12, not 4 and 6
+        let b: Matrix<i64> = matrix![4, 0;
+                                     0, 6];
+        check_smith(&b, &[2, 12]);
+
+        let c: Matrix<i64> = matrix![1, 2, 3;
+                                     4, 5, 6];
+        check_smith(&c, &[1, 3]);
+        check_smith(&c.transpose(), &[1, 3]);
+        check_smith(&Matrix::zeroes(2, 2), &[0, 0]);
+    }
+
+    #[test]
+    fn hermite_form() {
+        let a: Matrix<i64> = matrix![2, 3, 6, 2;
+                                     5, 6, 1, 6;
+                                     8, 3, 1, 1];
+
+        let (h, u) = a.hermite_normal_form();
+        assert_eq!(h, matrix![1, 0, 50, -11;
+                              0, 3, 28, -2;
+                              0, 0, 61, -13]);
+        assert_eq!(&u * &a, h);
+        assert_eq!(u.checked_det().unwrap().abs(), 1);
+    }
+
+    #[test]
+    fn hermite_form_rank_deficient() {
+        let a: Matrix<i64> = matrix![0, 4, 6;
+                                     0, 2, 3;
+                                     0, -6, 1];
+
+        let (h, u) = a.hermite_normal_form();
+        assert_eq!(h, matrix![0, 2, 3;
+                              0, 0, 10;
+                              0, 0, 0]);
+        assert_eq!(&u * &a, h);
+        assert_eq!(u.checked_det().unwrap().abs(), 1);
+    }
+}
@@ -0,0 +1,160 @@
+//! Small numeric traits used by the generic constructors and the numerical algorithms.
+//!
+//! These mirror the `Zero`, `One` and `Float` traits from the `num-traits` crate, so that
+//! any type implementing them there can be given an implementation here in a couple of lines.
+
+use std::fmt;
+use std::ops;
+
+/// The additive identity of a type.
+pub trait Zero: Sized + ops::Add<Output = Self> {
+    fn zero() -> Self;
+
+    fn is_zero(&self) -> bool;
+}
+
+/// The multiplicative identity of a type.
+pub trait One: Sized + ops::Mul<Output = Self> {
+    fn one() -> Self;
+}
+
+macro_rules! impl_zero_one {
+    ($zero:expr, $one:expr; $($t:ty),*) => {
+        $(
+            impl Zero for $t {
+                fn zero() -> $t { $zero }
+
+                fn is_zero(&self) -> bool { *self == $zero }
+            }
+
+            impl One for $t {
+                fn one() -> $t { $one }
+            }
+        )*
+    };
+}
+
+impl_zero_one!(0, 1; i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_zero_one!(0.0, 1.0; f32, f64);
+
+/// Real floating point numbers, i.e. `f32` and `f64`.
+///
+/// Anything that needs square roots, absolute values or a notion of "close enough to zero"
+/// (normalisation, decompositions, solvers, ...) is implemented for matrices of these.
+pub trait Real: Copy + Default + PartialOrd + fmt::Debug + fmt::Display + Zero + One
+    + ops::Sub<Output = Self> + ops::Div<Output = Self> + ops::Neg<Output = Self> {
+    /// The difference between 1 and the next representable number.
+    fn epsilon() -> Self;
+
+    fn from_f64(x: f64) -> Self;
+
+    fn to_f64(self) -> f64;
+
+    fn sqrt(self) -> Self;
+
+    fn abs(self) -> Self;
+
+    fn powi(self, n: i32) -> Self;
+
+    fn powf(self, n: Self) -> Self;
+
+    fn exp(self) -> Self;
+
+    fn ln(self) -> Self;
+
+    /// The arccosine in radians, in the range `[0, pi]`.
+    fn acos(self) -> Self;
+
+    /// `sqrt(self^2 + other^2)`, without needless overflow or underflow.
+    fn hypot(self, other: Self) -> Self;
+
+    fn is_finite(self) -> bool;
+}
+
+macro_rules! impl_real {
+    ($($t:ident),*) => {
+        $(
+            impl Real for $t {
+                fn epsilon() -> $t { $t::EPSILON }
+
+                fn from_f64(x: f64) -> $t { x as $t }
+
+                fn to_f64(self) -> f64 { self as f64 }
+
+                fn sqrt(self) -> $t { $t::sqrt(self) }
+
+                fn abs(self) -> $t { $t::abs(self) }
+
+                fn powi(self, n: i32) -> $t { $t::powi(self, n) }
+
+                fn powf(self, n: $t) -> $t { $t::powf(self, n) }
+
+                fn exp(self) -> $t { $t::exp(self) }
+
+                fn ln(self) -> $t { $t::ln(self) }
+
+                fn acos(self) -> $t { $t::acos(self) }
+
+                fn hypot(self, other: $t) -> $t { $t::hypot(self, other) }
+
+                fn is_finite(self) -> bool { $t::is_finite(self) }
+            }
+        )*
+    };
+}
+
+impl_real!(f32, f64);
+
+/// Number types whose arithmetic is exact, such as ratios and integers modulo a prime.
+///
+/// Elimination over these needs no tolerances and no care in choosing pivots: any nonzero
+/// element will do, and a zero is really zero. Floating point numbers are deliberately not
+/// fields in this sense.
+pub trait Field: Copy + Default + PartialEq + fmt::Debug + Zero + One
+    + ops::Sub<Output = Self> + ops::Div<Output = Self> + ops::Neg<Output = Self> {}
+
+/// Primitive integers, with the overflow-aware arithmetic they provide.
+pub trait Integer: Copy + Default + Ord + fmt::Debug + fmt::Display + Zero + One {
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    fn checked_sub(self, other: Self) -> Option<Self>;
+
+    fn checked_mul(self, other: Self) -> Option<Self>;
+
+    /// `None` on division by zero, and on overflow (`MIN / -1`).
+    fn checked_div(self, other: Self) -> Option<Self>;
+
+    fn saturating_add(self, other: Self) -> Self;
+
+    fn saturating_mul(self, other: Self) -> Self;
+
+    fn wrapping_add(self, other: Self) -> Self;
+
+    fn wrapping_mul(self, other: Self) -> Self;
+}
+
+macro_rules! impl_integer {
+    ($($t:ident),*) => {
+        $(
+            impl Integer for $t {
+                fn checked_add(self, other: $t) -> Option<$t> { $t::checked_add(self, other) }
+
+                fn checked_sub(self, other: $t) -> Option<$t> { $t::checked_sub(self, other) }
+
+                fn checked_mul(self, other: $t) -> Option<$t> { $t::checked_mul(self, other) }
+
+                fn checked_div(self, other: $t) -> Option<$t> { $t::checked_div(self, other) }
+
+                fn saturating_add(self, other: $t) -> $t { $t::saturating_add(self, other) }
+
+                fn saturating_mul(self, other: $t) -> $t { $t::saturating_mul(self, other) }
+
+                fn wrapping_add(self, other: $t) -> $t { $t::wrapping_add(self, other) }
+
+                fn wrapping_mul(self, other: $t) -> $t { $t::wrapping_mul(self, other) }
+            }
+        )*
+    };
+}
+
+impl_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
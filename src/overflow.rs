@@ -0,0 +1,115 @@
+//! Integer arithmetic that deals with overflow explicitly, instead of panicking in debug
+//! builds and silently wrapping around in release builds.
+
+use crate::{Integer, Matrix};
+
+impl<T: Integer> Matrix<T> {
+    /// Adds two matrices, or returns `None` if any element overflows.
+    /// Like `+`, this panics if the shapes differ.
+    pub fn checked_add(&self, o: &Matrix<T>) -> Option<Matrix<T>> {
+        self.check_same_shape(o);
+        let contents = self.contents.iter().zip(&o.contents)
+            .map(|(&a, &b)| a.checked_add(b))
+            .collect::<Option<Vec<T>>>()?;
+
+        Some(Matrix { rows: self.rows, columns: self.columns, contents })
+    }
+
+    /// Multiplies two matrices, or returns `None` if any product or partial sum overflows.
+    /// Like `*`, this panics if the inner dimensions don't match.
+    pub fn checked_mul(&self, o: &Matrix<T>) -> Option<Matrix<T>> {
+        self.check_multiplicable(o);
+        let mut contents = Vec::with_capacity(self.rows * o.columns);
+        for r in 0..self.rows {
+            for c in 0..o.columns {
+                let mut acc = T::zero();
+                for k in 0..self.columns {
+                    let product = self.contents[r * self.columns + k].checked_mul(o.contents[k * o.columns + c])?;
+                    acc = acc.checked_add(product)?;
+                }
+                contents.push(acc);
+            }
+        }
+
+        Some(Matrix { rows: self.rows, columns: o.columns, contents })
+    }
+
+    /// Adds two matrices, clamping every element to the range of `T`.
+    pub fn saturating_add(&self, o: &Matrix<T>) -> Matrix<T> {
+        self.check_same_shape(o);
+        self.zip_with(o, |&a, &b| a.saturating_add(b))
+    }
+
+    /// Multiplies two matrices, clamping every product and partial sum to the range of `T`.
+    ///
+    /// Note that with mixed signs the result depends on the order of summation once something
+    /// saturated, so this is only a guard against wild results, not an exact answer.
+    pub fn saturating_mul(&self, o: &Matrix<T>) -> Matrix<T> {
+        self.check_multiplicable(o);
+        self.mul_with(o, T::zero, |&a, &b| a.saturating_add(b), |&a, &b| a.saturating_mul(b))
+    }
+
+    /// Adds two matrices, wrapping around at the bounds of `T`.
+    pub fn wrapping_add(&self, o: &Matrix<T>) -> Matrix<T> {
+        self.check_same_shape(o);
+        self.zip_with(o, |&a, &b| a.wrapping_add(b))
+    }
+
+    /// Multiplies two matrices, wrapping around at the bounds of `T`. This is exact modulo
+    /// 2 to the power of the bit width of `T`.
+    pub fn wrapping_mul(&self, o: &Matrix<T>) -> Matrix<T> {
+        self.check_multiplicable(o);
+        self.mul_with(o, T::zero, |&a, &b| a.wrapping_add(b), |&a, &b| a.wrapping_mul(b))
+    }
+
+    fn check_same_shape(&self, o: &Matrix<T>) {
+        assert!(self.columns == o.columns && self.rows == o.rows, "Can only add matrices of the same dimension.");
+    }
+
+    fn check_multiplicable(&self, o: &Matrix<T>) {
+        assert!(self.columns == o.rows,
+            "Matrices of dimensions ({}, {}) and ({}, {}) aren't multiplicable.",
+            self.rows, self.columns, o.rows, o.columns);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked() {
+        let a: Matrix<i8> = matrix![100, 1; 1, 1];
+
+        assert_eq!(a.checked_add(&Matrix::ones(2, 2)), Some(matrix![101, 2; 2, 2]));
+        assert_eq!(a.checked_add(&a), None);
+        assert_eq!(a.checked_mul(&Matrix::identity(2)), Some(a.clone()));
+        assert_eq!(a.checked_mul(&a), None);
+    }
+
+    #[test]
+    fn saturating() {
+        let a: Matrix<u8> = matrix![200, 1];
+        let b: Matrix<u8> = matrix![100, 1];
+
+        assert_eq!(a.saturating_add(&b), matrix![255, 2]);
+        assert_eq!(a.saturating_mul(&b.transpose()), matrix![255]);
+    }
+
+    #[test]
+    fn wrapping() {
+        let a: Matrix<u8> = matrix![200, 1];
+        let b: Matrix<u8> = matrix![100, 1];
+
+        assert_eq!(a.wrapping_add(&b), matrix![44, 2]);
+        // 200 * 100 + 1 = 20001 = 78 * 256 + 33
+        assert_eq!(a.wrapping_mul(&b.transpose()), matrix![33]);
+    }
+
+    #[test]
+    #[should_panic(expected = "aren't multiplicable")]
+    fn checked_mismatch() {
+        let a: Matrix<i32> = Matrix::zeroes(2, 3);
+        a.checked_mul(&a);
+    }
+}
@@ -0,0 +1,160 @@
+//! Permutation matrices, stored as the permutation itself.
+
+use std::ops;
+
+use crate::{Matrix, One, Zero};
+
+/// An n×n permutation matrix `P`, stored as n indices instead of n² elements.
+///
+/// Row `i` of `P A` is row `indices()[i]` of `A`, so `P` has its one in row `i` at column
+/// `indices()[i]`. Applying it with `*` moves whole rows or columns around in O(n) row or
+/// column copies, instead of the O(n³) of a dense product.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PermutationMatrix {
+    perm: Vec<usize>
+}
+
+impl PermutationMatrix {
+    /// The permutation matrix taking row `perm[i]` to row `i`. This panics if `perm` isn't a
+    /// permutation of `0..perm.len()`.
+    pub fn new(perm: Vec<usize>) -> PermutationMatrix {
+        let mut seen = vec![false; perm.len()];
+        for &i in &perm {
+            assert!(i < perm.len() && !seen[i], "{:?} isn't a permutation of 0..{}.", perm, perm.len());
+            seen[i] = true;
+        }
+        PermutationMatrix { perm }
+    }
+
+    /// The n×n identity.
+    pub fn identity(n: usize) -> PermutationMatrix {
+        PermutationMatrix { perm: (0..n).collect() }
+    }
+
+    /// The number of rows, which is also the number of columns.
+    pub fn size(&self) -> usize {
+        self.perm.len()
+    }
+
+    /// The column of the one in each row.
+    pub fn indices(&self) -> &[usize] {
+        &self.perm
+    }
+
+    /// The inverse, which is also the transpose.
+    pub fn inverse(&self) -> PermutationMatrix {
+        let mut inverse = vec![0; self.perm.len()];
+        for (i, &p) in self.perm.iter().enumerate() {
+            inverse[p] = i;
+        }
+        PermutationMatrix { perm: inverse }
+    }
+
+    /// The determinant: 1 for an even permutation and -1 for an odd one.
+    pub fn sign(&self) -> i32 {
+        if self.is_odd() { -1 } else { 1 }
+    }
+
+    /// Whether the permutation is a product of an odd number of transpositions.
+    pub fn is_odd(&self) -> bool {
+        // a cycle of length k takes k - 1 transpositions
+        let mut seen = vec![false; self.perm.len()];
+        let mut transpositions = 0;
+        for start in 0..self.perm.len() {
+            let mut i = start;
+            let mut length = 0;
+            while !seen[i] {
+                seen[i] = true;
+                i = self.perm[i];
+                length += 1;
+            }
+            if length > 1 {
+                transpositions += length - 1;
+            }
+        }
+        transpositions % 2 == 1
+    }
+
+    /// The dense n×n matrix.
+    pub fn to_dense<T: Default + Clone + Zero + One>(&self) -> Matrix<T> {
+        let n = self.perm.len();
+        Matrix::from_fn(n, n, |r, c| if self.perm[r] == c { T::one() } else { T::zero() })
+    }
+}
+
+impl<T: Default + Clone + Zero + One> From<PermutationMatrix> for Matrix<T> {
+    fn from(p: PermutationMatrix) -> Matrix<T> {
+        p.to_dense()
+    }
+}
+
+/// The product `P Q`, which applies `Q` first and then `P` when multiplying from the left.
+impl ops::Mul<&PermutationMatrix> for &PermutationMatrix {
+    type Output = PermutationMatrix;
+    fn mul(self, o: &PermutationMatrix) -> PermutationMatrix {
+        assert!(self.size() == o.size(),
+            "Permutations of {} and {} elements can't be composed.", self.size(), o.size());
+        PermutationMatrix { perm: self.perm.iter().map(|&i| o.perm[i]).collect() }
+    }
+}
+
+/// `P A`, which reorders the rows of `A`.
+impl<T: Default + Clone> ops::Mul<&Matrix<T>> for &PermutationMatrix {
+    type Output = Matrix<T>;
+    fn mul(self, a: &Matrix<T>) -> Matrix<T> {
+        assert!(self.size() == a.rows,
+            "Matrices of dimensions ({}, {}) and ({}, {}) aren't multiplicable.",
+            self.size(), self.size(), a.rows, a.columns);
+        let contents = self.perm.iter()
+            .flat_map(|&r| a.contents[r * a.columns..(r + 1) * a.columns].iter().cloned())
+            .collect();
+        Matrix { rows: a.rows, columns: a.columns, contents }
+    }
+}
+
+/// `A P`, which reorders the columns of `A`: column `j` of the product is column `i` of `A`
+/// for the `i` with `indices()[i] == j`.
+impl<T: Default + Clone> ops::Mul<&PermutationMatrix> for &Matrix<T> {
+    type Output = Matrix<T>;
+    fn mul(self, p: &PermutationMatrix) -> Matrix<T> {
+        assert!(self.columns == p.size(),
+            "Matrices of dimensions ({}, {}) and ({}, {}) aren't multiplicable.",
+            self.rows, self.columns, p.size(), p.size());
+        let inverse = p.inverse();
+        Matrix::from_fn(self.rows, self.columns, |r, c| self.contents[r * self.columns + inverse.perm[c]].clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_dense() {
+        let p = PermutationMatrix::new(vec![2, 0, 3, 1]);
+        let q = PermutationMatrix::new(vec![1, 3, 0, 2]);
+        let a: Matrix<f64> = Matrix::from_fn(4, 3, |r, c| (r * 3 + c) as f64);
+        let dense: Matrix<f64> = p.to_dense();
+
+        assert_eq!(&p * &a, &dense * &a);
+        assert_eq!(&a.transpose() * &p, &a.transpose() * &dense);
+        assert_eq!((&p * &q).to_dense::<f64>(), &dense * &q.to_dense());
+        assert_eq!(&p * &p.inverse(), PermutationMatrix::identity(4));
+        assert_eq!(p.inverse().to_dense::<f64>(), dense.transpose());
+    }
+
+    #[test]
+    fn sign() {
+        assert_eq!(PermutationMatrix::identity(3).sign(), 1);
+        assert_eq!(PermutationMatrix::new(vec![1, 0, 2]).sign(), -1);
+        assert_eq!(PermutationMatrix::new(vec![1, 2, 0]).sign(), 1);
+        let p = PermutationMatrix::new(vec![2, 0, 3, 1]);
+        assert_eq!(p.sign() as f64, p.to_dense::<f64>().det().round());
+    }
+
+    #[test]
+    #[should_panic(expected = "isn't a permutation")]
+    fn not_a_permutation() {
+        PermutationMatrix::new(vec![0, 2, 2]);
+    }
+}
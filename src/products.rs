@@ -0,0 +1,308 @@
+//! Products other than the ordinary matrix product.
+
+use std::borrow::Borrow;
+use std::ops;
+
+use crate::{view, Matrix, Vector, Zero};
+
+impl<T: Default + Copy + ops::Mul<Output = T>> Matrix<T> {
+    /// The Kronecker product, which replaces every element `a` of `self` with the block `a * o`.
+    ///
+    /// For an m×n and a p×q matrix this gives an (m·p)×(n·q) matrix.
+    pub fn kronecker(&self, o: &Matrix<T>) -> Matrix<T> {
+        Matrix::from_fn(self.rows * o.rows, self.columns * o.columns, |r, c| {
+            let a = self.contents[(r / o.rows) * self.columns + c / o.columns];
+            let b = o.contents[(r % o.rows) * o.columns + c % o.columns];
+            a * b
+        })
+    }
+}
+
+impl<T: Default + Copy + ops::Mul<Output = T>> Matrix<T> {
+    /// The outer product `u vᵀ`, a `u.len()` by `v.len()` matrix of rank (at most) one
+    /// with `u[r] * v[c]` at position `(r, c)`.
+    ///
+    /// This is the same as `&u * &v.transpose()` with a `ColVector` and a `RowVector`.
+    pub fn outer(u: &Vector<T>, v: &Vector<T>) -> Matrix<T> {
+        Matrix::from_fn(u.len(), v.len(), |r, c| u[r] * v[c])
+    }
+}
+
+impl<T: Default + Clone + Copy + ops::Add<Output = T> + ops::Mul<Output = T>> Matrix<T> {
+    /// Multiplies a chain of matrices, `factors[0] * factors[1] * ...`, in the cheapest order.
+    ///
+    /// Matrix multiplication is associative, but the cost isn't: for a 10×1000, a 1000×10 and a
+    /// 10×1000 matrix, `(AB)C` takes a hundredth of the work of `A(BC)`. This picks the
+    /// parenthesization with the fewest scalar multiplications by dynamic programming first.
+    ///
+    /// This panics if the list is empty or two neighbours aren't multiplicable.
+    pub fn multi_product<M: Borrow<Matrix<T>>>(factors: &[M]) -> Matrix<T> {
+        assert!(!factors.is_empty(), "Can't multiply an empty list of matrices.");
+        for pair in factors.windows(2) {
+            let (a, b) = (pair[0].borrow(), pair[1].borrow());
+            assert!(a.columns == b.rows,
+                "Matrices of dimensions ({}, {}) and ({}, {}) aren't multiplicable.",
+                a.rows, a.columns, b.rows, b.columns);
+        }
+
+        let split = chain_order(factors);
+        chain_product(factors, &split, 0, factors.len() - 1)
+            .unwrap_or_else(|| factors[0].borrow().clone())
+    }
+}
+
+impl<T: Default + Copy + Zero + ops::Mul<Output = T>> Matrix<T> {
+    /// The bilinear form `xᵀ A y`, computed directly without any intermediate matrices.
+    ///
+    /// This panics unless `x` has as many elements as the matrix has rows and `y` as many as it has columns.
+    pub fn bilinear_form(&self, x: &Vector<T>, y: &Vector<T>) -> T {
+        assert!(x.len() == self.rows && y.len() == self.columns,
+            "Vectors of lengths {} and {} don't fit a bilinear form of dimensions ({}, {}).",
+            x.len(), y.len(), self.rows, self.columns);
+
+        (0..self.rows).fold(T::zero(), |acc, r| {
+            let row = &self.contents[r * self.columns..(r + 1) * self.columns];
+            let ay = row.iter().zip(y).fold(T::zero(), |s, (&a, &b)| s + a * b);
+            acc + x[r] * ay
+        })
+    }
+
+    /// The quadratic form `xᵀ A x`, e.g. the energy of a state or a squared Mahalanobis distance.
+    pub fn quadratic_form(&self, x: &Vector<T>) -> T {
+        self.bilinear_form(x, x)
+    }
+}
+
+impl<T: Default + Copy + Zero + ops::Mul<Output = T>> Matrix<T> {
+    /// The Gram matrix `AᵀA` of the columns, with their inner products at `(i, j)`.
+    ///
+    /// The result is symmetric, so only its upper triangle is computed and then mirrored,
+    /// which is about half the work of `&a.transpose() * &a`. The sums run along the rows of
+    /// `A`, so the elements are read in storage order.
+    pub fn gram(&self) -> Matrix<T> {
+        let n = self.columns;
+        let mut g = Matrix::zeroes(n, n);
+        for row in self.contents.chunks(n.max(1)) {
+            for (i, &a) in row.iter().enumerate() {
+                for (j, &b) in row.iter().enumerate().skip(i) {
+                    g.contents[i * n + j] = g.contents[i * n + j] + a * b;
+                }
+            }
+        }
+        mirror_upper(&mut g);
+        g
+    }
+
+    /// The Gram matrix `AAᵀ` of the rows, with their inner products at `(i, j)`. Like `gram`,
+    /// this computes only the upper triangle.
+    pub fn row_gram(&self) -> Matrix<T> {
+        self.kernel_matrix(|x, y| x.iter().zip(y).fold(T::zero(), |acc, (&a, &b)| acc + a * b))
+    }
+}
+
+impl<T: Default + Copy> Matrix<T> {
+    /// The symmetric matrix `K` with `K[(i, j)] = kernel(row i, row j)`, treating every row as a
+    /// sample, e.g. a Gaussian or polynomial kernel for kernel methods.
+    ///
+    /// The kernel is assumed to be symmetric, and is only evaluated for `i <= j`: once per pair
+    /// of rows and once for every row with itself.
+    pub fn kernel_matrix<F: FnMut(&[T], &[T]) -> T>(&self, mut kernel: F) -> Matrix<T> {
+        let (n, width) = (self.rows, self.columns);
+        let row = |i: usize| &self.contents[i * width..(i + 1) * width];
+        let mut k = Matrix { rows: n, columns: n, contents: vec![T::default(); n * n] };
+        for i in 0..n {
+            for j in i..n {
+                k.contents[i * n + j] = kernel(row(i), row(j));
+            }
+        }
+        mirror_upper(&mut k);
+        k
+    }
+}
+
+// copies the upper triangle of a square matrix into the lower one
+fn mirror_upper<T: Default + Copy>(m: &mut Matrix<T>) {
+    let n = m.rows;
+    for i in 0..n {
+        for j in 0..i {
+            m.contents[i * n + j] = m.contents[j * n + i];
+        }
+    }
+}
+
+// the classic matrix chain DP. `split[i][j]` is where the product of factors i through j
+// is best split into (i..=k) and (k + 1..=j)
+fn chain_order<T: Default, M: Borrow<Matrix<T>>>(factors: &[M]) -> Vec<Vec<usize>> {
+    let n = factors.len();
+    // factor i is dims[i] by dims[i + 1]
+    let mut dims: Vec<usize> = factors.iter().map(|m| m.borrow().rows).collect();
+    dims.push(factors[n - 1].borrow().columns);
+
+    let mut cost = vec![vec![0usize; n]; n];
+    let mut split = vec![vec![0usize; n]; n];
+    for length in 2..=n {
+        for i in 0..=n - length {
+            let j = i + length - 1;
+            cost[i][j] = usize::MAX;
+            for k in i..j {
+                let c = cost[i][k] + cost[k + 1][j] + dims[i] * dims[k + 1] * dims[j + 1];
+                if c < cost[i][j] {
+                    cost[i][j] = c;
+                    split[i][j] = k;
+                }
+            }
+        }
+    }
+    split
+}
+
+// multiplies factors i through j, or returns None if that's just the single factor i
+fn chain_product<T, M>(factors: &[M], split: &[Vec<usize>], i: usize, j: usize) -> Option<Matrix<T>> where
+    T: Default + Clone + Copy + ops::Add<Output = T> + ops::Mul<Output = T>,
+    M: Borrow<Matrix<T>> {
+    if i == j {
+        return None;
+    }
+
+    let k = split[i][j];
+    let left = chain_product(factors, split, i, k);
+    let right = chain_product(factors, split, k + 1, j);
+    Some(view::mul(
+        left.as_ref().unwrap_or_else(|| factors[i].borrow()),
+        right.as_ref().unwrap_or_else(|| factors[k + 1].borrow())
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kronecker() {
+        let a: Matrix<i32> = matrix![1, 2;
+                                     3, 4];
+        let b: Matrix<i32> = matrix![0, 5];
+
+        assert_eq!(a.kronecker(&b), matrix![0, 5, 0, 10;
+                                            0, 15, 0, 20]);
+        assert_eq!(b.kronecker(&a), matrix![0, 0, 5, 10;
+                                            0, 0, 15, 20]);
+    }
+
+    #[test]
+    fn kronecker_with_identity() {
+        let a: Matrix<i32> = matrix![1, 2;
+                                     3, 4];
+        let blocks = Matrix::identity(2).kronecker(&a);
+
+        assert_eq!(blocks.shape(), (4, 4).into());
+        assert_eq!(blocks.submatrix(2..4, 2..4), a);
+        assert_eq!(blocks.submatrix(0..2, 2..4), Matrix::zeroes(2, 2));
+        assert!(a.kronecker(&Matrix::zeroes(0, 3)).is_empty());
+    }
+
+    #[test]
+    fn outer() {
+        let u = Vector::new(vec![1, 2, 3]);
+        let v = Vector::new(vec![4, 5]);
+
+        let m = Matrix::outer(&u, &v);
+        assert_eq!(m, matrix![4, 5;
+                              8, 10;
+                              12, 15]);
+        assert_eq!(m, &u * &v.clone().transpose());
+    }
+
+    #[test]
+    fn rank_one_update() {
+        let mut covariance: Matrix<f64> = Matrix::zeroes(2, 2);
+        for sample in &[vec![1.0, -1.0], vec![-1.0, 1.0]] {
+            let x = Vector::new(sample.clone());
+            covariance += Matrix::outer(&x, &x);
+        }
+
+        assert_eq!(covariance, matrix![2.0, -2.0; -2.0, 2.0]);
+    }
+
+    #[test]
+    fn chain_order() {
+        let a: Matrix<i32> = Matrix::ones(10, 100);
+        let b: Matrix<i32> = Matrix::ones(100, 5);
+        let c: Matrix<i32> = Matrix::ones(5, 50);
+
+        // (AB)C costs 7500 multiplications, A(BC) 75000
+        let split = super::chain_order(&[&a, &b, &c]);
+        assert_eq!(split[0][2], 1);
+
+        let product = Matrix::multi_product(&[&a, &b, &c]);
+        assert_eq!(product, &(&a * &b) * &c);
+    }
+
+    #[test]
+    fn chain_products() {
+        let a: Matrix<i32> = matrix![1, 2; 3, 4];
+        let b: Matrix<i32> = matrix![0, 1; 1, 0];
+        let v: Matrix<i32> = matrix![1; -1];
+
+        assert_eq!(Matrix::multi_product(&[&a]), a);
+        assert_eq!(Matrix::multi_product(&[&a, &b, &a, &v]), &(&(&a * &b) * &a) * &v);
+    }
+
+    #[test]
+    #[should_panic(expected = "Matrices of dimensions (2, 2) and (3, 1) aren't multiplicable.")]
+    fn broken_chain() {
+        let a: Matrix<i32> = Matrix::zeroes(2, 2);
+        let b: Matrix<i32> = Matrix::zeroes(3, 1);
+        Matrix::multi_product(&[&a, &a, &b]);
+    }
+
+    #[test]
+    fn forms() {
+        let a: Matrix<i32> = matrix![2, 1;
+                                     1, 3];
+        let x = Vector::new(vec![1, 2]);
+        let y = Vector::new(vec![-1, 1]);
+
+        assert_eq!(a.quadratic_form(&x), 2 + 2 + 2 + 12);
+        assert_eq!(a.bilinear_form(&x, &y), x.dot(&(&a * &y)));
+
+        let wide: Matrix<i32> = matrix![1, 2, 3];
+        assert_eq!(wide.bilinear_form(&Vector::new(vec![2]), &Vector::new(vec![1, 1, 1])), 12);
+    }
+
+    #[test]
+    #[should_panic(expected = "Vectors of lengths 2 and 2 don't fit a bilinear form of dimensions (1, 3).")]
+    fn form_mismatch() {
+        let wide: Matrix<i32> = matrix![1, 2, 3];
+        wide.quadratic_form(&Vector::new(vec![1, 1]));
+    }
+
+    #[test]
+    fn gram_matrices() {
+        let a: Matrix<i32> = matrix![1, 2, 0;
+                                     -1, 3, 4];
+
+        assert_eq!(a.gram(), &a.transpose() * &a);
+        assert_eq!(a.row_gram(), &a * &a.transpose());
+        assert_eq!(a.row_gram(), matrix![5, 5; 5, 26]);
+        assert_eq!(Matrix::<i32>::zeroes(3, 0).gram(), Matrix::zeroes(0, 0));
+    }
+
+    #[test]
+    fn kernel_matrix() {
+        let samples: Matrix<f64> = matrix![0.0, 0.0;
+                                           1.0, 0.0;
+                                           0.0, 2.0];
+        let mut evaluations = 0;
+        let k = samples.kernel_matrix(|x, y| {
+            evaluations += 1;
+            let d2 = x.iter().zip(y).fold(0.0, |acc, (a, b)| acc + (a - b) * (a - b));
+            (-d2 / 2.0).exp()
+        });
+
+        assert_eq!(evaluations, 6);
+        assert_eq!(k, k.transpose());
+        assert_eq!(k.diagonal(), vec![1.0; 3]);
+        assert!((k[(1, 2)] - (-2.5f64).exp()).abs() < 1e-15);
+    }
+}
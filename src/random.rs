@@ -0,0 +1,127 @@
+//! Random matrix generation, enabled with the `rand` feature.
+//!
+//! The generator is a plain SplitMix64, which is fast and statistically good enough for
+//! simulations and tests, but it is NOT suitable for anything cryptographic. Any other
+//! generator can be plugged in by implementing `RandomSource` for it.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::Matrix;
+
+/// Anything that can produce a stream of random 64-bit integers.
+pub trait RandomSource {
+    fn next_u64(&mut self) -> u64;
+
+    /// Returns a uniformly distributed float in `[0, 1)`.
+    fn next_f64(&mut self) -> f64 {
+        // the top 53 bits fill the mantissa exactly
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+/// A small, seedable SplitMix64 generator.
+#[derive(Clone, Debug)]
+pub struct SplitMix64 {
+    state: u64
+}
+
+impl SplitMix64 {
+    /// Creates a generator from a fixed seed. The same seed always gives the same sequence.
+    pub fn seed_from_u64(seed: u64) -> SplitMix64 {
+        SplitMix64 { state: seed }
+    }
+
+    /// Creates a generator seeded from the system clock.
+    pub fn from_time() -> SplitMix64 {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_nanos() as u64);
+        SplitMix64::seed_from_u64(nanos)
+    }
+}
+
+impl RandomSource for SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
+
+/// The distribution to draw matrix elements from.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Distribution {
+    /// Uniformly distributed over `[low, high)`.
+    Uniform { low: f64, high: f64 },
+    /// Normally distributed with the given mean and standard deviation.
+    Normal { mean: f64, std_dev: f64 }
+}
+
+impl Distribution {
+    /// Draws a single sample using the given source.
+    pub fn sample<R: RandomSource>(&self, rng: &mut R) -> f64 {
+        match *self {
+            Distribution::Uniform { low, high } => low + (high - low) * rng.next_f64(),
+            Distribution::Normal { mean, std_dev } => {
+                // Box-Muller; 1 - u keeps the logarithm away from zero
+                let u = 1.0 - rng.next_f64();
+                let v = rng.next_f64();
+                let z = (-2.0 * u.ln()).sqrt() * (2.0 * std::f64::consts::PI * v).cos();
+                mean + std_dev * z
+            }
+        }
+    }
+}
+
+impl Matrix<f64> {
+    /// Returns a matrix of the given size filled with samples from `distribution`.
+    ///
+    /// The generator is seeded from the system clock, so every call gives different results.
+    /// Use `random_with_rng` for reproducible output.
+    pub fn random(rows: usize, columns: usize, distribution: Distribution) -> Matrix<f64> {
+        Matrix::random_with_rng(rows, columns, distribution, &mut SplitMix64::from_time())
+    }
+
+    /// Returns a matrix of the given size filled with samples from `distribution`,
+    /// drawn from the supplied generator.
+    pub fn random_with_rng<R: RandomSource>(rows: usize, columns: usize, distribution: Distribution, rng: &mut R) -> Matrix<f64> {
+        Matrix::from_fn(rows, columns, |_, _| distribution.sample(rng))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seeded_is_reproducible() {
+        let dist = Distribution::Uniform { low: -1.0, high: 1.0 };
+        let a = Matrix::random_with_rng(4, 5, dist, &mut SplitMix64::seed_from_u64(42));
+        let b = Matrix::random_with_rng(4, 5, dist, &mut SplitMix64::seed_from_u64(42));
+
+        assert_eq!(a.contents, b.contents);
+    }
+
+    #[test]
+    fn uniform_stays_in_range() {
+        let dist = Distribution::Uniform { low: 2.0, high: 3.0 };
+        let m = Matrix::random_with_rng(20, 20, dist, &mut SplitMix64::seed_from_u64(7));
+
+        assert!(m.contents.iter().all(|&x| (2.0..3.0).contains(&x)));
+    }
+
+    #[test]
+    fn normal_moments() {
+        let dist = Distribution::Normal { mean: 5.0, std_dev: 2.0 };
+        let m = Matrix::random_with_rng(100, 100, dist, &mut SplitMix64::seed_from_u64(1));
+
+        let n = m.contents.len() as f64;
+        let mean = m.contents.iter().sum::<f64>() / n;
+        let var = m.contents.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / n;
+
+        assert!((mean - 5.0).abs() < 0.1);
+        assert!((var.sqrt() - 2.0).abs() < 0.1);
+    }
+}
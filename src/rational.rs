@@ -0,0 +1,234 @@
+//! Exact rational numbers.
+//!
+//! `Ratio` has the same basic methods as `num_rational::Ratio`, so code written against one
+//! carries over to the other. Since it is a `Field`, matrices of ratios can be reduced, solved and
+//! inverted without rounding (`rref_exact`, `solve_exact`, `inverse_exact`), so that answers
+//! come out as `3/7` rather than `0.42857142`. The numerator and denominator are primitive
+//! integers, so long computations can still overflow them, with the usual consequences for
+//! primitive integer arithmetic.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops;
+
+use crate::{Field, Integer, One, Zero};
+
+/// A fraction `numer / denom`, always kept in lowest terms with a positive denominator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Ratio<T> {
+    numer: T,
+    denom: T
+}
+
+pub type Rational32 = Ratio<i32>;
+pub type Rational64 = Ratio<i64>;
+
+impl<T> Ratio<T> where
+    T: Integer + ops::Sub<Output = T> + ops::Div<Output = T> + ops::Rem<Output = T> + ops::Neg<Output = T> {
+    /// The fraction `numer / denom`, reduced to lowest terms. This panics if `denom` is zero.
+    pub fn new(numer: T, denom: T) -> Ratio<T> {
+        if denom.is_zero() {
+            panic!("The denominator of a ratio can't be zero.");
+        }
+        let g = gcd(numer, denom);
+        Ratio { numer: numer / g, denom: denom / g }
+    }
+
+    pub fn from_integer(n: T) -> Ratio<T> {
+        Ratio { numer: n, denom: T::one() }
+    }
+
+    pub fn numer(&self) -> &T {
+        &self.numer
+    }
+
+    pub fn denom(&self) -> &T {
+        &self.denom
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denom == T::one()
+    }
+
+    /// The reciprocal. This panics for zero.
+    pub fn recip(&self) -> Ratio<T> {
+        Ratio::new(self.denom, self.numer)
+    }
+}
+
+macro_rules! impl_ratio {
+    ($($t:ident),*) => {
+        $(
+            impl Ratio<$t> {
+                /// The nearest floating point number.
+                pub fn to_f64(&self) -> f64 {
+                    self.numer as f64 / self.denom as f64
+                }
+            }
+
+            impl Default for Ratio<$t> {
+                fn default() -> Ratio<$t> {
+                    Self::from_integer(0)
+                }
+            }
+
+            impl From<$t> for Ratio<$t> {
+                fn from(n: $t) -> Ratio<$t> {
+                    Self::from_integer(n)
+                }
+            }
+
+            impl ops::Add for Ratio<$t> {
+                type Output = Ratio<$t>;
+                fn add(self, o: Ratio<$t>) -> Ratio<$t> {
+                    // dividing out the common part of the denominators first keeps the
+                    // intermediates small
+                    let g = gcd(self.denom, o.denom);
+                    let numer = self.numer * (o.denom / g) + o.numer * (self.denom / g);
+                    Self::new(numer, self.denom / g * o.denom)
+                }
+            }
+
+            impl ops::Sub for Ratio<$t> {
+                type Output = Ratio<$t>;
+                fn sub(self, o: Ratio<$t>) -> Ratio<$t> {
+                    self + -o
+                }
+            }
+
+            impl ops::Mul for Ratio<$t> {
+                type Output = Ratio<$t>;
+                fn mul(self, o: Ratio<$t>) -> Ratio<$t> {
+                    let (a, b) = (gcd(self.numer, o.denom), gcd(o.numer, self.denom));
+                    Self::new((self.numer / a) * (o.numer / b), (self.denom / b) * (o.denom / a))
+                }
+            }
+
+            impl ops::Div for Ratio<$t> {
+                type Output = Ratio<$t>;
+                fn div(self, o: Ratio<$t>) -> Ratio<$t> {
+                    if o.numer == 0 {
+                        panic!("Can't divide a ratio by zero.");
+                    }
+                    self * o.recip()
+                }
+            }
+
+            impl ops::Neg for Ratio<$t> {
+                type Output = Ratio<$t>;
+                fn neg(self) -> Ratio<$t> {
+                    Ratio { numer: -self.numer, denom: self.denom }
+                }
+            }
+
+            impl Zero for Ratio<$t> {
+                fn zero() -> Ratio<$t> {
+                    Self::from_integer(0)
+                }
+
+                fn is_zero(&self) -> bool {
+                    self.numer == 0
+                }
+            }
+
+            impl One for Ratio<$t> {
+                fn one() -> Ratio<$t> {
+                    Self::from_integer(1)
+                }
+            }
+
+            impl Field for Ratio<$t> {}
+
+            impl Ord for Ratio<$t> {
+                fn cmp(&self, o: &Ratio<$t>) -> Ordering {
+                    // compares the continued fractions term by term, which unlike cross
+                    // multiplying can't overflow. The denominators stay positive throughout
+                    let (mut an, mut ad, mut bn, mut bd) = (self.numer, self.denom, o.numer, o.denom);
+                    loop {
+                        let (qa, ra) = (an.div_euclid(ad), an.rem_euclid(ad));
+                        let (qb, rb) = (bn.div_euclid(bd), bn.rem_euclid(bd));
+                        if qa != qb {
+                            return qa.cmp(&qb);
+                        }
+                        match (ra == 0, rb == 0) {
+                            (true, true) => return Ordering::Equal,
+                            (true, false) => return Ordering::Less,
+                            (false, true) => return Ordering::Greater,
+                            // ra/ad < rb/bd exactly when bd/rb < ad/ra
+                            (false, false) => (an, ad, bn, bd) = (bd, rb, ad, ra)
+                        }
+                    }
+                }
+            }
+
+            impl PartialOrd for Ratio<$t> {
+                fn partial_cmp(&self, o: &Ratio<$t>) -> Option<Ordering> {
+                    Some(self.cmp(o))
+                }
+            }
+
+            /// Renders as `3/7`, or just `3` for integers.
+            impl fmt::Display for Ratio<$t> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    if self.denom == 1 {
+                        f.pad(&self.numer.to_string())
+                    } else {
+                        f.pad(&format!("{}/{}", self.numer, self.denom))
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_ratio!(i8, i16, i32, i64, i128, isize);
+
+// the greatest common divisor, with the sign of b so that dividing by it makes the
+// denominator positive. Only zero if both are
+fn gcd<T: Integer + ops::Rem<Output = T> + ops::Neg<Output = T>>(a: T, b: T) -> T {
+    let (mut x, mut y) = (a, b);
+    while !y.is_zero() {
+        let r = x % y;
+        x = y;
+        y = r;
+    }
+    if (x < T::zero()) != (b < T::zero()) { -x } else { x }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn r(n: i64, d: i64) -> Rational64 {
+        Rational64::new(n, d)
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(r(2, -4), r(-1, 2));
+        assert_eq!(*r(6, 8).numer(), 3);
+        assert_eq!(*r(6, 8).denom(), 4);
+        assert_eq!(r(1, 6) + r(1, 3), r(1, 2));
+        assert_eq!(r(1, 6) - r(1, 3), r(-1, 6));
+        assert_eq!(r(2, 3) * r(9, 4), r(3, 2));
+        assert_eq!(r(2, 3) / r(-4, 9), r(-3, 2));
+        assert!(r(1, 3) < r(1, 2) && r(-1, 2) < r(-1, 3));
+        assert!((r(3, 7) * r(7, 3)).is_integer());
+        assert_eq!(format!("{}|{:>5}", r(3, 7), r(4, 2)), "3/7|    2");
+        assert_eq!(Rational64::default(), Rational64::zero());
+    }
+
+    #[test]
+    fn ordering_at_the_extremes() {
+        let big = |n: i128, d: i128| Ratio::<i128>::new(n, d);
+        assert_eq!(big(i128::MAX, 2).cmp(&big(i128::MAX, 4)), Ordering::Greater);
+        assert!(big(i128::MAX - 1, i128::MAX) > big(i128::MAX - 2, i128::MAX - 1));
+        assert!(big(i128::MIN + 1, 3) < big(i128::MIN + 1, 5));
+        assert_eq!(big(i128::MAX, 7).cmp(&big(i128::MAX, 7)), Ordering::Equal);
+
+        let mut sorted = vec![big(i128::MAX, 3), big(-1, i128::MAX), big(i128::MAX, 2), big(0, 1)];
+        sorted.sort();
+        assert_eq!(sorted, vec![big(-1, i128::MAX), big(0, 1), big(i128::MAX, 3), big(i128::MAX, 2)]);
+        assert!(Ratio::<i64>::new(i64::MIN + 1, i64::MAX) < r(-1, 1) + r(1, i64::MAX));
+    }
+}
@@ -0,0 +1,224 @@
+//! Reductions of a whole matrix, or of each row or column, down to single values.
+
+use crate::{Matrix, One, Zero};
+
+impl<T: Default> Matrix<T> {
+    /// Folds every row into a single value, starting from `init` each time.
+    pub fn fold_rows<U: Clone, F: FnMut(U, &T) -> U>(&self, init: U, mut f: F) -> Vec<U> {
+        (0..self.rows).map(|r| {
+            self.contents[r * self.columns..(r + 1) * self.columns].iter().fold(init.clone(), &mut f)
+        }).collect()
+    }
+
+    /// Folds every column into a single value, starting from `init` each time.
+    pub fn fold_cols<U: Clone, F: FnMut(U, &T) -> U>(&self, init: U, mut f: F) -> Vec<U> {
+        (0..self.columns).map(|c| {
+            self.contents.iter().skip(c).step_by(self.columns).fold(init.clone(), &mut f)
+        }).collect()
+    }
+}
+
+impl<T: Default + Clone + Zero> Matrix<T> {
+    /// The sum of every row.
+    pub fn row_sums(&self) -> Vec<T> {
+        self.fold_rows(T::zero(), |acc, e| acc + e.clone())
+    }
+
+    /// The sum of every column.
+    pub fn col_sums(&self) -> Vec<T> {
+        self.fold_cols(T::zero(), |acc, e| acc + e.clone())
+    }
+
+    /// The sum of all elements. This is zero for an empty matrix.
+    pub fn sum(&self) -> T {
+        self.contents.iter().fold(T::zero(), |acc, e| acc + e.clone())
+    }
+}
+
+impl<T: Default + Clone + One> Matrix<T> {
+    /// The product of every row.
+    pub fn row_products(&self) -> Vec<T> {
+        self.fold_rows(T::one(), |acc, e| acc * e.clone())
+    }
+
+    /// The product of every column.
+    pub fn col_products(&self) -> Vec<T> {
+        self.fold_cols(T::one(), |acc, e| acc * e.clone())
+    }
+
+    /// The product of all elements. This is one for an empty matrix.
+    pub fn product(&self) -> T {
+        self.contents.iter().fold(T::one(), |acc, e| acc * e.clone())
+    }
+}
+
+impl<T: Default + Clone + PartialOrd> Matrix<T> {
+    /// The smallest element of every row, or `None` for rows without any elements.
+    pub fn row_mins(&self) -> Vec<Option<T>> {
+        self.fold_rows(None, |acc, e| pick(acc, e, |e, best| e < best))
+    }
+
+    /// The largest element of every row, or `None` for rows without any elements.
+    pub fn row_maxes(&self) -> Vec<Option<T>> {
+        self.fold_rows(None, |acc, e| pick(acc, e, |e, best| e > best))
+    }
+
+    /// The smallest element of every column, or `None` for columns without any elements.
+    pub fn col_mins(&self) -> Vec<Option<T>> {
+        self.fold_cols(None, |acc, e| pick(acc, e, |e, best| e < best))
+    }
+
+    /// The largest element of every column, or `None` for columns without any elements.
+    pub fn col_maxes(&self) -> Vec<Option<T>> {
+        self.fold_cols(None, |acc, e| pick(acc, e, |e, best| e > best))
+    }
+}
+
+impl<T: Default + Clone + PartialOrd> Matrix<T> {
+    /// The largest element together with its `(row, column)` position, or `None` for an empty matrix.
+    /// If the largest value occurs more than once, the first one in row-major order wins.
+    pub fn max(&self) -> Option<(T, (usize, usize))> {
+        self.argmax().map(|(r, c)| (self.contents[r * self.columns + c].clone(), (r, c)))
+    }
+
+    /// The smallest element together with its `(row, column)` position, or `None` for an empty matrix.
+    pub fn min(&self) -> Option<(T, (usize, usize))> {
+        self.argmin().map(|(r, c)| (self.contents[r * self.columns + c].clone(), (r, c)))
+    }
+}
+
+impl<T: Default + PartialOrd> Matrix<T> {
+    /// The `(row, column)` position of the largest element, or `None` for an empty matrix.
+    pub fn argmax(&self) -> Option<(usize, usize)> {
+        best_index(self.contents.iter(), |e, best| e > best).map(|i| (i / self.columns, i % self.columns))
+    }
+
+    /// The `(row, column)` position of the smallest element, or `None` for an empty matrix.
+    pub fn argmin(&self) -> Option<(usize, usize)> {
+        best_index(self.contents.iter(), |e, best| e < best).map(|i| (i / self.columns, i % self.columns))
+    }
+
+    /// For every row, the column of its largest element.
+    pub fn row_argmax(&self) -> Vec<Option<usize>> {
+        self.row_slices().map(|row| best_index(row.iter(), |e, best| e > best)).collect()
+    }
+
+    /// For every row, the column of its smallest element.
+    pub fn row_argmin(&self) -> Vec<Option<usize>> {
+        self.row_slices().map(|row| best_index(row.iter(), |e, best| e < best)).collect()
+    }
+
+    /// For every column, the row of its largest element. This is the classic pivot search.
+    pub fn col_argmax(&self) -> Vec<Option<usize>> {
+        (0..self.columns).map(|c| best_index(self.column_iter(c), |e, best| e > best)).collect()
+    }
+
+    /// For every column, the row of its smallest element.
+    pub fn col_argmin(&self) -> Vec<Option<usize>> {
+        (0..self.columns).map(|c| best_index(self.column_iter(c), |e, best| e < best)).collect()
+    }
+
+    fn row_slices(&self) -> impl Iterator<Item = &[T]> + '_ {
+        (0..self.rows).map(move |r| &self.contents[r * self.columns..(r + 1) * self.columns])
+    }
+
+    fn column_iter(&self, c: usize) -> impl Iterator<Item = &T> + '_ {
+        self.contents.iter().skip(c).step_by(self.columns)
+    }
+}
+
+// the position of the best element; the first of several equal ones wins
+fn best_index<'a, T: 'a, I, F>(elements: I, better: F) -> Option<usize> where
+    I: Iterator<Item = &'a T>,
+    F: Fn(&T, &T) -> bool {
+    let mut best: Option<(usize, &T)> = None;
+    for (i, e) in elements.enumerate() {
+        match best {
+            Some((_, b)) if !better(e, b) => {},
+            _ => best = Some((i, e))
+        }
+    }
+    best.map(|(i, _)| i)
+}
+
+// keeps the best element seen so far; the first of several equal ones wins
+fn pick<T: Clone, F: Fn(&T, &T) -> bool>(best: Option<T>, e: &T, better: F) -> Option<T> {
+    match best {
+        Some(b) if !better(e, &b) => Some(b),
+        _ => Some(e.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sums() {
+        let m: Matrix<i32> = matrix![1, 2, 3;
+                                     4, 5, 6];
+
+        assert_eq!(m.row_sums(), vec![6, 15]);
+        assert_eq!(m.col_sums(), vec![5, 7, 9]);
+        assert_eq!(m.sum(), 21);
+        assert_eq!(Matrix::<i32>::zeroes(0, 3).sum(), 0);
+    }
+
+    #[test]
+    fn products() {
+        let m: Matrix<i32> = matrix![1, 2, 3;
+                                     4, 5, 6];
+
+        assert_eq!(m.row_products(), vec![6, 120]);
+        assert_eq!(m.col_products(), vec![4, 10, 18]);
+        assert_eq!(m.product(), 720);
+    }
+
+    #[test]
+    fn extrema() {
+        let m: Matrix<f64> = matrix![1.0, -2.0;
+                                     0.5,  7.0];
+
+        assert_eq!(m.row_mins(), vec![Some(-2.0), Some(0.5)]);
+        assert_eq!(m.row_maxes(), vec![Some(1.0), Some(7.0)]);
+        assert_eq!(m.col_mins(), vec![Some(0.5), Some(-2.0)]);
+        assert_eq!(m.col_maxes(), vec![Some(1.0), Some(7.0)]);
+        assert_eq!(Matrix::<f64>::zeroes(2, 0).row_maxes(), vec![None, None]);
+    }
+
+    #[test]
+    fn positions_of_extrema() {
+        let m: Matrix<i32> = matrix![3, 9, 1;
+                                     9, 0, 4];
+
+        assert_eq!(m.argmax(), Some((0, 1)));
+        assert_eq!(m.argmin(), Some((1, 1)));
+        assert_eq!(m.max(), Some((9, (0, 1))));
+        assert_eq!(m.min(), Some((0, (1, 1))));
+        assert_eq!(Matrix::<i32>::zeroes(0, 0).max(), None);
+    }
+
+    #[test]
+    fn per_axis_positions() {
+        let m: Matrix<f64> = matrix![0.1, 0.7, 0.2;
+                                     0.5, 0.3, 0.2];
+
+        assert_eq!(m.row_argmax(), vec![Some(1), Some(0)]);
+        assert_eq!(m.row_argmin(), vec![Some(0), Some(2)]);
+        assert_eq!(m.col_argmax(), vec![Some(1), Some(0), Some(0)]);
+        assert_eq!(m.col_argmin(), vec![Some(0), Some(1), Some(0)]);
+        assert_eq!(Matrix::<f64>::zeroes(2, 0).row_argmax(), vec![None, None]);
+    }
+
+    #[test]
+    fn custom_folds() {
+        let m: Matrix<i32> = matrix![1, -2, 3;
+                                     -4, 5, -6];
+
+        let negatives = m.fold_rows(0, |n, &e| if e < 0 { n + 1 } else { n });
+        assert_eq!(negatives, vec![1, 2]);
+
+        let joined = m.fold_cols(String::new(), |s, e| s + &e.to_string());
+        assert_eq!(joined, vec!["1-4", "-25", "3-6"]);
+    }
+}
@@ -0,0 +1,231 @@
+//! Elementary row (and column) operations, the building blocks of Gaussian elimination.
+
+use std::ops;
+
+use crate::{Matrix, Real};
+
+impl<T: Default> Matrix<T> {
+    /// Swaps rows `a` and `b`.
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        assert!(a < self.rows && b < self.rows,
+            "Can't swap rows {} and {} in a matrix with {} rows.", a, b, self.rows);
+        if a == b {
+            return;
+        }
+
+        let (low, high) = (a.min(b), a.max(b));
+        let (top, bottom) = self.contents.split_at_mut(high * self.columns);
+        top[low * self.columns..(low + 1) * self.columns].swap_with_slice(&mut bottom[..self.columns]);
+    }
+
+    /// Swaps columns `a` and `b`.
+    pub fn swap_cols(&mut self, a: usize, b: usize) {
+        assert!(a < self.columns && b < self.columns,
+            "Can't swap columns {} and {} in a matrix with {} columns.", a, b, self.columns);
+
+        for r in 0..self.rows {
+            self.contents.swap(r * self.columns + a, r * self.columns + b);
+        }
+    }
+}
+
+impl<T: Default + Clone + ops::Mul<Output = T>> Matrix<T> {
+    /// Multiplies every element of row `i` by `k`.
+    pub fn scale_row(&mut self, i: usize, k: T) {
+        assert!(i < self.rows, "Row {} doesn't exist in a matrix with {} rows.", i, self.rows);
+
+        for e in &mut self.contents[i * self.columns..(i + 1) * self.columns] {
+            *e = e.clone() * k.clone();
+        }
+    }
+
+    /// Multiplies every element of column `j` by `k`.
+    pub fn scale_col(&mut self, j: usize, k: T) {
+        assert!(j < self.columns, "Column {} doesn't exist in a matrix with {} columns.", j, self.columns);
+
+        for r in 0..self.rows {
+            let e = &mut self.contents[r * self.columns + j];
+            *e = e.clone() * k.clone();
+        }
+    }
+}
+
+impl<T: Default + Clone + ops::Add<Output = T> + ops::Mul<Output = T>> Matrix<T> {
+    /// Adds `k` times row `src` to row `dst`, leaving `src` as it is.
+    pub fn add_scaled_row(&mut self, src: usize, dst: usize, k: T) {
+        assert!(src < self.rows && dst < self.rows,
+            "Can't add row {} to row {} in a matrix with {} rows.", src, dst, self.rows);
+
+        for c in 0..self.columns {
+            let addend = self.contents[src * self.columns + c].clone() * k.clone();
+            let e = &mut self.contents[dst * self.columns + c];
+            *e = e.clone() + addend;
+        }
+    }
+
+    /// Adds `k` times column `src` to column `dst`, leaving `src` as it is.
+    pub fn add_scaled_col(&mut self, src: usize, dst: usize, k: T) {
+        assert!(src < self.columns && dst < self.columns,
+            "Can't add column {} to column {} in a matrix with {} columns.", src, dst, self.columns);
+
+        for r in 0..self.rows {
+            let addend = self.contents[r * self.columns + src].clone() * k.clone();
+            let e = &mut self.contents[r * self.columns + dst];
+            *e = e.clone() + addend;
+        }
+    }
+}
+
+impl<T: Default + Clone + ops::Mul<Output = T>> Matrix<T> {
+    /// Multiplies row `i` by `factors[i]`, which is the same as multiplying by `diag(factors)` from the left.
+    pub fn scale_rows(&mut self, factors: &[T]) {
+        assert!(factors.len() == self.rows,
+            "Got {} scaling factors for a matrix with {} rows.", factors.len(), self.rows);
+
+        for (i, k) in factors.iter().enumerate() {
+            self.scale_row(i, k.clone());
+        }
+    }
+
+    /// Multiplies column `j` by `factors[j]`, which is the same as multiplying by `diag(factors)` from the right.
+    pub fn scale_cols(&mut self, factors: &[T]) {
+        assert!(factors.len() == self.columns,
+            "Got {} scaling factors for a matrix with {} columns.", factors.len(), self.columns);
+
+        if self.columns > 0 {
+            for row in self.contents.chunks_mut(self.columns) {
+                for (e, k) in row.iter_mut().zip(factors) {
+                    *e = e.clone() * k.clone();
+                }
+            }
+        }
+    }
+}
+
+/// What to divide by when normalising rows or columns.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Normalization {
+    /// Divide by the euclidean length, giving unit vectors.
+    L2,
+    /// Divide by the sum of the elements, so that e.g. every row of a
+    /// non-negative matrix becomes a probability distribution.
+    Sum
+}
+
+impl<T: Real> Matrix<T> {
+    /// Divides every row by its norm. Rows for which that norm is zero are left as they are.
+    pub fn normalize_rows(&mut self, by: Normalization) {
+        let norms = match by {
+            Normalization::L2 => self.fold_rows(T::zero(), |acc, &e| acc + e * e).into_iter().map(T::sqrt).collect(),
+            Normalization::Sum => self.row_sums()
+        };
+
+        let columns = self.columns;
+        for (i, e) in self.contents.iter_mut().enumerate() {
+            divide(e, norms[i / columns]);
+        }
+    }
+
+    /// Divides every column by its norm. Columns for which that norm is zero are left as they are.
+    pub fn normalize_cols(&mut self, by: Normalization) {
+        let norms = match by {
+            Normalization::L2 => self.fold_cols(T::zero(), |acc, &e| acc + e * e).into_iter().map(T::sqrt).collect(),
+            Normalization::Sum => self.col_sums()
+        };
+
+        let columns = self.columns;
+        for (i, e) in self.contents.iter_mut().enumerate() {
+            divide(e, norms[i % columns]);
+        }
+    }
+}
+
+// zero norms leave the element untouched rather than turning it into NaN
+fn divide<T: Real>(e: &mut T, norm: T) {
+    if !norm.is_zero() {
+        *e = *e / norm;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn swapping() {
+        let mut m: Matrix<i32> = matrix![1, 2, 3;
+                                         4, 5, 6;
+                                         7, 8, 9];
+
+        m.swap_rows(2, 0);
+        assert_eq!(m.contents, vec![7, 8, 9, 4, 5, 6, 1, 2, 3]);
+        m.swap_rows(1, 1);
+        m.swap_cols(0, 1);
+        assert_eq!(m.contents, vec![8, 7, 9, 5, 4, 6, 2, 1, 3]);
+    }
+
+    #[test]
+    fn scaling() {
+        let mut m: Matrix<i32> = matrix![1, 2;
+                                         3, 4];
+
+        m.scale_row(1, 3);
+        assert_eq!(m.contents, vec![1, 2, 9, 12]);
+        m.scale_col(0, -1);
+        assert_eq!(m.contents, vec![-1, 2, -9, 12]);
+    }
+
+    #[test]
+    fn elimination_step() {
+        let mut m: Matrix<f64> = matrix![2.0, 1.0;
+                                         4.0, 5.0];
+
+        m.add_scaled_row(0, 1, -2.0);
+        assert_eq!(m.contents, vec![2.0, 1.0, 0.0, 3.0]);
+        m.add_scaled_col(0, 1, 1.0);
+        assert_eq!(m.contents, vec![2.0, 3.0, 0.0, 3.0]);
+    }
+
+    #[test]
+    fn diagonal_scaling() {
+        let mut m: Matrix<i32> = matrix![1, 2;
+                                         3, 4];
+
+        m.scale_rows(&[2, 3]);
+        assert_eq!(m.contents, vec![2, 4, 9, 12]);
+        m.scale_cols(&[1, -1]);
+        assert_eq!(m.contents, vec![2, -4, 9, -12]);
+    }
+
+    #[test]
+    fn empty_scaling() {
+        let mut m: Matrix<i32> = Matrix::zeroes(3, 0);
+        m.scale_cols(&[]);
+        m.scale_rows(&[1, 2, 3]);
+        assert_eq!(m.shape(), (3, 0).into());
+    }
+
+    #[test]
+    fn normalization() {
+        let mut m: Matrix<f64> = matrix![3.0, 4.0;
+                                         0.0, 0.0;
+                                         1.0, 3.0];
+
+        m.normalize_rows(Normalization::L2);
+        assert_eq!(m.contents[..4], [0.6, 0.8, 0.0, 0.0]);
+
+        let mut p: Matrix<f64> = matrix![1.0, 2.0;
+                                         3.0, 6.0];
+        p.normalize_cols(Normalization::Sum);
+        assert_eq!(p.contents, vec![0.25, 0.25, 0.75, 0.75]);
+        p.normalize_rows(Normalization::Sum);
+        assert_eq!(p.row_sums(), vec![1.0, 1.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Can't swap rows 0 and 2")]
+    fn swap_out_of_bounds() {
+        let mut m: Matrix<i32> = Matrix::zeroes(2, 2);
+        m.swap_rows(0, 2);
+    }
+}
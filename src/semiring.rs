@@ -0,0 +1,155 @@
+//! Matrix multiplication over semirings other than the usual `(+, ×)`.
+//!
+//! The row-by-column structure of the product only needs an "addition" to combine terms, a
+//! "multiplication" to form them, and a zero to start from. Swapping those out gives boolean
+//! products for reachability (`OrAnd`) or tropical products for shortest paths (`MinPlus`).
+
+use crate::{Matrix, One, Real, Zero};
+
+/// The operations a matrix product is built from.
+///
+/// `zero` has to be the identity of `add`, e.g. infinity for `min`.
+pub trait Semiring<T> {
+    fn zero() -> T;
+
+    fn add(a: &T, b: &T) -> T;
+
+    fn mul(a: &T, b: &T) -> T;
+}
+
+/// The ordinary `(+, ×)` semiring, which gives the usual matrix product.
+#[derive(Clone, Copy, Debug)]
+pub struct Standard;
+
+impl<T: Clone + Zero + One> Semiring<T> for Standard {
+    fn zero() -> T {
+        T::zero()
+    }
+
+    fn add(a: &T, b: &T) -> T {
+        a.clone() + b.clone()
+    }
+
+    fn mul(a: &T, b: &T) -> T {
+        a.clone() * b.clone()
+    }
+}
+
+/// The boolean `(or, and)` semiring. Multiplying adjacency matrices over it tells which nodes
+/// can reach which in a given number of steps.
+#[derive(Clone, Copy, Debug)]
+pub struct OrAnd;
+
+impl Semiring<bool> for OrAnd {
+    fn zero() -> bool {
+        false
+    }
+
+    fn add(a: &bool, b: &bool) -> bool {
+        *a || *b
+    }
+
+    fn mul(a: &bool, b: &bool) -> bool {
+        *a && *b
+    }
+}
+
+/// The tropical `(min, +)` semiring, with infinity as its zero. Multiplying distance matrices
+/// over it gives the lengths of the shortest paths using one more edge.
+#[derive(Clone, Copy, Debug)]
+pub struct MinPlus;
+
+impl<T: Real> Semiring<T> for MinPlus {
+    fn zero() -> T {
+        T::from_f64(f64::INFINITY)
+    }
+
+    fn add(a: &T, b: &T) -> T {
+        if b < a { *b } else { *a }
+    }
+
+    fn mul(a: &T, b: &T) -> T {
+        *a + *b
+    }
+}
+
+impl<T: Default> Matrix<T> {
+    /// Multiplies two matrices like `*` does, but with the operations of the semiring `S`.
+    ///
+    /// For example, `a.semiring_mul::<MinPlus>(&b)` is the min-plus product.
+    /// This panics if the inner dimensions don't match.
+    pub fn semiring_mul<S: Semiring<T>>(&self, o: &Matrix<T>) -> Matrix<T> {
+        self.mul_with(o, S::zero, S::add, S::mul)
+    }
+
+    /// Multiplies two matrices with the given `add` and `mul`, where every element of the result
+    /// starts out as `zero()`. This is `semiring_mul` for one-off operations.
+    pub fn mul_with<Z, A, M>(&self, o: &Matrix<T>, zero: Z, add: A, mul: M) -> Matrix<T> where
+        Z: Fn() -> T,
+        A: Fn(&T, &T) -> T,
+        M: Fn(&T, &T) -> T {
+        assert!(self.columns == o.rows,
+            "Matrices of dimensions ({}, {}) and ({}, {}) aren't multiplicable.",
+            self.rows, self.columns, o.rows, o.columns);
+
+        let mut contents = Vec::with_capacity(self.rows * o.columns);
+        for r in 0..self.rows {
+            for c in 0..o.columns {
+                let mut acc = zero();
+                for k in 0..self.columns {
+                    acc = add(&acc, &mul(&self.contents[r * self.columns + k], &o.contents[k * o.columns + c]));
+                }
+                contents.push(acc);
+            }
+        }
+
+        Matrix { rows: self.rows, columns: o.columns, contents }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn standard_matches_operator() {
+        let a: Matrix<i32> = matrix![1, 2; 3, 4];
+        let b: Matrix<i32> = matrix![0, 1; 1, 0];
+
+        assert_eq!(a.semiring_mul::<Standard>(&b), &a * &b);
+    }
+
+    #[test]
+    fn reachability() {
+        // 0 -> 1 -> 2
+        let adjacency = matrix![false, true, false;
+                                false, false, true;
+                                false, false, false];
+
+        let two_steps = adjacency.semiring_mul::<OrAnd>(&adjacency);
+        assert_eq!(two_steps, matrix![false, false, true;
+                                      false, false, false;
+                                      false, false, false]);
+    }
+
+    #[test]
+    fn shortest_paths() {
+        let inf = f64::INFINITY;
+        let d: Matrix<f64> = matrix![0.0, 4.0, inf;
+                                     inf, 0.0, 1.0;
+                                     2.0, inf, 0.0];
+
+        let two_edges = d.semiring_mul::<MinPlus>(&d);
+        assert_eq!(two_edges, matrix![0.0, 4.0, 5.0;
+                                      3.0, 0.0, 1.0;
+                                      2.0, 6.0, 0.0]);
+    }
+
+    #[test]
+    fn closures() {
+        let a: Matrix<i32> = matrix![1, 5; 3, 2];
+        let max_times = a.mul_with(&a, || i32::MIN, |x, y| *x.max(y), |x, y| x * y);
+
+        assert_eq!(max_times, matrix![15, 10; 6, 15]);
+    }
+}
@@ -0,0 +1,258 @@
+use std::fmt;
+use std::ops;
+
+use crate::{Matrix, MatrixError, One, Zero};
+
+/// The dimensions of a matrix.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Shape {
+    pub rows: usize,
+    pub cols: usize
+}
+
+impl Shape {
+    pub fn new(rows: usize, cols: usize) -> Shape {
+        Shape { rows, cols }
+    }
+
+    /// The number of elements a matrix of this shape holds.
+    pub fn len(&self) -> usize {
+        self.rows * self.cols
+    }
+
+    /// Whether a matrix of this shape holds no elements at all.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+}
+
+/// One of the two directions of a matrix.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Axis {
+    /// Along the rows, i.e. vertically, moving from one row to the next.
+    Rows,
+    /// Along the columns, i.e. horizontally, moving from one column to the next.
+    Columns
+}
+
+impl From<(usize, usize)> for Shape {
+    fn from((rows, cols): (usize, usize)) -> Shape {
+        Shape { rows, cols }
+    }
+}
+
+impl fmt::Display for Shape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.rows, self.cols)
+    }
+}
+
+impl<T: Default> Matrix<T> {
+    pub fn shape(&self) -> Shape {
+        Shape { rows: self.rows, cols: self.columns }
+    }
+
+    /// The total number of elements, i.e. rows times columns.
+    pub fn len(&self) -> usize {
+        self.contents.len()
+    }
+
+    /// Whether the matrix has no elements, which is the case if it has either zero rows or zero columns.
+    pub fn is_empty(&self) -> bool {
+        self.contents.is_empty()
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.rows == self.columns
+    }
+}
+
+impl<T: Default + PartialEq> Matrix<T> {
+    /// Whether the matrix equals its own transpose. Non-square matrices are never symmetric.
+    pub fn is_symmetric(&self) -> bool {
+        if !self.is_square() {
+            return false;
+        }
+
+        for r in 0..self.rows {
+            for c in (r + 1)..self.columns {
+                if self.contents[r * self.columns + c] != self.contents[c * self.columns + r] {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+}
+
+impl<T: Default + Copy + PartialEq + ops::Neg<Output = T>> Matrix<T> {
+    /// Whether the matrix equals the negation of its transpose, which makes its diagonal zero.
+    /// Non-square matrices are never skew-symmetric.
+    pub fn is_skew_symmetric(&self) -> bool {
+        if !self.is_square() {
+            return false;
+        }
+
+        for r in 0..self.rows {
+            for c in r..self.columns {
+                if self.contents[r * self.columns + c] != -self.contents[c * self.columns + r] {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+}
+
+impl<T: Default + Copy + One + ops::Add<Output = T> + ops::Sub<Output = T> + ops::Div<Output = T>> Matrix<T> {
+    /// The symmetric part `(A + Aᵀ) / 2`. Together with `skew_part` it splits a square matrix
+    /// into a symmetric and a skew-symmetric matrix that add up to it.
+    ///
+    /// For integer elements the halving rounds toward zero, so the split is only exact for
+    /// types with exact division, such as floats and `Ratio`. This panics for non-square
+    /// matrices; see `try_symmetrize`.
+    pub fn symmetrize(&self) -> Matrix<T> {
+        self.try_symmetrize().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// The non-panicking version of `symmetrize`.
+    pub fn try_symmetrize(&self) -> Result<Matrix<T>, MatrixError> {
+        self.combine_with_transpose(|a, b| a + b)
+    }
+
+    /// The skew-symmetric part `(A - Aᵀ) / 2`, which has a zero diagonal. This panics for
+    /// non-square matrices; see `try_skew_part`.
+    pub fn skew_part(&self) -> Matrix<T> {
+        self.try_skew_part().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// The non-panicking version of `skew_part`.
+    pub fn try_skew_part(&self) -> Result<Matrix<T>, MatrixError> {
+        self.combine_with_transpose(|a, b| a - b)
+    }
+
+    // f(a_rc, a_cr) / 2 at every (r, c)
+    fn combine_with_transpose<F: Fn(T, T) -> T>(&self, f: F) -> Result<Matrix<T>, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare { rows: self.rows, columns: self.columns });
+        }
+        let n = self.rows;
+        let two = T::one() + T::one();
+        Ok(Matrix::from_fn(n, n, |r, c| f(self.contents[r * n + c], self.contents[c * n + r]) / two))
+    }
+}
+
+impl<T: Default + Zero> Matrix<T> {
+    /// Whether every element off the main diagonal is zero.
+    ///
+    /// This (and the triangular checks) also work for non-square matrices,
+    /// where the main diagonal is the one starting at the top left.
+    pub fn is_diagonal(&self) -> bool {
+        self.all_zero_where(|r, c| r != c)
+    }
+
+    /// Whether every element below the main diagonal is zero.
+    pub fn is_upper_triangular(&self) -> bool {
+        self.all_zero_where(|r, c| r > c)
+    }
+
+    /// Whether every element above the main diagonal is zero.
+    pub fn is_lower_triangular(&self) -> bool {
+        self.all_zero_where(|r, c| r < c)
+    }
+
+    fn all_zero_where<F: Fn(usize, usize) -> bool>(&self, selected: F) -> bool {
+        self.contents.iter().enumerate().all(|(i, e)| {
+            !selected(i / self.columns, i % self.columns) || e.is_zero()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shape_and_length() {
+        let m: Matrix<i32> = Matrix::zeroes(2, 5);
+
+        assert_eq!(m.shape(), Shape::new(2, 5));
+        assert_eq!(m.shape(), (2, 5).into());
+        assert_eq!(m.len(), 10);
+        assert!(!m.is_empty());
+        assert!(!m.is_square());
+        assert!(Matrix::<i32>::zeroes(0, 3).is_empty());
+    }
+
+    #[test]
+    fn symmetry() {
+        let s: Matrix<i32> = matrix![1, 2, 3;
+                                     2, 4, 5;
+                                     3, 5, 6];
+        let n: Matrix<i32> = matrix![1, 2;
+                                     3, 4];
+
+        assert!(s.is_symmetric());
+        assert!(!n.is_symmetric());
+        assert!(!Matrix::<i32>::zeroes(2, 3).is_symmetric());
+    }
+
+    #[test]
+    fn skew_symmetry() {
+        let k: Matrix<i32> = matrix![0, 2, -1;
+                                     -2, 0, 4;
+                                     1, -4, 0];
+        let d: Matrix<i32> = matrix![1, 2;
+                                     -2, 0];
+
+        assert!(k.is_skew_symmetric());
+        assert!(!d.is_skew_symmetric());
+        assert!(Matrix::<i32>::zeroes(2, 2).is_skew_symmetric());
+        assert!(!Matrix::<i32>::zeroes(2, 3).is_skew_symmetric());
+    }
+
+    #[test]
+    fn triangularity() {
+        let upper: Matrix<i32> = matrix![1, 2, 3;
+                                         0, 4, 5;
+                                         0, 0, 6];
+        let lower: Matrix<i32> = matrix![1, 0;
+                                         2, 3;
+                                         4, 5];
+
+        assert!(upper.is_upper_triangular());
+        assert!(!upper.is_lower_triangular());
+        assert!(lower.is_lower_triangular());
+        assert!(!lower.is_upper_triangular());
+        assert!(!upper.is_diagonal());
+        assert!(Matrix::<f64>::identity(4).is_diagonal());
+    }
+
+    #[test]
+    fn symmetric_and_skew_parts() {
+        let a: Matrix<f64> = matrix![1.0, 4.0, -2.0;
+                                     0.0, 3.0, 5.0;
+                                     6.0, 1.0, 2.0];
+        let s = a.symmetrize();
+        let k = a.skew_part();
+
+        assert_eq!(s, matrix![1.0, 2.0, 2.0;
+                              2.0, 3.0, 3.0;
+                              2.0, 3.0, 2.0]);
+        assert!(s.is_symmetric());
+        assert!(k.is_skew_symmetric());
+        assert_eq!(&s + &k, a);
+    }
+
+    #[test]
+    #[should_panic(expected = "This operation requires a square matrix, but got one of dimensions (2, 3).")]
+    fn non_square_symmetrize() {
+        let m: Matrix<f64> = Matrix::zeroes(2, 3);
+        assert_eq!(m.try_skew_part(), Err(MatrixError::NotSquare { rows: 2, columns: 3 }));
+        m.symmetrize();
+    }
+}
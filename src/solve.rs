@@ -0,0 +1,360 @@
+//! Solving systems of linear equations.
+
+use crate::{Field, Matrix, MatrixError, Real, Vector};
+
+impl<T: Real> Matrix<T> {
+    /// Solves `A X = B` for `X`, where `A` is this (square) matrix.
+    ///
+    /// `B` can have any number of columns; a single column is the usual `A x = b`, and a
+    /// `Vector` turns into one with `Matrix::from`. Every column is solved for with the same
+    /// LU decomposition with partial pivoting. Fails with `NotSquare`, with `DimensionMismatch`
+    /// if `B` doesn't have as many rows as `A`, and with `Singular`.
+    ///
+    /// To solve many systems that share `A` but whose right hand sides aren't all known up
+    /// front, keep the result of `lu` around instead.
+    pub fn solve(&self, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        self.lu()?.solve_matrix(b)
+    }
+
+    /// Like `solve`, followed by up to `rounds` rounds of iterative refinement: the residual
+    /// `R = B - A X` is computed, the correction `A D = R` is solved for with the same LU
+    /// decomposition, and `X + D` is the new solution. This stops early once the corrections
+    /// no longer make a difference.
+    ///
+    /// Refinement recovers some of the accuracy lost on mildly ill-conditioned systems. With
+    /// `extended`, the residuals are computed in roughly twice the working precision, using
+    /// error-free transformations, which makes the result accurate to working precision as long
+    /// as the condition number is well below `1 / T::epsilon()`.
+    pub fn solve_refined(&self, b: &Matrix<T>, rounds: usize, extended: bool) -> Result<Matrix<T>, MatrixError> {
+        let lu = self.lu()?;
+        let mut x = lu.solve_matrix(b)?;
+        let n = self.rows;
+
+        for _ in 0..rounds {
+            let residual = Matrix::from_fn(n, b.columns, |r, c| {
+                let row = self.contents[r * n..(r + 1) * n].iter().enumerate().map(|(k, &a)| (a, x.contents[k * b.columns + c]));
+                let start = b.contents[r * b.columns + c];
+                if extended {
+                    compensated_residual(start, row)
+                } else {
+                    row.fold(start, |acc, (a, x)| acc - a * x)
+                }
+            });
+            let correction = lu.solve_matrix(&residual)?;
+            x += &correction;
+
+            let size = |m: &Matrix<T>| m.contents.iter().fold(T::zero(), |acc, e| if e.abs() > acc { e.abs() } else { acc });
+            if size(&correction) <= T::epsilon() * size(&x) {
+                break;
+            }
+        }
+        Ok(x)
+    }
+
+    /// Solves `L X = B` by forward substitution, where `L` is this matrix and lower triangular.
+    ///
+    /// Only the lower triangle is read, so this also works on a matrix that stores something
+    /// else above the diagonal. At O(n²) per column of `B`, this is much cheaper than `solve`.
+    /// Fails with `NotSquare`, with `DimensionMismatch` if `B` has the wrong number of rows,
+    /// and with `Singular` if a diagonal element is zero.
+    pub fn solve_lower_triangular(&self, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        self.check_triangular(b)?;
+        let n = self.rows;
+        let mut x = b.clone();
+        for c in 0..b.columns {
+            for r in 0..n {
+                let sum = (0..r).fold(x.contents[r * b.columns + c], |acc, k| {
+                    acc - self.contents[r * n + k] * x.contents[k * b.columns + c]
+                });
+                x.contents[r * b.columns + c] = sum / self.contents[r * n + r];
+            }
+        }
+        Ok(x)
+    }
+
+    /// Solves `U X = B` by back substitution, where `U` is this matrix and upper triangular.
+    /// Only the upper triangle is read; otherwise this is just like `solve_lower_triangular`.
+    pub fn solve_upper_triangular(&self, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        self.check_triangular(b)?;
+        let n = self.rows;
+        let mut x = b.clone();
+        for c in 0..b.columns {
+            for r in (0..n).rev() {
+                let sum = (r + 1..n).fold(x.contents[r * b.columns + c], |acc, k| {
+                    acc - self.contents[r * n + k] * x.contents[k * b.columns + c]
+                });
+                x.contents[r * b.columns + c] = sum / self.contents[r * n + r];
+            }
+        }
+        Ok(x)
+    }
+
+    fn check_triangular(&self, b: &Matrix<T>) -> Result<(), MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare { rows: self.rows, columns: self.columns });
+        }
+        if b.rows != self.rows {
+            return Err(MatrixError::DimensionMismatch {
+                operation: "solving", left: (self.rows, self.columns), right: (b.rows, b.columns)
+            });
+        }
+        if self.diagonal().iter().any(|d| d.is_zero()) {
+            return Err(MatrixError::Singular);
+        }
+        Ok(())
+    }
+
+    /// Solves `A X = B` in the least squares sense, for any shape of `A`, and returns `X`
+    /// together with the norm of the residual `A X - B`.
+    ///
+    /// Tall matrices with independent columns are handled with a QR decomposition. Everything
+    /// else, i.e. wide or rank-deficient matrices, has infinitely many minimisers, and falls
+    /// back to the SVD to get the one of smallest norm. Fails with `DimensionMismatch` if `B`
+    /// doesn't have as many rows as `A`.
+    pub fn lstsq(&self, b: &Matrix<T>) -> Result<(Matrix<T>, T), MatrixError> {
+        if b.rows != self.rows {
+            return Err(MatrixError::DimensionMismatch {
+                operation: "least squares", left: (self.rows, self.columns), right: (b.rows, b.columns)
+            });
+        }
+
+        let x = match self.full_rank_lstsq(b) {
+            Some(x) => x,
+            None => {
+                let svd = self.svd();
+                svd.solve(b, svd.default_tolerance())?
+            }
+        };
+
+        let residual = (&(self * &x) - b).contents.iter().fold(T::zero(), |acc, &e| acc + e * e).sqrt();
+        Ok((x, residual))
+    }
+
+    // the QR route of lstsq, or None if it doesn't apply
+    fn full_rank_lstsq(&self, b: &Matrix<T>) -> Option<Matrix<T>> {
+        if self.rows < self.columns {
+            return None;
+        }
+
+        let qr = self.qr();
+        let mut columns = Vec::with_capacity(b.columns);
+        for c in 0..b.columns {
+            columns.push(qr.solve(&Vector::new(b.column(c).contents)).ok()?);
+        }
+        Some(Matrix::from_fn(self.columns, b.columns, |r, c| columns[c][r]))
+    }
+}
+
+impl<T: Field> Matrix<T> {
+    /// Solves `A X = B` exactly, by Gauss–Jordan elimination on `[A | B]` without rounding. Fails
+    /// with `NotSquare`, with `DimensionMismatch` if `B` doesn't have as many rows as `A`, and
+    /// with `Singular` only if `A` really is singular.
+    pub fn solve_exact(&self, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare { rows: self.rows, columns: self.columns });
+        }
+        if b.rows != self.rows {
+            return Err(MatrixError::DimensionMismatch { operation: "solving", left: (self.rows, self.columns), right: (b.rows, b.columns) });
+        }
+
+        let n = self.rows;
+        let (reduced, pivots) = Matrix::hstack(&[self, b]).rref_exact();
+        if pivots.iter().take_while(|&&p| p < n).count() < n {
+            return Err(MatrixError::Singular);
+        }
+        Ok(Matrix::from_fn(n, b.columns, |r, c| reduced[(r, n + c)]))
+    }
+}
+
+// start - Σ a b, with the rounding errors of every sum and product tracked separately and added
+// back at the end (the Dot2 algorithm of Ogita, Rump and Oishi)
+fn compensated_residual<T: Real, I: Iterator<Item = (T, T)>>(start: T, terms: I) -> T {
+    let mut sum = start;
+    let mut error = T::zero();
+    for (a, b) in terms {
+        let (product, product_error) = two_product(-a, b);
+        let (next, sum_error) = two_sum(sum, product);
+        sum = next;
+        error = error + product_error + sum_error;
+    }
+    sum + error
+}
+
+// a + b as a rounded sum and its exact rounding error
+fn two_sum<T: Real>(a: T, b: T) -> (T, T) {
+    let s = a + b;
+    let z = s - a;
+    (s, (a - (s - z)) + (b - z))
+}
+
+// a b as a rounded product and its exact rounding error, by Dekker's splitting
+fn two_product<T: Real>(a: T, b: T) -> (T, T) {
+    let p = a * b;
+    let (a_high, a_low) = split(a);
+    let (b_high, b_low) = split(b);
+    (p, ((a_high * b_high - p) + a_high * b_low + a_low * b_high) + a_low * b_low)
+}
+
+// splits x into two halves with at most half of the significant bits each
+fn split<T: Real>(x: T) -> (T, T) {
+    // 2^⌈p / 2⌉ + 1, with p the number of bits in the significand
+    let bits = 1.0 - T::epsilon().to_f64().log2();
+    let factor = T::from_f64(2f64.powf((bits / 2.0).ceil()) + 1.0);
+    let c = factor * x;
+    let high = c - (c - x);
+    (high, x - high)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Rational64, Ratio};
+
+    #[test]
+    fn single_right_hand_side() {
+        let a: Matrix<f64> = matrix![2.0, 1.0, -1.0;
+                                     -3.0, -1.0, 2.0;
+                                     -2.0, 1.0, 2.0];
+        let b = Matrix::from(Vector::new(vec![8.0, -11.0, -3.0]));
+
+        let x = a.solve(&b).unwrap();
+        assert_eq!(x.shape(), (3, 1).into());
+        assert!(x.approx_eq(&matrix![2.0; 3.0; -1.0], 1e-12, 0.0));
+    }
+
+    #[test]
+    fn several_right_hand_sides() {
+        let a: Matrix<f64> = matrix![4.0, 3.0;
+                                     6.0, 3.0];
+        let b: Matrix<f64> = matrix![10.0, 1.0, 0.0;
+                                     12.0, 0.0, 1.0];
+
+        let x = a.solve(&b).unwrap();
+        assert!((&a * &x).approx_eq(&b, 1e-12, 0.0));
+        // the last two columns of B are the identity, so they solve for the inverse
+        assert!(x.submatrix(0..2, 1..3).approx_eq(&a.inverse().unwrap(), 1e-12, 0.0));
+    }
+
+    #[test]
+    fn triangular_systems() {
+        let l: Matrix<f64> = matrix![2.0, 0.0, 0.0;
+                                     1.0, 1.0, 0.0;
+                                     -1.0, 3.0, 4.0];
+        let b: Matrix<f64> = matrix![2.0, 4.0;
+                                     3.0, 1.0;
+                                     12.0, -6.0];
+
+        let x = l.solve_lower_triangular(&b).unwrap();
+        assert!(x.approx_eq(&matrix![1.0, 2.0; 2.0, -1.0; 1.75, -0.25], 1e-14, 0.0));
+        assert!((&l * &x).approx_eq(&b, 1e-14, 0.0));
+
+        let u = l.transpose();
+        let x = u.solve_upper_triangular(&b).unwrap();
+        assert!((&u * &x).approx_eq(&b, 1e-14, 0.0));
+    }
+
+    #[test]
+    fn triangular_ignores_other_triangle() {
+        let packed: Matrix<f64> = matrix![1.0, 9.0;
+                                          2.0, 4.0];
+        let b: Matrix<f64> = matrix![1.0; 6.0];
+
+        assert_eq!(packed.solve_lower_triangular(&b).unwrap(), matrix![1.0; 1.0]);
+        assert_eq!(packed.solve_upper_triangular(&b).unwrap(), matrix![-12.5; 1.5]);
+
+        let singular: Matrix<f64> = matrix![1.0, 0.0;
+                                            2.0, 0.0];
+        assert_eq!(singular.solve_lower_triangular(&b), Err(MatrixError::Singular));
+    }
+
+    #[test]
+    fn line_fit() {
+        // noisy measurements of a line, y = 1.3 + 1.8 t being the best fit
+        let a: Matrix<f64> = matrix![1.0, 0.0;
+                                     1.0, 1.0;
+                                     1.0, 2.0;
+                                     1.0, 3.0];
+        let b: Matrix<f64> = matrix![1.5; 2.5; 5.5; 6.5];
+
+        let (x, residual) = a.lstsq(&b).unwrap();
+        assert!(x.approx_eq(&matrix![1.3; 1.8], 1e-12, 0.0));
+        assert!((residual - 0.8f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn minimum_norm_solutions() {
+        // one equation in two unknowns: of all x + y = 2, (1, 1) is the shortest
+        let wide: Matrix<f64> = matrix![1.0, 1.0];
+        let (x, residual) = wide.lstsq(&matrix![2.0]).unwrap();
+        assert!(x.approx_eq(&matrix![1.0; 1.0], 1e-12, 0.0));
+        assert!(residual < 1e-12);
+
+        // dependent columns, where QR gives up
+        let deficient: Matrix<f64> = matrix![1.0, 2.0;
+                                             2.0, 4.0;
+                                             3.0, 6.0];
+        let (x, residual) = deficient.lstsq(&matrix![1.0; 2.0; 3.0]).unwrap();
+        assert!(x.approx_eq(&matrix![0.2; 0.4], 1e-12, 0.0));
+        assert!(residual < 1e-12);
+    }
+
+    #[test]
+    fn iterative_refinement() {
+        // the 8×8 Hilbert matrix scaled to integers, with a right hand side that is exact too,
+        // so that the exact solution is all ones
+        let a: Matrix<f64> = Matrix::from_fn(8, 8, |r, c| 360360.0 / (r + c + 1) as f64);
+        let b = Matrix::from_fn(8, 1, |r, _| (0..8).map(|c| a[(r, c)]).sum());
+        let ones: Matrix<f64> = Matrix::ones(8, 1);
+        let error = |x: &Matrix<f64>| (x - &ones).contents.iter().fold(0.0f64, |acc, e| acc.max(e.abs()));
+
+        let plain = a.solve(&b).unwrap();
+        let refined = a.solve_refined(&b, 10, true).unwrap();
+        assert!(error(&plain) > 1e-10);
+        assert!(error(&refined) < 1e-13);
+        assert!(error(&a.solve_refined(&b, 10, false).unwrap()) <= error(&plain));
+        assert_eq!(a.solve_refined(&b, 0, true).unwrap(), plain);
+    }
+
+    #[test]
+    fn error_free_transformations() {
+        let (s, e) = two_sum(1.0, 1e-20);
+        assert_eq!((s, e), (1.0, 1e-20));
+
+        // the double nearest to a third is just below it, so the product rounds up to 1
+        let (p, e) = two_product(1.0f64 / 3.0, 3.0);
+        assert_eq!((p, e), (1.0, -(2f64.powi(-54))));
+    }
+
+    #[test]
+    fn failures() {
+        let singular: Matrix<f64> = matrix![1.0, 2.0;
+                                            2.0, 4.0];
+        let b: Matrix<f64> = matrix![1.0; 1.0];
+
+        assert_eq!(singular.solve(&b), Err(MatrixError::Singular));
+        assert_eq!(Matrix::<f64>::zeroes(2, 3).solve(&b), Err(MatrixError::NotSquare { rows: 2, columns: 3 }));
+        assert_eq!(Matrix::<f64>::identity(3).solve(&b),
+            Err(MatrixError::DimensionMismatch { operation: "solving", left: (3, 3), right: (2, 1) }));
+        assert_eq!(Matrix::<f64>::zeroes(3, 2).lstsq(&b),
+            Err(MatrixError::DimensionMismatch { operation: "least squares", left: (3, 2), right: (2, 1) }));
+    }
+
+    #[test]
+    fn exact_solutions() {
+        let a: Matrix<Rational64> = matrix![2, 1, 1;
+                                            1, 3, 2;
+                                            1, 0, 0].map(|&e| Ratio::from(e));
+        let b: Matrix<Rational64> = matrix![1; 2; 3].map(|&e| Ratio::from(e));
+
+        let x = a.solve_exact(&b).unwrap();
+        assert_eq!(&a * &x, b);
+        assert_eq!(x, matrix![3; 9; -14].map(|&e| Ratio::from(e)));
+        let halves = a.solve_exact(&(&b * Ratio::new(1, 2))).unwrap();
+        assert_eq!(halves[(0, 0)], Ratio::new(3, 2));
+
+        let singular: Matrix<Rational64> = matrix![1, 2; 2, 4].map(|&e| Ratio::from(e));
+        assert_eq!(singular.solve_exact(&Matrix::zeroes(2, 1)).unwrap_err(), MatrixError::Singular);
+        assert_eq!(a.solve_exact(&Matrix::zeroes(2, 1)).unwrap_err(),
+            MatrixError::DimensionMismatch { operation: "solving", left: (3, 3), right: (2, 1) });
+    }
+}
@@ -0,0 +1,348 @@
+//! Building bigger matrices out of smaller ones.
+
+use std::borrow::Borrow;
+
+use crate::{Matrix, MatrixError, MatrixLike, MatrixView, Zero};
+
+impl<T: Default + Clone> Matrix<T> {
+    /// Joins matrices side by side, so `hstack(&[&a, &b])` gives the augmented matrix `[a | b]`.
+    ///
+    /// All parts need to have the same number of rows; this panics otherwise.
+    /// Stacking nothing gives a 0 by 0 matrix.
+    pub fn hstack<M: Borrow<Matrix<T>>>(parts: &[M]) -> Matrix<T> {
+        Matrix::try_hstack(parts).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// The non-panicking version of `hstack`.
+    pub fn try_hstack<M: Borrow<Matrix<T>>>(parts: &[M]) -> Result<Matrix<T>, MatrixError> {
+        let rows = parts.first().map_or(0, |m| m.borrow().rows);
+        check_all(parts, "horizontal stacking", |m| m.rows == rows)?;
+
+        let columns = parts.iter().map(|m| m.borrow().columns).sum();
+        let mut contents: Vec<T> = Vec::with_capacity(rows * columns);
+        for r in 0..rows {
+            for m in parts {
+                let m = m.borrow();
+                contents.extend_from_slice(&m.contents[r * m.columns..(r + 1) * m.columns]);
+            }
+        }
+
+        Ok(Matrix { rows, columns, contents })
+    }
+
+    /// Joins matrices on top of each other.
+    ///
+    /// All parts need to have the same number of columns; this panics otherwise.
+    /// Stacking nothing gives a 0 by 0 matrix.
+    pub fn vstack<M: Borrow<Matrix<T>>>(parts: &[M]) -> Matrix<T> {
+        Matrix::try_vstack(parts).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// The non-panicking version of `vstack`.
+    pub fn try_vstack<M: Borrow<Matrix<T>>>(parts: &[M]) -> Result<Matrix<T>, MatrixError> {
+        let columns = parts.first().map_or(0, |m| m.borrow().columns);
+        check_all(parts, "vertical stacking", |m| m.columns == columns)?;
+
+        let rows = parts.iter().map(|m| m.borrow().rows).sum();
+        let mut contents: Vec<T> = Vec::with_capacity(rows * columns);
+        for m in parts {
+            contents.extend_from_slice(&m.borrow().contents);
+        }
+
+        Ok(Matrix { rows, columns, contents })
+    }
+}
+
+impl<T: Default> Matrix<T> {
+    /// Cuts the matrix into tiles of `block_rows` by `block_cols`, returned as a grid of views.
+    ///
+    /// `blocks(..)[i][j]` is the tile in the i-th block row and j-th block column. If the
+    /// dimensions aren't multiples of the block size, the tiles along the bottom and
+    /// right edges are smaller.
+    pub fn blocks(&self, block_rows: usize, block_cols: usize) -> Vec<Vec<MatrixView<'_, T>>> {
+        assert!(block_rows > 0 && block_cols > 0, "Blocks must be at least 1 by 1.");
+
+        (0..self.rows).step_by(block_rows).map(|r| {
+            (0..self.columns).step_by(block_cols).map(|c| {
+                self.view(r..(r + block_rows).min(self.rows), c..(c + block_cols).min(self.columns))
+            }).collect()
+        }).collect()
+    }
+}
+
+impl<T: Default + Clone> Matrix<T> {
+    /// Reassembles a grid of blocks into a single matrix, the inverse of `blocks`.
+    ///
+    /// Every block in a block row needs the same number of rows, every block in a block
+    /// column the same number of columns, and every block row the same number of blocks.
+    /// This panics otherwise.
+    pub fn from_blocks<M: MatrixLike<T>>(blocks: &[Vec<M>]) -> Matrix<T> {
+        Matrix::try_from_blocks(blocks).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// The non-panicking version of `from_blocks`.
+    pub fn try_from_blocks<M: MatrixLike<T>>(blocks: &[Vec<M>]) -> Result<Matrix<T>, MatrixError> {
+        let widths: Vec<usize> = blocks.first().map_or(Vec::new(), |row| row.iter().map(|b| b.shape().cols).collect());
+
+        for row in blocks {
+            let height = row.first().map_or(0, |b| b.shape().rows);
+            if row.len() != widths.len() {
+                return Err(MatrixError::ElementCount { expected: widths.len(), found: row.len() });
+            }
+
+            for (b, &width) in row.iter().zip(&widths) {
+                let shape = b.shape();
+                if shape.rows != height || shape.cols != width {
+                    return Err(MatrixError::DimensionMismatch {
+                        operation: "block assembly",
+                        left: (height, width),
+                        right: (shape.rows, shape.cols)
+                    });
+                }
+            }
+        }
+
+        let rows: usize = blocks.iter().map(|row| row.first().map_or(0, |b| b.shape().rows)).sum();
+        let columns: usize = widths.iter().sum();
+        let mut contents: Vec<T> = Vec::with_capacity(rows * columns);
+        for row in blocks {
+            let height = row.first().map_or(0, |b| b.shape().rows);
+            for r in 0..height {
+                for b in row {
+                    for c in 0..b.shape().cols {
+                        contents.push(b.element(r, c).clone());
+                    }
+                }
+            }
+        }
+
+        Ok(Matrix { rows, columns, contents })
+    }
+}
+
+impl<T: Default + Clone> Matrix<T> {
+    /// Repeats the matrix `reps_rows` times vertically and `reps_cols` times horizontally,
+    /// like NumPy's `tile`. An m by n matrix becomes `m * reps_rows` by `n * reps_cols`.
+    pub fn tile(&self, reps_rows: usize, reps_cols: usize) -> Matrix<T> {
+        let (rows, columns) = (self.rows, self.columns);
+        Matrix::from_fn(rows * reps_rows, columns * reps_cols, |r, c| {
+            self.contents[(r % rows) * columns + c % columns].clone()
+        })
+    }
+}
+
+impl<T: Default + Clone> Matrix<T> {
+    /// Copies `block` into this matrix, with its top left corner ending up at `(row, column)`.
+    ///
+    /// This panics if the block doesn't fit entirely inside the matrix at that position.
+    pub fn set_block<M: MatrixLike<T> + ?Sized>(&mut self, row: usize, column: usize, block: &M) {
+        self.try_set_block(row, column, block).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// The non-panicking version of `set_block`.
+    pub fn try_set_block<M: MatrixLike<T> + ?Sized>(&mut self, row: usize, column: usize, block: &M) -> Result<(), MatrixError> {
+        let shape = block.shape();
+        if row + shape.rows > self.rows || column + shape.cols > self.columns {
+            return Err(MatrixError::DimensionMismatch {
+                operation: "block assignment",
+                left: (self.rows, self.columns),
+                right: (row + shape.rows, column + shape.cols)
+            });
+        }
+
+        self.view_mut(row..row + shape.rows, column..column + shape.cols).copy_from(block);
+        Ok(())
+    }
+}
+
+impl<T: Default + Clone + Zero> Matrix<T> {
+    /// Places the matrices along the diagonal, top left to bottom right, with zeroes everywhere
+    /// else. This is also known as their direct sum.
+    ///
+    /// The parts don't need to be square, and empty parts still shift everything after them.
+    pub fn block_diag<M: Borrow<Matrix<T>>>(parts: &[M]) -> Matrix<T> {
+        let rows = parts.iter().map(|m| m.borrow().rows).sum();
+        let columns = parts.iter().map(|m| m.borrow().columns).sum();
+
+        let mut result = Matrix::zeroes(rows, columns);
+        let (mut r, mut c) = (0, 0);
+        for m in parts {
+            let m = m.borrow();
+            result.set_block(r, c, m);
+            r += m.rows;
+            c += m.columns;
+        }
+        result
+    }
+}
+
+// reports the first part that doesn't fit next to the first one
+fn check_all<T, M, F>(parts: &[M], operation: &'static str, fits: F) -> Result<(), MatrixError> where
+    T: Default,
+    M: Borrow<Matrix<T>>,
+    F: Fn(&Matrix<T>) -> bool {
+    match parts.iter().map(Borrow::borrow).find(|m| !fits(m)) {
+        Some(m) => {
+            let first = parts[0].borrow();
+            Err(MatrixError::DimensionMismatch {
+                operation,
+                left: (first.rows, first.columns),
+                right: (m.rows, m.columns)
+            })
+        },
+        None => Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn augmented_matrix() {
+        let a: Matrix<i32> = matrix![1, 2;
+                                     3, 4];
+        let b: Matrix<i32> = matrix![5; 6];
+
+        let ab = Matrix::hstack(&[&a, &b]);
+        assert_eq!(ab.shape(), (2, 3).into());
+        assert_eq!(ab.contents, vec![1, 2, 5, 3, 4, 6]);
+    }
+
+    #[test]
+    fn vertical() {
+        let a: Matrix<i32> = matrix![1, 2];
+        let b: Matrix<i32> = matrix![3, 4;
+                                     5, 6];
+
+        let ab = Matrix::vstack(&[a, b]);
+        assert_eq!(ab.shape(), (3, 2).into());
+        assert_eq!(ab.contents, vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn mismatched_parts() {
+        let a: Matrix<i32> = Matrix::zeroes(2, 2);
+        let b: Matrix<i32> = Matrix::zeroes(3, 2);
+        let c: Matrix<i32> = Matrix::zeroes(2, 3);
+
+        assert_eq!(Matrix::try_hstack(&[&a, &b]).err(), Some(MatrixError::DimensionMismatch {
+            operation: "horizontal stacking",
+            left: (2, 2),
+            right: (3, 2)
+        }));
+        assert!(Matrix::try_vstack(&[&a, &c]).is_err());
+        assert!(Matrix::try_vstack(&[&a, &b]).is_ok());
+    }
+
+    #[test]
+    fn stacking_nothing() {
+        let parts: [Matrix<i32>; 0] = [];
+
+        assert_eq!(Matrix::hstack(&parts).shape(), (0, 0).into());
+        assert_eq!(Matrix::vstack(&parts).shape(), (0, 0).into());
+    }
+
+    #[test]
+    fn tiles() {
+        let m: Matrix<usize> = Matrix::from_fn(5, 4, |r, c| r * 4 + c);
+        let blocks = m.blocks(2, 3);
+
+        assert_eq!(blocks.len(), 3);
+        assert_eq!(blocks[0].len(), 2);
+        assert_eq!(blocks[0][0].shape(), (2, 3).into());
+        assert_eq!(blocks[2][1].shape(), (1, 1).into());
+        assert_eq!(blocks[1][0].iter().copied().collect::<Vec<_>>(), vec![8, 9, 10, 12, 13, 14]);
+    }
+
+    #[test]
+    fn blocks_round_trip() {
+        let m: Matrix<usize> = Matrix::from_fn(5, 7, |r, c| r * 7 + c);
+        let rebuilt = Matrix::from_blocks(&m.blocks(2, 3));
+
+        assert_eq!(rebuilt.shape(), m.shape());
+        assert_eq!(rebuilt.contents, m.contents);
+    }
+
+    #[test]
+    fn assemble_from_owned_blocks() {
+        let a: Matrix<i32> = Matrix::identity(2);
+        let b: Matrix<i32> = Matrix::zeroes(2, 1);
+        let c: Matrix<i32> = Matrix::ones(1, 2);
+        let d: Matrix<i32> = matrix![9];
+
+        let m = Matrix::from_blocks(&[vec![a, b], vec![c, d]]);
+        assert_eq!(m.contents, vec![1, 0, 0,
+                                    0, 1, 0,
+                                    1, 1, 9]);
+    }
+
+    #[test]
+    fn misaligned_blocks() {
+        let a: Matrix<i32> = Matrix::zeroes(2, 2);
+        let b: Matrix<i32> = Matrix::zeroes(2, 1);
+        let c: Matrix<i32> = Matrix::zeroes(1, 1);
+
+        assert!(Matrix::try_from_blocks(&[vec![&a, &b], vec![&c, &c]]).is_err());
+        assert!(Matrix::try_from_blocks(&[vec![&a, &b], vec![&a]]).is_err());
+    }
+
+    #[test]
+    fn tiling() {
+        let m: Matrix<i32> = matrix![1, 2;
+                                     3, 4];
+        let t = m.tile(2, 3);
+
+        assert_eq!(t.shape(), (4, 6).into());
+        assert_eq!(t.row(0).contents, vec![1, 2, 1, 2, 1, 2]);
+        assert_eq!(t.row(3).contents, vec![3, 4, 3, 4, 3, 4]);
+        assert_eq!(m.tile(0, 5).shape(), (0, 10).into());
+    }
+
+    #[test]
+    fn writing_blocks() {
+        let mut m: Matrix<i32> = Matrix::zeroes(3, 4);
+        m.set_block(1, 2, &matrix![1, 2; 3, 4]);
+        m.set_block(0, 0, &Matrix::<i32>::ones(1, 2).as_view());
+
+        assert_eq!(m.contents, vec![1, 1, 0, 0,
+                                    0, 0, 1, 2,
+                                    0, 0, 3, 4]);
+    }
+
+    #[test]
+    fn block_must_fit() {
+        let mut m: Matrix<i32> = Matrix::zeroes(3, 3);
+        let b: Matrix<i32> = Matrix::ones(2, 2);
+
+        assert!(m.try_set_block(2, 0, &b).is_err());
+        assert!(m.try_set_block(0, 2, &b).is_err());
+        assert!(m.try_set_block(1, 1, &b).is_ok());
+        assert_eq!(m.sum(), 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "Can't perform horizontal stacking")]
+    fn hstack_panics() {
+        Matrix::hstack(&[Matrix::<i32>::zeroes(1, 1), Matrix::zeroes(2, 1)]);
+    }
+
+    #[test]
+    fn block_diagonal() {
+        let a: Matrix<i32> = matrix![1, 2;
+                                     3, 4];
+        let b: Matrix<i32> = matrix![5];
+        let c: Matrix<i32> = matrix![6, 7];
+
+        let d = Matrix::block_diag(&[&a, &b, &c]);
+        assert_eq!(d, matrix![1, 2, 0, 0, 0;
+                              3, 4, 0, 0, 0;
+                              0, 0, 5, 0, 0;
+                              0, 0, 0, 6, 7]);
+        assert_eq!(Matrix::<i32>::block_diag::<Matrix<i32>>(&[]), matrix![]);
+
+        let gap: Matrix<i32> = Matrix::zeroes(0, 1);
+        assert_eq!(Matrix::block_diag(&[&b, &gap, &b, &gap]), matrix![5, 0, 0, 0;
+                                                                      0, 0, 5, 0]);
+    }
+}
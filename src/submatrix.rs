@@ -0,0 +1,87 @@
+use std::ops::Range;
+
+use crate::Matrix;
+
+impl<T: Default + Clone> Matrix<T> {
+    /// Returns a copy of the block covering the given rows and columns (end exclusive).
+    ///
+    /// This panics if either range reaches outside the matrix.
+    pub fn submatrix(&self, rows: Range<usize>, columns: Range<usize>) -> Matrix<T> {
+        assert!(rows.start <= rows.end && rows.end <= self.rows && columns.start <= columns.end && columns.end <= self.columns,
+            "Rows {:?} and columns {:?} don't fit in a matrix of dimensions ({}, {}).",
+            rows, columns, self.rows, self.columns);
+
+        Matrix::from_fn(rows.len(), columns.len(), |r, c| {
+            self.contents[(rows.start + r) * self.columns + columns.start + c].clone()
+        })
+    }
+
+    /// Returns row `i` as a 1 by n matrix.
+    pub fn row(&self, i: usize) -> Matrix<T> {
+        assert!(i < self.rows, "Row {} doesn't exist in a matrix with {} rows.", i, self.rows);
+
+        self.submatrix(i..i + 1, 0..self.columns)
+    }
+
+    /// Returns column `j` as an n by 1 matrix.
+    pub fn column(&self, j: usize) -> Matrix<T> {
+        assert!(j < self.columns, "Column {} doesn't exist in a matrix with {} columns.", j, self.columns);
+
+        self.submatrix(0..self.rows, j..j + 1)
+    }
+
+    /// Returns the matrix with row `i` and column `j` removed, as used in cofactor expansion.
+    pub fn minor(&self, i: usize, j: usize) -> Matrix<T> {
+        assert!(i < self.rows && j < self.columns,
+            "Can't remove row {} and column {} from a matrix of dimensions ({}, {}).",
+            i, j, self.rows, self.columns);
+
+        Matrix::from_fn(self.rows - 1, self.columns - 1, |r, c| {
+            let r = if r < i { r } else { r + 1 };
+            let c = if c < j { c } else { c + 1 };
+            self.contents[r * self.columns + c].clone()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counting(rows: usize, columns: usize) -> Matrix<usize> {
+        Matrix::from_fn(rows, columns, |r, c| r * columns + c)
+    }
+
+    #[test]
+    fn submatrix_block() {
+        let m = counting(4, 5);
+        let s = m.submatrix(1..3, 1..4);
+
+        assert_eq!(s.shape(), (2, 3).into());
+        assert_eq!(s.contents, vec![6, 7, 8, 11, 12, 13]);
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let m = counting(3, 3);
+
+        assert_eq!(m.row(1).shape(), (1, 3).into());
+        assert_eq!(m.row(1).contents, vec![3, 4, 5]);
+        assert_eq!(m.column(2).shape(), (3, 1).into());
+        assert_eq!(m.column(2).contents, vec![2, 5, 8]);
+    }
+
+    #[test]
+    fn minor_removes_row_and_column() {
+        let m = counting(3, 3);
+
+        assert_eq!(m.minor(1, 1).contents, vec![0, 2, 6, 8]);
+        assert_eq!(m.minor(0, 2).contents, vec![3, 4, 6, 7]);
+    }
+
+    #[test]
+    #[should_panic(expected = "don't fit in a matrix")]
+    fn submatrix_out_of_bounds() {
+        counting(2, 2).submatrix(0..1, 1..3);
+    }
+}
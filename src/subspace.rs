@@ -0,0 +1,229 @@
+//! The rank of a matrix, and bases of the subspaces that come with it.
+
+use crate::{Matrix, Real, Vector};
+
+impl<T: Real> Matrix<T> {
+    /// The numerical rank: the number of singular values that aren't negligible next to the
+    /// largest one. See `rank_with_tol` to pick the cutoff yourself.
+    pub fn rank(&self) -> usize {
+        self.svd().rank()
+    }
+
+    /// The number of singular values above `tolerance`. A larger tolerance treats nearly
+    /// dependent rows, e.g. those of noisy measurements, as dependent.
+    pub fn rank_with_tol(&self, tolerance: T) -> usize {
+        self.singular_values().into_iter().filter(|&s| s > tolerance).count()
+    }
+
+    /// An orthonormal basis of the null space, i.e. of all `x` with `A x = 0`, as the columns
+    /// of an n×(n - rank) matrix. These are the right singular vectors belonging to the
+    /// negligible singular values.
+    pub fn null_space(&self) -> Matrix<T> {
+        let svd = self.svd_full();
+        let rank = svd.rank();
+        let vt = svd.vt();
+        Matrix::from_fn(self.columns, self.columns - rank, |r, c| vt[(rank + c, r)])
+    }
+
+    /// An orthonormal basis of the column space (or range), i.e. of all `A x`, as the columns
+    /// of an m×rank matrix. These are the left singular vectors belonging to the
+    /// non-negligible singular values.
+    pub fn column_space(&self) -> Matrix<T> {
+        let svd = self.svd();
+        let rank = svd.rank();
+        svd.u().submatrix(0..self.rows, 0..rank)
+    }
+
+    /// An orthonormal basis of the column space by modified Gram–Schmidt, as the columns of a
+    /// new matrix.
+    ///
+    /// The columns are taken in order, and each one that is (numerically) a combination of the
+    /// ones before it is skipped, so the result has as many columns as the rank. This is
+    /// cheaper than `column_space`, but less robust for nearly dependent columns.
+    pub fn orthonormalize(&self) -> Matrix<T> {
+        self.orthonormalize_with_r().0
+    }
+
+    /// Like `orthonormalize`, but also returns the upper triangular (or, if columns were
+    /// skipped, upper trapezoidal) `R`, so that `A = Q R`.
+    pub fn orthonormalize_with_r(&self) -> (Matrix<T>, Matrix<T>) {
+        let (m, n) = (self.rows, self.columns);
+        let largest = (0..n).map(|c| norm(&self.column(c).contents)).fold(T::zero(), |acc, x| if x > acc { x } else { acc });
+        let tolerance = largest * T::epsilon() * T::from_f64(m.max(n) as f64);
+
+        let mut basis: Vec<Vec<T>> = Vec::new();
+        let mut r: Vec<Vec<T>> = Vec::new();
+        for c in 0..n {
+            let mut v = self.column(c).contents;
+            let mut coefficients = vec![T::zero(); n];
+            // modified Gram–Schmidt: project out each basis vector from the updated v, not the
+            // original column, which keeps rounding errors from piling up
+            for (i, q) in basis.iter().enumerate() {
+                let projection = q.iter().zip(&v).fold(T::zero(), |acc, (&a, &b)| acc + a * b);
+                for (x, &y) in v.iter_mut().zip(q) {
+                    *x = *x - projection * y;
+                }
+                r[i][c] = projection;
+            }
+
+            let length = norm(&v);
+            if length > tolerance {
+                coefficients[c] = length;
+                basis.push(v.into_iter().map(|x| x / length).collect());
+                r.push(coefficients);
+            }
+        }
+
+        let q = Matrix::from_fn(m, basis.len(), |row, col| basis[col][row]);
+        let r = Matrix::from_fn(basis.len(), n, |row, col| r[row][col]);
+        (q, r)
+    }
+
+    /// The m×m orthogonal projector `P = A (AᵀA)⁻¹ Aᵀ` onto the column space.
+    ///
+    /// Rather than forming `AᵀA`, which squares the condition number, this is `Q Qᵀ` for an
+    /// orthonormal basis `Q` of the column space taken from a QR decomposition with column
+    /// pivoting. That also covers rank-deficient `A`, for which `AᵀA` has no inverse.
+    pub fn projector(&self) -> Matrix<T> {
+        let q = self.range_basis();
+        &q * &q.transpose()
+    }
+
+    /// Splits `b` into its projection `P b` onto the column space and the residual `b - P b`,
+    /// which is orthogonal to it. The projection is the closest point to `b` of the form `A x`,
+    /// i.e. `A` times the least squares solution, and the length of the residual is the least
+    /// squares error. This panics unless `b` has as many elements as the matrix has rows.
+    pub fn project(&self, b: &Vector<T>) -> (Vector<T>, Vector<T>) {
+        assert!(b.len() == self.rows,
+            "A vector of length {} can't be projected onto the columns of a matrix with {} rows.", b.len(), self.rows);
+
+        let q = self.range_basis();
+        let projection = q.apply(q.apply_transposed(b.as_slice()).as_slice());
+        let residual = Vector::new(b.iter().zip(projection.iter()).map(|(&x, &p)| x - p).collect());
+        (projection, residual)
+    }
+
+    // an orthonormal basis of the column space, from the leading columns of a pivoted QR
+    fn range_basis(&self) -> Matrix<T> {
+        let pivoted = self.qr_pivoted();
+        let rank = pivoted.rank();
+        pivoted.qr().thin_q().submatrix(0..self.rows, 0..rank)
+    }
+}
+
+fn norm<T: Real>(v: &[T]) -> T {
+    v.iter().fold(T::zero(), |acc, &x| acc + x * x).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn full_and_deficient() {
+        assert_eq!(Matrix::<f64>::identity(4).rank(), 4);
+        assert_eq!(Matrix::<f64>::zeroes(3, 2).rank(), 0);
+        assert_eq!(Matrix::<f64>::zeroes(0, 0).rank(), 0);
+
+        let a: Matrix<f64> = matrix![1.0, 2.0, 3.0;
+                                     4.0, 5.0, 6.0;
+                                     7.0, 8.0, 9.0];
+        assert_eq!(a.rank(), 2);
+        assert_eq!(a.transpose().rank(), 2);
+        assert_eq!(Matrix::<f64>::hilbert(6).rank(), 6);
+    }
+
+    #[test]
+    fn null_space() {
+        let a: Matrix<f64> = matrix![1.0, 2.0, 3.0;
+                                     4.0, 5.0, 6.0;
+                                     7.0, 8.0, 9.0];
+
+        let n = a.null_space();
+        assert_eq!(n.shape(), (3, 1).into());
+        assert!((&a * &n).approx_eq(&Matrix::zeroes(3, 1), 1e-12, 0.0));
+        assert!((n.transpose() * &n).approx_eq(&Matrix::identity(1), 1e-12, 0.0));
+
+        // a wide matrix always has a null space
+        let wide: Matrix<f64> = matrix![1.0, 0.0, 1.0, 0.0];
+        let n = wide.null_space();
+        assert_eq!(n.shape(), (4, 3).into());
+        assert!((&wide * &n).approx_eq(&Matrix::zeroes(1, 3), 1e-12, 0.0));
+
+        assert_eq!(Matrix::<f64>::identity(2).null_space().shape(), (2, 0).into());
+    }
+
+    #[test]
+    fn column_space() {
+        let a: Matrix<f64> = matrix![1.0, 2.0;
+                                     2.0, 4.0;
+                                     0.0, 0.0];
+
+        let c = a.column_space();
+        assert_eq!(c.shape(), (3, 1).into());
+        assert!(((c[(0, 0)] * 5f64.sqrt()).abs() - 1.0).abs() < 1e-12);
+        assert!(((c[(1, 0)] * 5f64.sqrt()).abs() - 2.0).abs() < 1e-12);
+        // every column of A is left unchanged by projecting onto the basis
+        assert!((&(&c * &c.transpose()) * &a).approx_eq(&a, 1e-12, 0.0));
+    }
+
+    #[test]
+    fn gram_schmidt() {
+        let a: Matrix<f64> = matrix![3.0, 1.0;
+                                     4.0, 2.0;
+                                     0.0, 2.0];
+
+        let (q, r) = a.orthonormalize_with_r();
+        assert!(q.column(0).approx_eq(&matrix![0.6; 0.8; 0.0], 1e-12, 0.0));
+        assert!((q.transpose() * &q).approx_eq(&Matrix::identity(2), 1e-12, 0.0));
+        assert!(r.tril(-1).approx_eq(&Matrix::zeroes(2, 2), 0.0, 0.0));
+        assert!((&q * &r).approx_eq(&a, 1e-12, 0.0));
+        assert_eq!(a.orthonormalize(), q);
+    }
+
+    #[test]
+    fn gram_schmidt_skips_dependent_columns() {
+        let a: Matrix<f64> = matrix![1.0, 2.0, 0.0;
+                                     1.0, 2.0, 1.0;
+                                     0.0, 0.0, 1.0];
+
+        let (q, r) = a.orthonormalize_with_r();
+        assert_eq!(q.shape(), (3, 2).into());
+        assert_eq!(r.shape(), (2, 3).into());
+        assert!((&q * &r).approx_eq(&a, 1e-12, 0.0));
+    }
+
+    #[test]
+    fn with_tolerance() {
+        let a: Matrix<f64> = matrix![1.0, 1.0;
+                                     1.0, 1.0 + 1e-9];
+
+        assert_eq!(a.rank(), 2);
+        assert_eq!(a.rank_with_tol(1e-6), 1);
+    }
+
+    #[test]
+    fn projections() {
+        let a: Matrix<f64> = matrix![1.0, 0.0;
+                                     1.0, 1.0;
+                                     1.0, 2.0];
+
+        let p = a.projector();
+        assert!((&p * &p).approx_eq(&p, 1e-12, 0.0));
+        assert!(p.approx_eq(&p.transpose(), 1e-12, 0.0));
+        assert!((&p * &a).approx_eq(&a, 1e-12, 0.0));
+        let direct = &(&a * &(&a.transpose() * &a).inverse().unwrap()) * &a.transpose();
+        assert!(p.approx_eq(&direct, 1e-12, 0.0));
+
+        // the least squares line through (0, 6), (1, 0), (2, 0) is 5 - 3t
+        let (projection, residual) = a.project(&Vector::new(vec![6.0, 0.0, 0.0]));
+        assert!(Matrix::from(projection).approx_eq(&matrix![5.0; 2.0; -1.0], 1e-12, 0.0));
+        assert!(Matrix::from(residual.clone()).approx_eq(&matrix![1.0; -2.0; 1.0], 1e-12, 0.0));
+        assert!(a.apply_transposed(residual.as_slice()).iter().all(|x| x.abs() < 1e-12));
+
+        // rank deficient: both columns span the same line
+        let b: Matrix<f64> = matrix![1.0, 2.0;
+                                     1.0, 2.0];
+        assert!(b.projector().approx_eq(&matrix![0.5, 0.5; 0.5, 0.5], 1e-12, 0.0));
+    }
+}
@@ -0,0 +1,173 @@
+//! Tridiagonal matrices, stored as their three diagonals.
+
+use crate::{Matrix, MatrixError, Real, Vector, Zero};
+
+/// A square matrix that is zero outside of its main diagonal and the diagonals directly above
+/// and below it, as they show up in 1D finite differences and cubic splines.
+///
+/// Only the three diagonals are stored, and `solve` takes O(n) time instead of the O(n³) of a
+/// dense solve.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Tridiagonal<T> {
+    lower: Vec<T>,
+    diagonal: Vec<T>,
+    upper: Vec<T>
+}
+
+impl<T> Tridiagonal<T> {
+    /// Creates an n×n tridiagonal matrix from its subdiagonal, diagonal and superdiagonal.
+    /// This panics unless the off-diagonals are one element shorter than the diagonal.
+    pub fn new(lower: Vec<T>, diagonal: Vec<T>, upper: Vec<T>) -> Tridiagonal<T> {
+        let n = diagonal.len();
+        assert!(lower.len() == n.saturating_sub(1) && upper.len() == n.saturating_sub(1),
+            "A tridiagonal matrix with {} diagonal elements needs {} elements below and above it, but got {} and {}.",
+            n, n.saturating_sub(1), lower.len(), upper.len());
+
+        Tridiagonal { lower, diagonal, upper }
+    }
+
+    /// The number of rows, which is also the number of columns.
+    pub fn size(&self) -> usize {
+        self.diagonal.len()
+    }
+
+    /// The subdiagonal, from the top left down.
+    pub fn lower(&self) -> &[T] {
+        &self.lower
+    }
+
+    pub fn diagonal(&self) -> &[T] {
+        &self.diagonal
+    }
+
+    /// The superdiagonal, from the top left down.
+    pub fn upper(&self) -> &[T] {
+        &self.upper
+    }
+}
+
+impl<T: Default + Clone + Zero> Tridiagonal<T> {
+    /// The three diagonals of a square matrix; everything else is ignored. Fails with `NotSquare`.
+    pub fn from_dense(m: &Matrix<T>) -> Result<Tridiagonal<T>, MatrixError> {
+        if !m.is_square() {
+            return Err(MatrixError::NotSquare { rows: m.rows, columns: m.columns });
+        }
+
+        let n = m.rows;
+        let at = |r: usize, c: usize| m.contents[r * n + c].clone();
+        Ok(Tridiagonal {
+            lower: (1..n).map(|i| at(i, i - 1)).collect(),
+            diagonal: (0..n).map(|i| at(i, i)).collect(),
+            upper: (1..n).map(|i| at(i - 1, i)).collect()
+        })
+    }
+
+    /// The dense n×n matrix.
+    pub fn to_dense(&self) -> Matrix<T> {
+        let n = self.size();
+        let mut m = Matrix::zeroes(n, n);
+        for i in 0..n {
+            m.contents[i * n + i] = self.diagonal[i].clone();
+            if i > 0 {
+                m.contents[i * n + i - 1] = self.lower[i - 1].clone();
+                m.contents[(i - 1) * n + i] = self.upper[i - 1].clone();
+            }
+        }
+        m
+    }
+}
+
+impl<T: Default + Clone + Zero> From<Tridiagonal<T>> for Matrix<T> {
+    fn from(t: Tridiagonal<T>) -> Matrix<T> {
+        t.to_dense()
+    }
+}
+
+impl<T: Real> Tridiagonal<T> {
+    /// Solves `A x = b` with the Thomas algorithm, i.e. Gaussian elimination without pivoting.
+    ///
+    /// Skipping the pivoting is what makes this O(n), and it's safe for the diagonally dominant
+    /// and the symmetric positive definite matrices that usually come up. Fails with `Singular`
+    /// if elimination runs into a zero pivot, and with `DimensionMismatch` if `b` has the wrong length.
+    pub fn solve(&self, b: &Vector<T>) -> Result<Vector<T>, MatrixError> {
+        let n = self.size();
+        if b.len() != n {
+            return Err(MatrixError::DimensionMismatch { operation: "solving", left: (n, n), right: (b.len(), 1) });
+        }
+
+        // the modified superdiagonal and right hand side of the forward sweep
+        let mut upper = vec![T::zero(); n];
+        let mut x = vec![T::zero(); n];
+        for i in 0..n {
+            let (below, previous_upper, previous_x) = if i == 0 {
+                (T::zero(), T::zero(), T::zero())
+            } else {
+                (self.lower[i - 1], upper[i - 1], x[i - 1])
+            };
+
+            let pivot = self.diagonal[i] - below * previous_upper;
+            if pivot.is_zero() || !pivot.is_finite() {
+                return Err(MatrixError::Singular);
+            }
+            if i + 1 < n {
+                upper[i] = self.upper[i] / pivot;
+            }
+            x[i] = (b[i] - below * previous_x) / pivot;
+        }
+
+        for i in (0..n.saturating_sub(1)).rev() {
+            x[i] = x[i] - upper[i] * x[i + 1];
+        }
+        Ok(Vector::new(x))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dense_round_trip() {
+        let t = Tridiagonal::new(vec![1, 2], vec![3, 4, 5], vec![6, 7]);
+        let dense: Matrix<i32> = matrix![3, 6, 0;
+                                         1, 4, 7;
+                                         0, 2, 5];
+
+        assert_eq!(t.to_dense(), dense);
+        assert_eq!(Tridiagonal::from_dense(&dense), Ok(t.clone()));
+        assert_eq!(Matrix::from(t), dense);
+        assert_eq!(Tridiagonal::from_dense(&Matrix::<i32>::zeroes(2, 3)), Err(MatrixError::NotSquare { rows: 2, columns: 3 }));
+    }
+
+    #[test]
+    fn second_difference() {
+        // -u'' = 1 on (0, 1) with u(0) = u(1) = 0, whose solution x (1 - x) / 2 the
+        // finite differences reproduce exactly at the grid points
+        let n = 9;
+        let h = 1.0 / (n + 1) as f64;
+        let t = Tridiagonal::new(vec![-1.0; n - 1], vec![2.0; n], vec![-1.0; n - 1]);
+        let b = Vector::new(vec![h * h; n]);
+
+        let u = t.solve(&b).unwrap();
+        for (i, &ui) in u.iter().enumerate() {
+            let x = (i + 1) as f64 * h;
+            assert!((ui - x * (1.0 - x) / 2.0).abs() < 1e-14);
+        }
+        assert!(t.to_dense().apply(u.as_slice()).into_vec().iter().all(|&e| (e - h * h).abs() < 1e-14));
+    }
+
+    #[test]
+    fn failures() {
+        let t = Tridiagonal::new(vec![1.0], vec![0.0, 1.0], vec![1.0]);
+        assert_eq!(t.solve(&Vector::new(vec![1.0, 1.0])), Err(MatrixError::Singular));
+        assert_eq!(t.solve(&Vector::new(vec![1.0])),
+            Err(MatrixError::DimensionMismatch { operation: "solving", left: (2, 2), right: (1, 1) }));
+        assert_eq!(Tridiagonal::<f64>::new(vec![], vec![], vec![]).solve(&Vector::new(vec![])), Ok(Vector::new(vec![])));
+    }
+
+    #[test]
+    #[should_panic(expected = "needs 2 elements below and above it, but got 2 and 1.")]
+    fn wrong_lengths() {
+        Tridiagonal::new(vec![1, 2], vec![3, 4, 5], vec![6]);
+    }
+}
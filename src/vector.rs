@@ -0,0 +1,554 @@
+//! A dedicated vector type, so that applying a matrix to a vector doesn't need n×1 matrices.
+
+use std::fmt;
+use std::iter::FromIterator;
+use std::ops;
+use std::slice;
+use std::vec;
+
+use crate::{format_grid, Matrix, MatrixLike, Real, Shape, Zero};
+
+/// A list of numbers that matrices can be applied to.
+///
+/// A vector is always a column: `matrix * vector` is another column, and where it has to act
+/// like a matrix, such as in `MatrixLike` or when converting it into one, it's n×1. That's why
+/// it's also available as `ColVector`. Multiplying by a matrix from the left takes a row, which
+/// `transpose` turns it into, so getting the orientation wrong doesn't compile:
+///
+/// ```
+/// use matricks::{matrix, ColVector, Matrix, RowVector};
+///
+/// let m: Matrix<i32> = matrix![1, 2;
+///                              3, 4];
+/// let col: ColVector<i32> = vec![1, 1].into();
+/// let row: RowVector<i32> = col.clone().transpose();
+///
+/// assert_eq!(&m * &col, vec![3, 7].into());
+/// assert_eq!(&row * &m, RowVector::new(vec![4, 6]));
+/// assert_eq!(&row * &col, 2);
+/// ```
+///
+/// ```compile_fail
+/// use matricks::{matrix, ColVector, Matrix};
+///
+/// let m: Matrix<i32> = matrix![1, 2;
+///                              3, 4];
+/// let col: ColVector<i32> = vec![1, 1].into();
+/// let _ = &col * &m;
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Vector<T> {
+    contents: Vec<T>
+}
+
+impl<T> Vector<T> {
+    pub fn new(contents: Vec<T>) -> Vector<T> {
+        Vector { contents }
+    }
+
+    pub fn len(&self) -> usize {
+        self.contents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.contents.is_empty()
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.contents
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.contents
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.contents
+    }
+
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.contents.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
+        self.contents.iter_mut()
+    }
+}
+
+/// A vector that is explicitly a column. This is the same type as `Vector`, which is always
+/// a column; rows are `RowVector`s.
+pub type ColVector<T> = Vector<T>;
+
+/// A vector that is explicitly a row, i.e. a 1×n matrix.
+///
+/// Only the products that make sense for a row are defined: `row * col` is a scalar,
+/// `col * row` a matrix and `row * matrix` another row, while `matrix * row` doesn't compile.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RowVector<T> {
+    contents: Vec<T>
+}
+
+impl<T> Vector<T> {
+    /// Turns this column into a row.
+    pub fn transpose(self) -> RowVector<T> {
+        RowVector { contents: self.contents }
+    }
+}
+
+impl<T> RowVector<T> {
+    pub fn new(contents: Vec<T>) -> RowVector<T> {
+        RowVector { contents }
+    }
+
+    pub fn len(&self) -> usize {
+        self.contents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.contents.is_empty()
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.contents
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.contents
+    }
+
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.contents.iter()
+    }
+
+    /// Turns this row into a column.
+    pub fn transpose(self) -> ColVector<T> {
+        Vector { contents: self.contents }
+    }
+}
+
+impl<T> From<Vec<T>> for RowVector<T> {
+    fn from(contents: Vec<T>) -> RowVector<T> {
+        RowVector { contents }
+    }
+}
+
+/// Turns the vector into a 1×n matrix.
+impl<T: Default> From<RowVector<T>> for Matrix<T> {
+    fn from(v: RowVector<T>) -> Matrix<T> {
+        Matrix { rows: 1, columns: v.contents.len(), contents: v.contents }
+    }
+}
+
+impl<T> ops::Index<usize> for RowVector<T> {
+    type Output = T;
+    fn index(&self, i: usize) -> &T {
+        &self.contents[i]
+    }
+}
+
+impl<T> ops::IndexMut<usize> for RowVector<T> {
+    fn index_mut(&mut self, i: usize) -> &mut T {
+        &mut self.contents[i]
+    }
+}
+
+impl<T> MatrixLike<T> for RowVector<T> {
+    fn shape(&self) -> Shape {
+        Shape::new(1, self.contents.len())
+    }
+
+    fn element(&self, row: usize, column: usize) -> &T {
+        assert!(row == 0 && column < self.contents.len(),
+            "Index ({}, {}) is out of bounds for a row vector of length {}.", row, column, self.contents.len());
+        &self.contents[column]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for RowVector<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        format_grid(f, 1, self.contents.len(), self.contents.iter())
+    }
+}
+
+/// A row times a column is their dot product.
+impl<T: Copy + Zero + ops::Mul<Output = T>> ops::Mul<&ColVector<T>> for &RowVector<T> {
+    type Output = T;
+    fn mul(self, col: &ColVector<T>) -> T {
+        assert!(self.len() == col.len(),
+            "Can't multiply a row of length {} by a column of length {}.", self.len(), col.len());
+
+        self.contents.iter().zip(&col.contents).fold(T::zero(), |acc, (&a, &b)| acc + a * b)
+    }
+}
+
+impl<T: Copy + Zero + ops::Mul<Output = T>> ops::Mul<ColVector<T>> for RowVector<T> {
+    type Output = T;
+    fn mul(self, col: ColVector<T>) -> T {
+        &self * &col
+    }
+}
+
+/// A column times a row is the matrix of all their pairwise products.
+impl<T: Default + Copy + ops::Mul<Output = T>> ops::Mul<&RowVector<T>> for &ColVector<T> {
+    type Output = Matrix<T>;
+    fn mul(self, row: &RowVector<T>) -> Matrix<T> {
+        Matrix::from_fn(self.len(), row.len(), |r, c| self.contents[r] * row.contents[c])
+    }
+}
+
+impl<T: Default + Copy + ops::Mul<Output = T>> ops::Mul<RowVector<T>> for ColVector<T> {
+    type Output = Matrix<T>;
+    fn mul(self, row: RowVector<T>) -> Matrix<T> {
+        &self * &row
+    }
+}
+
+/// A row times a matrix is another row.
+impl<T: Default + Copy + ops::Add<Output = T> + ops::Mul<Output = T>> ops::Mul<&Matrix<T>> for &RowVector<T> {
+    type Output = RowVector<T>;
+    fn mul(self, m: &Matrix<T>) -> RowVector<T> {
+        m.apply_transposed(&self.contents).transpose()
+    }
+}
+
+impl<T: Default + Copy + ops::Add<Output = T> + ops::Mul<Output = T>> ops::Mul<Matrix<T>> for RowVector<T> {
+    type Output = RowVector<T>;
+    fn mul(self, m: Matrix<T>) -> RowVector<T> {
+        &self * &m
+    }
+}
+
+impl<T: Clone + Default> Vector<T> {
+    /// A vector of `n` default values, which is zero for all numeric types.
+    pub fn zeroes(n: usize) -> Vector<T> {
+        Vector { contents: vec![T::default(); n] }
+    }
+}
+
+impl<T: Copy + Zero + ops::Mul<Output = T>> Vector<T> {
+    /// The dot product, i.e. the sum of the products of corresponding elements.
+    pub fn dot(&self, o: &Vector<T>) -> T {
+        assert!(self.len() == o.len(),
+            "Can't take the dot product of vectors of lengths {} and {}.", self.len(), o.len());
+
+        self.contents.iter().zip(&o.contents).fold(T::zero(), |acc, (&a, &b)| acc + a * b)
+    }
+}
+
+impl<T: Copy + ops::Mul<Output = T> + ops::Sub<Output = T>> Vector<T> {
+    /// The cross product of two three-dimensional vectors, which is perpendicular to both.
+    pub fn cross(&self, o: &Vector<T>) -> Vector<T> {
+        assert!(self.len() == 3 && o.len() == 3,
+            "The cross product is only defined for vectors of length 3, but got {} and {}.", self.len(), o.len());
+
+        let (a, b) = (&self.contents, &o.contents);
+        Vector::new(vec![
+            a[1] * b[2] - a[2] * b[1],
+            a[2] * b[0] - a[0] * b[2],
+            a[0] * b[1] - a[1] * b[0]
+        ])
+    }
+}
+
+impl<T: Real> Vector<T> {
+    /// The euclidean length.
+    pub fn norm(&self) -> T {
+        self.dot(self).sqrt()
+    }
+
+    /// Scales the vector to unit length. The zero vector is left as it is.
+    pub fn normalize(&mut self) {
+        let norm = self.norm();
+        if !norm.is_zero() {
+            self.contents.iter_mut().for_each(|e| *e = *e / norm);
+        }
+    }
+
+    /// The angle between two vectors in radians, between 0 and pi. This is NaN if either is the zero vector.
+    pub fn angle_between(&self, o: &Vector<T>) -> T {
+        let cos = self.dot(o) / (self.norm() * o.norm());
+        // rounding can push the cosine of (anti)parallel vectors just past 1
+        let clamped = if cos > T::one() { T::one() } else if cos < -T::one() { -T::one() } else { cos };
+        clamped.acos()
+    }
+
+    /// The orthogonal projection of this vector onto the line spanned by `o`.
+    /// Projecting onto the zero vector gives the zero vector.
+    pub fn project_onto(&self, o: &Vector<T>) -> Vector<T> {
+        let length_squared = o.dot(o);
+        if length_squared.is_zero() {
+            return Vector::zeroes(o.len());
+        }
+
+        let k = self.dot(o) / length_squared;
+        o.contents.iter().map(|&e| e * k).collect()
+    }
+}
+
+impl<T> From<Vec<T>> for Vector<T> {
+    fn from(contents: Vec<T>) -> Vector<T> {
+        Vector { contents }
+    }
+}
+
+/// Turns the vector into a column matrix.
+impl<T: Default> From<Vector<T>> for Matrix<T> {
+    fn from(v: Vector<T>) -> Matrix<T> {
+        Matrix { rows: v.contents.len(), columns: 1, contents: v.contents }
+    }
+}
+
+impl<T> FromIterator<T> for Vector<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Vector<T> {
+        Vector { contents: iter.into_iter().collect() }
+    }
+}
+
+impl<T> IntoIterator for Vector<T> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    fn into_iter(self) -> vec::IntoIter<T> {
+        self.contents.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Vector<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> slice::Iter<'a, T> {
+        self.contents.iter()
+    }
+}
+
+impl<T> ops::Index<usize> for Vector<T> {
+    type Output = T;
+    fn index(&self, i: usize) -> &T {
+        &self.contents[i]
+    }
+}
+
+impl<T> ops::IndexMut<usize> for Vector<T> {
+    fn index_mut(&mut self, i: usize) -> &mut T {
+        &mut self.contents[i]
+    }
+}
+
+impl<T> MatrixLike<T> for Vector<T> {
+    fn shape(&self) -> Shape {
+        Shape::new(self.contents.len(), 1)
+    }
+
+    fn element(&self, row: usize, column: usize) -> &T {
+        assert!(column == 0 && row < self.contents.len(),
+            "Index ({}, {}) is out of bounds for a vector of length {}.", row, column, self.contents.len());
+        &self.contents[row]
+    }
+}
+
+/// Draws the vector as a column.
+impl<T: fmt::Display> fmt::Display for Vector<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        format_grid(f, self.contents.len(), 1, self.contents.iter())
+    }
+}
+
+impl<T: Default + Copy + ops::Add<Output = T> + ops::Mul<Output = T>> Matrix<T> {
+    pub(crate) fn apply(&self, v: &[T]) -> Vector<T> {
+        assert!(self.columns == v.len(),
+            "A matrix of dimensions ({}, {}) can't be applied to a vector of length {}.",
+            self.rows, self.columns, v.len());
+
+        (0..self.rows).map(|r| {
+            let row = &self.contents[r * self.columns..(r + 1) * self.columns];
+            row.iter().zip(v).fold(T::default(), |acc, (&a, &b)| acc + a * b)
+        }).collect()
+    }
+
+    pub(crate) fn apply_transposed(&self, v: &[T]) -> Vector<T> {
+        assert!(self.rows == v.len(),
+            "A vector of length {} can't be multiplied by a matrix of dimensions ({}, {}).",
+            v.len(), self.rows, self.columns);
+
+        (0..self.columns).map(|c| {
+            (0..self.rows).fold(T::default(), |acc, r| acc + v[r] * self.contents[r * self.columns + c])
+        }).collect()
+    }
+}
+
+/// Applies the matrix to a vector, treating the vector as a column.
+impl<T: Default + Copy + ops::Add<Output = T> + ops::Mul<Output = T>> ops::Mul<&Vector<T>> for &Matrix<T> {
+    type Output = Vector<T>;
+    fn mul(self, v: &Vector<T>) -> Vector<T> {
+        self.apply(&v.contents)
+    }
+}
+
+impl<T: Default + Copy + ops::Add<Output = T> + ops::Mul<Output = T>> ops::Mul<Vector<T>> for Matrix<T> {
+    type Output = Vector<T>;
+    fn mul(self, v: Vector<T>) -> Vector<T> {
+        self.apply(&v.contents)
+    }
+}
+
+impl<T: Default + Copy + ops::Add<Output = T> + ops::Mul<Output = T>> ops::Mul<&Vector<T>> for Matrix<T> {
+    type Output = Vector<T>;
+    fn mul(self, v: &Vector<T>) -> Vector<T> {
+        self.apply(&v.contents)
+    }
+}
+
+impl<T: Copy + ops::Add<Output = T>> ops::Add<&Vector<T>> for &Vector<T> {
+    type Output = Vector<T>;
+    fn add(self, o: &Vector<T>) -> Vector<T> {
+        assert!(self.len() == o.len(), "Can only add vectors of the same length.");
+        self.contents.iter().zip(&o.contents).map(|(&a, &b)| a + b).collect()
+    }
+}
+
+impl<T: Copy + ops::Add<Output = T>> ops::Add<Vector<T>> for Vector<T> {
+    type Output = Vector<T>;
+    fn add(self, o: Vector<T>) -> Vector<T> {
+        &self + &o
+    }
+}
+
+impl<T: Copy + ops::Sub<Output = T>> ops::Sub<&Vector<T>> for &Vector<T> {
+    type Output = Vector<T>;
+    fn sub(self, o: &Vector<T>) -> Vector<T> {
+        assert!(self.len() == o.len(), "Can only subtract vectors of the same length.");
+        self.contents.iter().zip(&o.contents).map(|(&a, &b)| a - b).collect()
+    }
+}
+
+impl<T: Copy + ops::Sub<Output = T>> ops::Sub<Vector<T>> for Vector<T> {
+    type Output = Vector<T>;
+    fn sub(self, o: Vector<T>) -> Vector<T> {
+        &self - &o
+    }
+}
+
+/// Scales every element by `k`.
+impl<T: Copy + ops::Mul<Output = T>> ops::Mul<T> for Vector<T> {
+    type Output = Vector<T>;
+    fn mul(mut self, k: T) -> Vector<T> {
+        self.contents.iter_mut().for_each(|e| *e = *e * k);
+        self
+    }
+}
+
+impl<T: Copy + ops::Neg<Output = T>> ops::Neg for Vector<T> {
+    type Output = Vector<T>;
+    fn neg(mut self) -> Vector<T> {
+        self.contents.iter_mut().for_each(|e| *e = -*e);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matrix_times_vector() {
+        let m: Matrix<i32> = matrix![1, 2, 3;
+                                     4, 5, 6];
+        let v = Vector::new(vec![1, 0, -1]);
+
+        assert_eq!(&m * &v, Vector::new(vec![-2, -2]));
+        assert_eq!(m * v, Vector::new(vec![-2, -2]));
+    }
+
+    #[test]
+    fn row_times_matrix() {
+        let m: Matrix<i32> = matrix![1, 2, 3;
+                                     4, 5, 6];
+        let v: Vector<i32> = vec![1, 1].into();
+
+        assert_eq!(&v.clone().transpose() * &m, RowVector::new(vec![5, 7, 9]));
+        assert_eq!(v.transpose() * m, RowVector::new(vec![5, 7, 9]));
+    }
+
+    #[test]
+    fn conversions() {
+        let v: Vector<f64> = (1..=3).map(f64::from).collect();
+        assert_eq!(v[2], 3.0);
+        assert_eq!(v.len(), 3);
+
+        let column = Matrix::from(v.clone());
+        assert_eq!(column.shape(), (3, 1).into());
+        assert_eq!(v.to_matrix(), column);
+    }
+
+    #[test]
+    fn dot_and_cross() {
+        let x = Vector::new(vec![1, 0, 0]);
+        let y = Vector::new(vec![0, 1, 0]);
+
+        assert_eq!(x.dot(&y), 0);
+        assert_eq!(Vector::new(vec![1, 2, 3]).dot(&Vector::new(vec![4, 5, 6])), 32);
+        assert_eq!(x.cross(&y), Vector::new(vec![0, 0, 1]));
+        assert_eq!(y.cross(&x), -Vector::new(vec![0, 0, 1]));
+    }
+
+    #[test]
+    fn lengths_and_angles() {
+        let mut v = Vector::new(vec![3.0, 4.0]);
+        assert_eq!(v.norm(), 5.0);
+        v.normalize();
+        assert_eq!(v, Vector::new(vec![0.6, 0.8]));
+
+        let x = Vector::new(vec![2.0, 0.0]);
+        let diagonal = Vector::new(vec![1.0, 1.0]);
+        assert!((x.angle_between(&diagonal) - std::f64::consts::FRAC_PI_4).abs() < 1e-12);
+        assert_eq!(x.angle_between(&(x.clone() * 3.0)), 0.0);
+        assert_eq!(diagonal.project_onto(&x), Vector::new(vec![1.0, 0.0]));
+        assert_eq!(diagonal.project_onto(&Vector::zeroes(2)), Vector::zeroes(2));
+    }
+
+    #[test]
+    fn vector_arithmetic() {
+        let a = Vector::new(vec![1, 2]);
+        let b = Vector::new(vec![3, 5]);
+
+        assert_eq!(&a + &b, Vector::new(vec![4, 7]));
+        assert_eq!(b - a.clone(), Vector::new(vec![2, 3]));
+        assert_eq!(a * 2, Vector::new(vec![2, 4]));
+    }
+
+    #[test]
+    fn oriented_products() {
+        let col: ColVector<i32> = Vector::new(vec![1, 2]);
+        let row = RowVector::new(vec![3, 4]);
+
+        assert_eq!(&row * &col, 11);
+        assert_eq!(&col * &row, matrix![3, 4; 6, 8]);
+        assert_eq!(col.clone().transpose() * col, 5);
+
+        let m: Matrix<i32> = matrix![1, 0; 1, 1];
+        assert_eq!(row * m, RowVector::new(vec![7, 4]));
+    }
+
+    #[test]
+    fn row_vectors_as_matrices() {
+        let row = RowVector::new(vec![1, 2, 3]);
+
+        assert_eq!(row.shape(), (1, 3).into());
+        assert_eq!(Matrix::from(row.clone()), matrix![1, 2, 3]);
+        assert_eq!(row.transpose().to_matrix(), matrix![1; 2; 3]);
+    }
+
+    #[test]
+    fn empty_products() {
+        let m: Matrix<i32> = Matrix::zeroes(2, 0);
+        assert_eq!(&m * &Vector::new(vec![]), Vector::zeroes(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "can't be applied to a vector of length 2")]
+    fn mismatched_length() {
+        let m: Matrix<i32> = Matrix::zeroes(2, 3);
+        let _ = m * Vector::new(vec![1, 2]);
+    }
+}
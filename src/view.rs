@@ -0,0 +1,567 @@
+//! Borrowed (and mutably borrowed) rectangular windows into a matrix.
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops;
+use std::ops::Range;
+use std::slice;
+
+use crate::{format_grid, Matrix, Shape};
+
+/// Read access shared by everything that looks like a matrix, so that algorithms can
+/// take either an owned `Matrix` or a `MatrixView` into one.
+pub trait MatrixLike<T> {
+    fn shape(&self) -> Shape;
+
+    /// Returns a reference to the element at `(row, column)`. Panics if that's out of bounds.
+    fn element(&self, row: usize, column: usize) -> &T;
+
+    /// Copies the elements into a new, owned matrix.
+    fn to_matrix(&self) -> Matrix<T> where T: Default + Clone {
+        let shape = self.shape();
+        Matrix::from_fn(shape.rows, shape.cols, |r, c| self.element(r, c).clone())
+    }
+}
+
+impl<T: Default> MatrixLike<T> for Matrix<T> {
+    fn shape(&self) -> Shape {
+        Matrix::shape(self)
+    }
+
+    fn element(&self, row: usize, column: usize) -> &T {
+        &self[(row, column)]
+    }
+}
+
+impl<T, M: MatrixLike<T> + ?Sized> MatrixLike<T> for &M {
+    fn shape(&self) -> Shape {
+        (**self).shape()
+    }
+
+    fn element(&self, row: usize, column: usize) -> &T {
+        (**self).element(row, column)
+    }
+}
+
+/// A read-only view of a rectangular region of a matrix, which doesn't copy any elements.
+///
+/// Views are created with `Matrix::view` (or `as_view` for the whole thing), and can
+/// themselves be narrowed down further with `view`.
+#[derive(Clone, Copy, Debug)]
+pub struct MatrixView<'a, T> {
+    rows: usize,
+    columns: usize,
+    // distance between the starts of two consecutive rows in `data`
+    stride: usize,
+    // starts at the top left element of the view
+    data: &'a [T]
+}
+
+/// Checks the ranges against the given shape, and works out which part of a buffer
+/// with the given stride they cover.
+fn window(shape: (usize, usize), stride: usize, rows: &Range<usize>, columns: &Range<usize>) -> Range<usize> {
+    assert!(rows.start <= rows.end && rows.end <= shape.0 && columns.start <= columns.end && columns.end <= shape.1,
+        "Rows {:?} and columns {:?} don't fit in a matrix of dimensions ({}, {}).",
+        rows, columns, shape.0, shape.1);
+
+    if rows.is_empty() || columns.is_empty() {
+        return 0..0;
+    }
+
+    let start = rows.start * stride + columns.start;
+    start..(rows.end - 1) * stride + columns.end
+}
+
+impl<T: Default> Matrix<T> {
+    /// Returns a view of the given rows and columns (end exclusive).
+    ///
+    /// This panics if either range reaches outside the matrix.
+    pub fn view(&self, rows: Range<usize>, columns: Range<usize>) -> MatrixView<'_, T> {
+        let span = window((self.rows, self.columns), self.columns, &rows, &columns);
+
+        MatrixView {
+            rows: rows.len(),
+            columns: columns.len(),
+            stride: self.columns,
+            data: &self.contents[span]
+        }
+    }
+
+    /// Returns a view of the whole matrix.
+    pub fn as_view(&self) -> MatrixView<'_, T> {
+        self.view(0..self.rows, 0..self.columns)
+    }
+}
+
+impl<'a, T> MatrixView<'a, T> {
+    pub fn shape(&self) -> Shape {
+        Shape { rows: self.rows, cols: self.columns }
+    }
+
+    /// Returns a view of the given rows and columns of this view.
+    pub fn view(&self, rows: Range<usize>, columns: Range<usize>) -> MatrixView<'a, T> {
+        let span = window((self.rows, self.columns), self.stride, &rows, &columns);
+
+        MatrixView {
+            rows: rows.len(),
+            columns: columns.len(),
+            stride: self.stride,
+            data: &self.data[span]
+        }
+    }
+
+    pub fn get(&self, row: usize, column: usize) -> Option<&'a T> {
+        if row < self.rows && column < self.columns {
+            self.data.get(row * self.stride + column)
+        } else {
+            None
+        }
+    }
+
+    /// Returns row `i` of the view as a slice.
+    pub fn row_slice(&self, i: usize) -> &'a [T] {
+        assert!(i < self.rows, "Row {} doesn't exist in a view with {} rows.", i, self.rows);
+
+        row_of(self.data, self.stride, self.columns, i)
+    }
+
+    /// Iterates over the elements of the view in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + 'a {
+        let (data, stride, columns) = (self.data, self.stride, self.columns);
+        (0..self.rows).flat_map(move |r| row_of(data, stride, columns, r).iter())
+    }
+}
+
+// row r of a view's data. A view without columns has no data at all, whatever its rows
+fn row_of<T>(data: &[T], stride: usize, columns: usize, r: usize) -> &[T] {
+    if columns == 0 {
+        return &[];
+    }
+    &data[r * stride..r * stride + columns]
+}
+
+impl<'a, T> MatrixLike<T> for MatrixView<'a, T> {
+    fn shape(&self) -> Shape {
+        MatrixView::shape(self)
+    }
+
+    fn element(&self, row: usize, column: usize) -> &T {
+        self.get(row, column).unwrap_or_else(|| panic!(
+            "Index ({}, {}) is out of bounds for a view of dimensions ({}, {}).",
+            row, column, self.rows, self.columns))
+    }
+}
+
+impl<'a, T> ops::Index<(usize, usize)> for MatrixView<'a, T> {
+    type Output = T;
+    fn index(&self, (row, column): (usize, usize)) -> &T {
+        self.element(row, column)
+    }
+}
+
+impl<'a, T: fmt::Display> fmt::Display for MatrixView<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        format_grid(f, self.rows, self.columns, self.iter())
+    }
+}
+
+/// Adds two matrix-likes element by element. Panics if the shapes differ.
+pub(crate) fn add<T, A, B>(a: &A, b: &B) -> Matrix<T> where
+    T: Default + Clone + ops::Add<Output = T> + Copy,
+    A: MatrixLike<T> + ?Sized,
+    B: MatrixLike<T> + ?Sized {
+    let (sa, sb) = (a.shape(), b.shape());
+    assert!(sa == sb, "Can only add matrices of the same dimension.");
+
+    Matrix::from_fn(sa.rows, sa.cols, |r, c| *a.element(r, c) + *b.element(r, c))
+}
+
+/// Multiplies two matrix-likes. Panics if the inner dimensions don't match.
+pub(crate) fn mul<T, A, B>(a: &A, b: &B) -> Matrix<T> where
+    T: Default + Clone + ops::Add<Output = T> + ops::Mul<Output = T> + Copy,
+    A: MatrixLike<T> + ?Sized,
+    B: MatrixLike<T> + ?Sized {
+    let (sa, sb) = (a.shape(), b.shape());
+    assert!(sa.cols == sb.rows,
+        "Matrices of dimensions ({}, {}) and ({}, {}) aren't multiplicable.",
+        sa.rows, sa.cols, sb.rows, sb.cols);
+
+    Matrix::from_fn(sa.rows, sb.cols, |r, c| {
+        let mut acc: T = Default::default();
+        for k in 0..sa.cols {
+            acc = acc + *a.element(r, k) * *b.element(k, c);
+        }
+        acc
+    })
+}
+
+impl<'a, 'b, T: Default + Clone + ops::Add<Output = T> + Copy> ops::Add<&MatrixView<'b, T>> for &MatrixView<'a, T> {
+    type Output = Matrix<T>;
+    fn add(self, o: &MatrixView<'b, T>) -> Matrix<T> {
+        add(self, o)
+    }
+}
+
+impl<'a, 'b, T: Default + Clone + ops::Add<Output = T> + ops::Mul<Output = T> + Copy> ops::Mul<&MatrixView<'b, T>> for &MatrixView<'a, T> {
+    type Output = Matrix<T>;
+    fn mul(self, o: &MatrixView<'b, T>) -> Matrix<T> {
+        mul(self, o)
+    }
+}
+
+/// A mutable view of a rectangular region of a matrix.
+///
+/// Unlike `MatrixView`, the region a mutable view covers isn't necessarily a contiguous
+/// slice that it owns exclusively: after `split_at_col`, the rows of the two halves
+/// interleave in memory. So this holds a raw pointer instead, and only ever touches
+/// the elements that are inside its own rectangle.
+pub struct MatrixViewMut<'a, T> {
+    rows: usize,
+    columns: usize,
+    stride: usize,
+    // points at the top left element of the view
+    ptr: *mut T,
+    _marker: PhantomData<&'a mut T>
+}
+
+// Safety: a mutable view behaves exactly like a `&mut` to the elements it covers.
+unsafe impl<'a, T: Send> Send for MatrixViewMut<'a, T> {}
+unsafe impl<'a, T: Sync> Sync for MatrixViewMut<'a, T> {}
+
+impl<T: Default> Matrix<T> {
+    /// Returns a mutable view of the given rows and columns (end exclusive).
+    ///
+    /// This panics if either range reaches outside the matrix.
+    pub fn view_mut(&mut self, rows: Range<usize>, columns: Range<usize>) -> MatrixViewMut<'_, T> {
+        let span = window((self.rows, self.columns), self.columns, &rows, &columns);
+
+        MatrixViewMut {
+            rows: rows.len(),
+            columns: columns.len(),
+            stride: self.columns,
+            ptr: self.contents[span].as_mut_ptr(),
+            _marker: PhantomData
+        }
+    }
+
+    /// Returns a mutable view of the whole matrix.
+    pub fn as_view_mut(&mut self) -> MatrixViewMut<'_, T> {
+        let (rows, columns) = (self.rows, self.columns);
+        self.view_mut(0..rows, 0..columns)
+    }
+
+    /// Splits the matrix into two mutable views, one with the rows above `row` and one with
+    /// the rest. Either may end up empty.
+    pub fn split_at_row(&mut self, row: usize) -> (MatrixViewMut<'_, T>, MatrixViewMut<'_, T>) {
+        self.as_view_mut().split_at_row(row)
+    }
+
+    /// Splits the matrix into two mutable views, one with the columns left of `column` and one
+    /// with the rest. Either may end up empty.
+    pub fn split_at_col(&mut self, column: usize) -> (MatrixViewMut<'_, T>, MatrixViewMut<'_, T>) {
+        self.as_view_mut().split_at_col(column)
+    }
+}
+
+impl<'a, T> MatrixViewMut<'a, T> {
+    pub fn shape(&self) -> Shape {
+        Shape { rows: self.rows, cols: self.columns }
+    }
+
+    /// Builds the view at offset `(row, column)` with the given size.
+    /// The caller has to make sure that it lies within this one.
+    fn offset(&self, row: usize, column: usize, rows: usize, columns: usize) -> *mut T {
+        if rows == 0 || columns == 0 {
+            // never dereferenced, so don't risk pointing past the allocation
+            self.ptr
+        } else {
+            // Safety: the top left element of a non-empty sub-rectangle is inside this view
+            unsafe { self.ptr.add(row * self.stride + column) }
+        }
+    }
+
+    /// Consumes the view and splits it into the rows above `row` and the rest.
+    pub fn split_at_row(self, row: usize) -> (MatrixViewMut<'a, T>, MatrixViewMut<'a, T>) {
+        assert!(row <= self.rows, "Can't split a view with {} rows at row {}.", self.rows, row);
+
+        let top = MatrixViewMut { rows: row, ..self.reborrow_unchecked() };
+        let bottom = MatrixViewMut {
+            rows: self.rows - row,
+            ptr: self.offset(row, 0, self.rows - row, self.columns),
+            ..self.reborrow_unchecked()
+        };
+        (top, bottom)
+    }
+
+    /// Consumes the view and splits it into the columns left of `column` and the rest.
+    pub fn split_at_col(self, column: usize) -> (MatrixViewMut<'a, T>, MatrixViewMut<'a, T>) {
+        assert!(column <= self.columns, "Can't split a view with {} columns at column {}.", self.columns, column);
+
+        let left = MatrixViewMut { columns: column, ..self.reborrow_unchecked() };
+        let right = MatrixViewMut {
+            columns: self.columns - column,
+            ptr: self.offset(0, column, self.rows, self.columns - column),
+            ..self.reborrow_unchecked()
+        };
+        (left, right)
+    }
+
+    // copies the view with an unbounded lifetime; only used when splitting, where
+    // the two halves are guaranteed to be disjoint
+    fn reborrow_unchecked<'b>(&self) -> MatrixViewMut<'b, T> {
+        MatrixViewMut {
+            rows: self.rows,
+            columns: self.columns,
+            stride: self.stride,
+            ptr: self.ptr,
+            _marker: PhantomData
+        }
+    }
+
+    /// Returns a narrower mutable view, borrowing this one for as long as it lives.
+    pub fn view_mut(&mut self, rows: Range<usize>, columns: Range<usize>) -> MatrixViewMut<'_, T> {
+        window((self.rows, self.columns), self.stride, &rows, &columns);
+
+        MatrixViewMut {
+            rows: rows.len(),
+            columns: columns.len(),
+            stride: self.stride,
+            ptr: self.offset(rows.start, columns.start, rows.len(), columns.len()),
+            _marker: PhantomData
+        }
+    }
+
+    pub fn get(&self, row: usize, column: usize) -> Option<&T> {
+        if row < self.rows && column < self.columns {
+            // Safety: the element is inside the view
+            Some(unsafe { &*self.ptr.add(row * self.stride + column) })
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut T> {
+        if row < self.rows && column < self.columns {
+            // Safety: the element is inside the view, and we hold it mutably
+            Some(unsafe { &mut *self.ptr.add(row * self.stride + column) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns row `i` of the view as a slice.
+    pub fn row_slice(&self, i: usize) -> &[T] {
+        assert!(i < self.rows, "Row {} doesn't exist in a view with {} rows.", i, self.rows);
+
+        // Safety: a single row of the view is contiguous and entirely inside it
+        unsafe { slice::from_raw_parts(self.ptr.add(i * self.stride), self.columns) }
+    }
+
+    /// Returns row `i` of the view as a mutable slice.
+    pub fn row_slice_mut(&mut self, i: usize) -> &mut [T] {
+        assert!(i < self.rows, "Row {} doesn't exist in a view with {} rows.", i, self.rows);
+
+        // Safety: as above, and we hold the view mutably
+        unsafe { slice::from_raw_parts_mut(self.ptr.add(i * self.stride), self.columns) }
+    }
+
+    /// Iterates over the elements of the view in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        (0..self.rows).flat_map(move |r| self.row_slice(r).iter())
+    }
+
+    /// Sets every element of the view to `value`.
+    pub fn fill(&mut self, value: T) where T: Clone {
+        for r in 0..self.rows {
+            for e in self.row_slice_mut(r) {
+                *e = value.clone();
+            }
+        }
+    }
+
+    /// Overwrites the view with the elements of `source`, which must have the same shape.
+    pub fn copy_from<M: MatrixLike<T> + ?Sized>(&mut self, source: &M) where T: Clone {
+        let shape = source.shape();
+        assert!(shape == self.shape(),
+            "Can't copy a matrix of dimensions ({}, {}) into a view of dimensions ({}, {}).",
+            shape.rows, shape.cols, self.rows, self.columns);
+
+        for r in 0..self.rows {
+            for (c, e) in self.row_slice_mut(r).iter_mut().enumerate() {
+                *e = source.element(r, c).clone();
+            }
+        }
+    }
+}
+
+impl<'a, T> MatrixLike<T> for MatrixViewMut<'a, T> {
+    fn shape(&self) -> Shape {
+        MatrixViewMut::shape(self)
+    }
+
+    fn element(&self, row: usize, column: usize) -> &T {
+        self.get(row, column).unwrap_or_else(|| panic!(
+            "Index ({}, {}) is out of bounds for a view of dimensions ({}, {}).",
+            row, column, self.rows, self.columns))
+    }
+}
+
+impl<'a, T> ops::Index<(usize, usize)> for MatrixViewMut<'a, T> {
+    type Output = T;
+    fn index(&self, (row, column): (usize, usize)) -> &T {
+        self.element(row, column)
+    }
+}
+
+impl<'a, T> ops::IndexMut<(usize, usize)> for MatrixViewMut<'a, T> {
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut T {
+        let (rows, columns) = (self.rows, self.columns);
+        self.get_mut(row, column).unwrap_or_else(|| panic!(
+            "Index ({}, {}) is out of bounds for a view of dimensions ({}, {}).",
+            row, column, rows, columns))
+    }
+}
+
+impl<'a, T: fmt::Display> fmt::Display for MatrixViewMut<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        format_grid(f, self.rows, self.columns, self.iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counting(rows: usize, columns: usize) -> Matrix<usize> {
+        Matrix::from_fn(rows, columns, |r, c| r * columns + c)
+    }
+
+    #[test]
+    fn view_matches_submatrix() {
+        let m = counting(4, 5);
+        let v = m.view(1..3, 2..5);
+
+        assert_eq!(v.shape(), (2, 3).into());
+        assert_eq!(v[(1, 2)], 14);
+        assert_eq!(v.iter().copied().collect::<Vec<_>>(), m.submatrix(1..3, 2..5).contents);
+        assert_eq!(v.to_matrix().contents, m.submatrix(1..3, 2..5).contents);
+    }
+
+    #[test]
+    fn nested_views() {
+        let m = counting(5, 5);
+        let v = m.view(1..5, 1..5).view(1..3, 0..2);
+
+        assert_eq!(v.iter().copied().collect::<Vec<_>>(), vec![11, 12, 16, 17]);
+        assert_eq!(v.row_slice(1), &[16, 17]);
+        assert_eq!(v.get(2, 0), None);
+    }
+
+    #[test]
+    fn empty_view() {
+        let m = counting(3, 3);
+        let v = m.view(1..1, 0..3);
+
+        assert_eq!(v.shape(), (0, 3).into());
+        assert_eq!(v.iter().count(), 0);
+
+        let thin = m.view(0..3, 1..1);
+        assert_eq!(thin.shape(), (3, 0).into());
+        assert_eq!(thin.iter().count(), 0);
+        assert!(thin.row_slice(2).is_empty());
+        assert_eq!(thin.to_string(), Matrix::<i32>::zeroes(3, 0).to_string());
+        assert_eq!(thin.view(1..3, 0..0).shape(), (2, 0).into());
+    }
+
+    #[test]
+    fn arithmetic_on_views() {
+        let m: Matrix<i32> = matrix![1, 2, 3;
+                                     4, 5, 6;
+                                     7, 8, 9];
+
+        let sum = &m.view(0..2, 0..2) + &m.view(1..3, 1..3);
+        assert_eq!(sum.contents, vec![6, 8, 12, 14]);
+
+        let product = &m.view(0..1, 0..3) * &m.view(0..3, 2..3);
+        assert_eq!(product.contents, vec![3 + 2 * 6 + 3 * 9]);
+    }
+
+    #[test]
+    fn display_matches_matrix() {
+        let m: Matrix<i32> = matrix![1, 2;
+                                     3, 4];
+
+        assert_eq!(m.as_view().to_string(), m.to_string());
+    }
+
+    #[test]
+    fn mutable_view_writes_through() {
+        let mut m: Matrix<i32> = Matrix::zeroes(3, 3);
+        {
+            let mut v = m.view_mut(1..3, 1..3);
+            v.fill(1);
+            v[(0, 1)] = 5;
+        }
+
+        assert_eq!(m.contents, vec![0, 0, 0,
+                                    0, 1, 5,
+                                    0, 1, 1]);
+    }
+
+    #[test]
+    fn split_at_col_is_disjoint() {
+        let mut m: Matrix<usize> = counting(3, 4);
+        let (mut left, mut right) = m.split_at_col(1);
+
+        assert_eq!(left.shape(), (3, 1).into());
+        assert_eq!(right.shape(), (3, 3).into());
+        assert_eq!(right.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 5, 6, 7, 9, 10, 11]);
+
+        left.fill(0);
+        for r in 0..3 {
+            right.row_slice_mut(r)[0] *= 10;
+        }
+
+        assert_eq!(m.contents, vec![0, 10, 2, 3,
+                                    0, 50, 6, 7,
+                                    0, 90, 10, 11]);
+    }
+
+    #[test]
+    fn split_recursively() {
+        let mut m: Matrix<i32> = Matrix::zeroes(4, 4);
+        let (top, bottom) = m.split_at_row(2);
+        let (mut a, mut b) = top.split_at_col(2);
+        let (mut c, mut d) = bottom.split_at_col(2);
+
+        a.fill(1);
+        b.fill(2);
+        c.fill(3);
+        d.copy_from(&matrix![4, 4; 4, 4]);
+
+        assert_eq!(m.contents, vec![1, 1, 2, 2,
+                                    1, 1, 2, 2,
+                                    3, 3, 4, 4,
+                                    3, 3, 4, 4]);
+    }
+
+    #[test]
+    fn split_at_edges() {
+        let mut m: Matrix<i32> = Matrix::ones(2, 2);
+        let (empty, mut all) = m.split_at_row(0);
+
+        assert!(empty.shape().is_empty());
+        all[(1, 1)] = 7;
+        assert_eq!(m.contents, vec![1, 1, 1, 7]);
+
+        let (all, empty) = m.split_at_col(2);
+        assert_eq!(all.shape(), (2, 2).into());
+        assert_eq!(empty.shape(), (2, 0).into());
+    }
+
+    #[test]
+    #[should_panic(expected = "don't fit in a matrix")]
+    fn view_out_of_bounds() {
+        counting(2, 2).view(0..3, 0..1);
+    }
+}